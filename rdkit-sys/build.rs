@@ -126,25 +126,28 @@ fn main() {
     let mut libs: Vec<_> = vec![
         // "Catalogs",
         // "ChemReactions",
-        // "ChemTransforms",
+        "ChemTransforms",
         "DataStructs",
         // "Depictor",
         "Descriptors",
         "FileParsers",
         "Fingerprints",
+        "FMCS",
         // "GenericGroups",
         "GraphMol",
         "Inchi",
         "RDInchiLib",
         "MolStandardize",
         // "MolTransforms",
-        // "PartialCharges",
+        "PartialCharges",
         "RDGeneral",
+        "RGroupDecomposition",
         // "RDGeometryLib",
         // "RingDecomposerLib",
         "ScaffoldNetwork",
         "SmilesParse",
         // "Subgraphs",
+        "SubstructLibrary",
         "SubstructMatch",
     ];
 
@@ -156,13 +159,11 @@ fn main() {
             "coordgen",
             "Catalogs",
             "ChemReactions",
-            "ChemTransforms",
             "CIPLabeler",
             "Depictor",
             "GenericGroups",
             "MolAlign",
             "MolTransforms",
-            "PartialCharges",
             "RDGeometryLib",
             "RingDecomposerLib",
             "Subgraphs",
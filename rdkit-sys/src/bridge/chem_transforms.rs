@@ -0,0 +1,43 @@
+#[cxx::bridge(namespace = "RDKit")]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("wrapper/include/ro_mol.h");
+        include!("wrapper/include/chem_transforms.h");
+
+        pub type ROMol = crate::ro_mol_ffi::ROMol;
+        pub type ROMolList = crate::ro_mol_ffi::ROMolList;
+
+        pub fn delete_substructs(
+            mol: &SharedPtr<ROMol>,
+            query: &SharedPtr<ROMol>,
+            only_frags: bool,
+            use_chirality: bool,
+        ) -> Result<SharedPtr<ROMol>>;
+        // one product per match unless replace_all is set
+        pub fn replace_substructs(
+            mol: &SharedPtr<ROMol>,
+            query: &SharedPtr<ROMol>,
+            replacement: &SharedPtr<ROMol>,
+            replace_all: bool,
+            replacement_connection_point: u32,
+            use_chirality: bool,
+        ) -> Result<UniquePtr<ROMolList>>;
+        // null if the core doesn't match
+        pub fn replace_sidechains(
+            mol: &SharedPtr<ROMol>,
+            core: &SharedPtr<ROMol>,
+            use_chirality: bool,
+        ) -> Result<SharedPtr<ROMol>>;
+        pub fn replace_core(
+            mol: &SharedPtr<ROMol>,
+            core: &SharedPtr<ROMol>,
+            replace_dummies: bool,
+            label_by_index: bool,
+            require_dummy_match: bool,
+            use_chirality: bool,
+        ) -> Result<SharedPtr<ROMol>>;
+
+        pub fn murcko_decompose(mol: &SharedPtr<ROMol>) -> Result<SharedPtr<ROMol>>;
+        pub fn make_scaffold_generic(mol: &SharedPtr<ROMol>) -> Result<SharedPtr<ROMol>>;
+    }
+}
@@ -1,5 +1,18 @@
 #[cxx::bridge(namespace = "RDKit")]
 pub mod ffi {
+    /// Per-atom Crippen logP and MR contributions with the atom type labels
+    pub struct CrippenAtomContribs {
+        pub logp: Vec<f64>,
+        pub mr: Vec<f64>,
+        pub atom_type_labels: Vec<String>,
+    }
+
+    /// Per-atom Labute ASA contributions and the summed hydrogen contribution
+    pub struct LabuteAtomContribs {
+        pub atoms: Vec<f64>,
+        pub hydrogens: f64,
+    }
+
     unsafe extern "C++" {
         include!("wrapper/include/ro_mol.h");
         include!("wrapper/include/descriptors.h");
@@ -8,12 +21,110 @@ pub mod ffi {
         pub type Properties;
 
         pub fn new_properties() -> SharedPtr<Properties>;
+        pub fn new_properties_with_names(names: &Vec<String>) -> Result<SharedPtr<Properties>>;
+        pub fn get_available_properties() -> UniquePtr<CxxVector<CxxString>>;
         pub fn get_property_names(
             properties: &SharedPtr<Properties>,
         ) -> UniquePtr<CxxVector<CxxString>>;
         pub fn compute_properties(
             properties: &SharedPtr<Properties>,
             mol: &SharedPtr<ROMol>,
+        ) -> Result<UniquePtr<CxxVector<f64>>>;
+
+        pub fn calc_mol_formula(
+            mol: &SharedPtr<ROMol>,
+            separate_isotopes: bool,
+            abbreviate_h_isotopes: bool,
+        ) -> String;
+        pub fn calc_exact_mw(mol: &SharedPtr<ROMol>, only_heavy: bool) -> f64;
+
+        pub fn get_crippen_atom_contribs(mol: &SharedPtr<ROMol>) -> CrippenAtomContribs;
+        pub fn get_tpsa_atom_contribs(
+            mol: &SharedPtr<ROMol>,
+            include_s_and_p: bool,
         ) -> UniquePtr<CxxVector<f64>>;
+        pub fn get_labute_atom_contribs(
+            mol: &SharedPtr<ROMol>,
+            include_hs: bool,
+        ) -> LabuteAtomContribs;
+        pub fn compute_gasteiger_charges(
+            mol: &SharedPtr<ROMol>,
+            n_iter: i32,
+            throw_on_param_failure: bool,
+        ) -> Result<UniquePtr<CxxVector<f64>>>;
+
+        pub fn calc_pmi1(
+            mol: &SharedPtr<ROMol>,
+            conf_id: i32,
+            use_atomic_masses: bool,
+        ) -> Result<f64>;
+        pub fn calc_pmi2(
+            mol: &SharedPtr<ROMol>,
+            conf_id: i32,
+            use_atomic_masses: bool,
+        ) -> Result<f64>;
+        pub fn calc_pmi3(
+            mol: &SharedPtr<ROMol>,
+            conf_id: i32,
+            use_atomic_masses: bool,
+        ) -> Result<f64>;
+        pub fn calc_npr1(
+            mol: &SharedPtr<ROMol>,
+            conf_id: i32,
+            use_atomic_masses: bool,
+        ) -> Result<f64>;
+        pub fn calc_npr2(
+            mol: &SharedPtr<ROMol>,
+            conf_id: i32,
+            use_atomic_masses: bool,
+        ) -> Result<f64>;
+        pub fn calc_radius_of_gyration(
+            mol: &SharedPtr<ROMol>,
+            conf_id: i32,
+            use_atomic_masses: bool,
+        ) -> Result<f64>;
+        pub fn calc_inertial_shape_factor(
+            mol: &SharedPtr<ROMol>,
+            conf_id: i32,
+            use_atomic_masses: bool,
+        ) -> Result<f64>;
+        pub fn calc_eccentricity(
+            mol: &SharedPtr<ROMol>,
+            conf_id: i32,
+            use_atomic_masses: bool,
+        ) -> Result<f64>;
+        pub fn calc_asphericity(
+            mol: &SharedPtr<ROMol>,
+            conf_id: i32,
+            use_atomic_masses: bool,
+        ) -> Result<f64>;
+        pub fn calc_spherocity_index(mol: &SharedPtr<ROMol>, conf_id: i32) -> Result<f64>;
+        pub fn calc_pbf(mol: &SharedPtr<ROMol>, conf_id: i32) -> Result<f64>;
+        pub fn calc_bcut2d(mol: &SharedPtr<ROMol>) -> Result<UniquePtr<CxxVector<f64>>>;
+        pub fn calc_autocorr_2d(mol: &SharedPtr<ROMol>) -> UniquePtr<CxxVector<f64>>;
+        pub fn calc_mqns(mol: &SharedPtr<ROMol>) -> UniquePtr<CxxVector<u32>>;
+        pub fn calc_peoe_vsa(mol: &SharedPtr<ROMol>) -> Result<UniquePtr<CxxVector<f64>>>;
+        pub fn calc_smr_vsa(mol: &SharedPtr<ROMol>) -> UniquePtr<CxxVector<f64>>;
+        pub fn calc_slogp_vsa(mol: &SharedPtr<ROMol>) -> UniquePtr<CxxVector<f64>>;
+
+        pub fn calc_autocorr_3d(
+            mol: &SharedPtr<ROMol>,
+            conf_id: i32,
+        ) -> Result<UniquePtr<CxxVector<f64>>>;
+        pub fn calc_rdf(mol: &SharedPtr<ROMol>, conf_id: i32) -> Result<UniquePtr<CxxVector<f64>>>;
+        pub fn calc_morse(
+            mol: &SharedPtr<ROMol>,
+            conf_id: i32,
+        ) -> Result<UniquePtr<CxxVector<f64>>>;
+        pub fn calc_whim(
+            mol: &SharedPtr<ROMol>,
+            conf_id: i32,
+            threshold: f64,
+        ) -> Result<UniquePtr<CxxVector<f64>>>;
+        pub fn calc_getaway(
+            mol: &SharedPtr<ROMol>,
+            conf_id: i32,
+            precision: u32,
+        ) -> Result<UniquePtr<CxxVector<f64>>>;
     }
 }
@@ -0,0 +1,75 @@
+#[cxx::bridge(namespace = "RDKit")]
+pub mod ffi {
+    #[repr(i32)]
+    #[derive(Debug, PartialEq)]
+    pub enum AtomComparator {
+        AtomCompareAny,
+        AtomCompareElements,
+        AtomCompareIsotopes,
+        AtomCompareAnyHeavyAtom,
+    }
+
+    #[repr(i32)]
+    #[derive(Debug, PartialEq)]
+    pub enum BondComparator {
+        BondCompareAny,
+        BondCompareOrder,
+        BondCompareOrderExact,
+    }
+
+    unsafe extern "C++" {
+        include!("wrapper/include/ro_mol.h");
+        include!("wrapper/include/fmcs.h");
+
+        pub type ROMol = crate::ro_mol_ffi::ROMol;
+        pub type ROMolList = crate::ro_mol_ffi::ROMolList;
+        pub type AtomComparator;
+        pub type BondComparator;
+        pub type MCSParameters;
+        pub type MCSResult;
+
+        pub fn new_mcs_parameters() -> SharedPtr<MCSParameters>;
+        pub fn mcs_parameters_set_atom_comparator(
+            params: &mut SharedPtr<MCSParameters>,
+            comparator: AtomComparator,
+        );
+        pub fn mcs_parameters_set_bond_comparator(
+            params: &mut SharedPtr<MCSParameters>,
+            comparator: BondComparator,
+        );
+        pub fn mcs_parameters_get_ring_matches_ring_only(params: &SharedPtr<MCSParameters>)
+            -> bool;
+        // applies to both atoms and bonds
+        pub fn mcs_parameters_set_ring_matches_ring_only(
+            params: &mut SharedPtr<MCSParameters>,
+            what: bool,
+        );
+        pub fn mcs_parameters_get_complete_rings_only(params: &SharedPtr<MCSParameters>) -> bool;
+        pub fn mcs_parameters_set_complete_rings_only(
+            params: &mut SharedPtr<MCSParameters>,
+            what: bool,
+        );
+        pub fn mcs_parameters_get_match_valences(params: &SharedPtr<MCSParameters>) -> bool;
+        pub fn mcs_parameters_set_match_valences(params: &mut SharedPtr<MCSParameters>, what: bool);
+        pub fn mcs_parameters_get_match_chiral_tag(params: &SharedPtr<MCSParameters>) -> bool;
+        pub fn mcs_parameters_set_match_chiral_tag(
+            params: &mut SharedPtr<MCSParameters>,
+            what: bool,
+        );
+        pub fn mcs_parameters_get_timeout(params: &SharedPtr<MCSParameters>) -> u32;
+        pub fn mcs_parameters_set_timeout(params: &mut SharedPtr<MCSParameters>, seconds: u32);
+        pub fn mcs_parameters_get_threshold(params: &SharedPtr<MCSParameters>) -> f64;
+        pub fn mcs_parameters_set_threshold(params: &mut SharedPtr<MCSParameters>, threshold: f64);
+
+        pub fn find_mcs(
+            mols: &UniquePtr<ROMolList>,
+            params: &SharedPtr<MCSParameters>,
+        ) -> Result<SharedPtr<MCSResult>>;
+        pub fn mcs_result_smarts(result: &SharedPtr<MCSResult>) -> String;
+        pub fn mcs_result_num_atoms(result: &SharedPtr<MCSResult>) -> u32;
+        pub fn mcs_result_num_bonds(result: &SharedPtr<MCSResult>) -> u32;
+        pub fn mcs_result_canceled(result: &SharedPtr<MCSResult>) -> bool;
+        // null if no common substructure was found
+        pub fn mcs_result_query_mol(result: &SharedPtr<MCSResult>) -> SharedPtr<ROMol>;
+    }
+}
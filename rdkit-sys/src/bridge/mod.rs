@@ -1,18 +1,30 @@
+mod chem_transforms;
+pub use chem_transforms::ffi as chem_transforms_ffi;
+
 mod descriptors;
 pub use descriptors::ffi as descriptors_ffi;
 
 mod fingerprint;
 pub use fingerprint::ffi as fingerprint_ffi;
 
+mod fmcs;
+pub use fmcs::ffi as fmcs_ffi;
+
 mod mol_ops;
 pub use mol_ops::ffi as mol_ops_ffi;
 
 mod mol_standardize;
-pub use mol_standardize::ffi as mol_standardize_ffi;
+pub use mol_standardize::{ffi as mol_standardize_ffi, TautomerCallback};
 
 mod periodic_table;
 pub use periodic_table::{ffi as periodic_table_ffi, PeriodicTableOps};
 
+mod resonance;
+pub use resonance::ffi as resonance_ffi;
+
+mod rgroup_decomposition;
+pub use rgroup_decomposition::ffi as rgroup_decomposition_ffi;
+
 mod ro_mol;
 pub use ro_mol::ffi as ro_mol_ffi;
 
@@ -22,5 +34,8 @@ pub use rw_mol::ffi as rw_mol_ffi;
 mod scaffold_network;
 pub use scaffold_network::ffi as scaffold_network_ffi;
 
+mod substruct_library;
+pub use substruct_library::ffi as substruct_library_ffi;
+
 mod substruct_match;
-pub use substruct_match::ffi as substruct_match_ffi;
+pub use substruct_match::{ffi as substruct_match_ffi, SubstructFinalCheck};
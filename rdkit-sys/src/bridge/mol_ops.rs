@@ -82,6 +82,14 @@ pub mod ffi {
 
         pub fn romol_set_hybridization(mol: &mut SharedPtr<ROMol>);
 
+        pub fn mol_get_formal_charge(mol: &SharedPtr<ROMol>) -> i32;
+
+        pub fn get_distance_matrix(
+            mol: &SharedPtr<ROMol>,
+            use_bond_order: bool,
+        ) -> UniquePtr<CxxVector<f64>>;
+        pub fn compute_balaban_j(mol: &SharedPtr<ROMol>) -> f64;
+
         pub fn clean_up(rw_mol: &mut SharedPtr<RWMol>);
     }
 }
@@ -1,5 +1,14 @@
 #[cxx::bridge(namespace = "RDKit")]
 pub mod ffi {
+    #[repr(i32)]
+    #[derive(Debug, PartialEq)]
+    pub enum TautomerEnumeratorStatus {
+        Completed,
+        MaxTautomersReached,
+        MaxTransformsReached,
+        Canceled,
+    }
+
     unsafe extern "C++" {
         include!("wrapper/include/ro_mol.h");
         include!("wrapper/include/mol_standardize.h");
@@ -9,10 +18,68 @@ pub mod ffi {
 
         pub type TautomerEnumerator;
         pub type TautomerEnumeratorResult;
+        pub type TautomerEnumeratorStatus;
         pub type CleanupParameters;
         pub type Uncharger;
+        pub type Normalizer;
+        pub type Reionizer;
+        pub type MetalDisconnector;
+        pub type LargestFragmentChooser;
+        pub type ValidationMethod;
 
         pub fn tautomer_enumerator() -> SharedPtr<TautomerEnumerator>;
+        pub fn new_tautomer_enumerator_from_params(
+            cleanup_params: &SharedPtr<CleanupParameters>,
+        ) -> Result<SharedPtr<TautomerEnumerator>>;
+
+        pub fn tautomer_enumerator_get_max_tautomers(
+            tautomer_enumerator: &SharedPtr<TautomerEnumerator>,
+        ) -> u32;
+        pub fn tautomer_enumerator_set_max_tautomers(
+            tautomer_enumerator: &mut SharedPtr<TautomerEnumerator>,
+            what: u32,
+        );
+        pub fn tautomer_enumerator_get_max_transforms(
+            tautomer_enumerator: &SharedPtr<TautomerEnumerator>,
+        ) -> u32;
+        pub fn tautomer_enumerator_set_max_transforms(
+            tautomer_enumerator: &mut SharedPtr<TautomerEnumerator>,
+            what: u32,
+        );
+        pub fn tautomer_enumerator_get_remove_sp3_stereo(
+            tautomer_enumerator: &SharedPtr<TautomerEnumerator>,
+        ) -> bool;
+        pub fn tautomer_enumerator_set_remove_sp3_stereo(
+            tautomer_enumerator: &mut SharedPtr<TautomerEnumerator>,
+            what: bool,
+        );
+        pub fn tautomer_enumerator_get_remove_bond_stereo(
+            tautomer_enumerator: &SharedPtr<TautomerEnumerator>,
+        ) -> bool;
+        pub fn tautomer_enumerator_set_remove_bond_stereo(
+            tautomer_enumerator: &mut SharedPtr<TautomerEnumerator>,
+            what: bool,
+        );
+        pub fn tautomer_enumerator_get_remove_isotopic_hs(
+            tautomer_enumerator: &SharedPtr<TautomerEnumerator>,
+        ) -> bool;
+        pub fn tautomer_enumerator_set_remove_isotopic_hs(
+            tautomer_enumerator: &mut SharedPtr<TautomerEnumerator>,
+            what: bool,
+        );
+        pub fn tautomer_enumerator_get_reassign_stereo(
+            tautomer_enumerator: &SharedPtr<TautomerEnumerator>,
+        ) -> bool;
+        pub fn tautomer_enumerator_set_reassign_stereo(
+            tautomer_enumerator: &mut SharedPtr<TautomerEnumerator>,
+            what: bool,
+        );
+        // replaces any previously set callback
+        pub fn tautomer_enumerator_set_callback(
+            tautomer_enumerator: &mut SharedPtr<TautomerEnumerator>,
+            callback: Box<TautomerCallback>,
+        );
+        pub fn tautomer_enumerator_score_tautomer(mol: &SharedPtr<ROMol>) -> i32;
 
         pub fn tautomer_enumerate(
             tautomer_enumerator: &SharedPtr<TautomerEnumerator>,
@@ -25,6 +92,18 @@ pub mod ffi {
             enumerator_result: &SharedPtr<TautomerEnumeratorResult>,
             at: usize,
         ) -> SharedPtr<ROMol>;
+        pub fn tautomer_enumerator_result_status(
+            enumerator_result: &SharedPtr<TautomerEnumeratorResult>,
+        ) -> TautomerEnumeratorStatus;
+        pub fn tautomer_enumerator_result_smiles(
+            enumerator_result: &SharedPtr<TautomerEnumeratorResult>,
+        ) -> UniquePtr<CxxVector<CxxString>>;
+        pub fn tautomer_enumerator_result_modified_atoms(
+            enumerator_result: &SharedPtr<TautomerEnumeratorResult>,
+        ) -> UniquePtr<CxxVector<u32>>;
+        pub fn tautomer_enumerator_result_modified_bonds(
+            enumerator_result: &SharedPtr<TautomerEnumeratorResult>,
+        ) -> UniquePtr<CxxVector<u32>>;
         pub fn tautomer_enumerator_canonicalize(
             tautomer_enumerator: &SharedPtr<TautomerEnumerator>,
             mol: &SharedPtr<ROMol>,
@@ -32,6 +111,117 @@ pub mod ffi {
 
         pub fn default_cleanup_parameters() -> SharedPtr<CleanupParameters>;
 
+        pub fn get_cleanup_rdbase(params: &SharedPtr<CleanupParameters>) -> String;
+        pub fn set_cleanup_rdbase(params: &mut SharedPtr<CleanupParameters>, what: &CxxString);
+
+        pub fn get_cleanup_normalizations(params: &SharedPtr<CleanupParameters>) -> String;
+        pub fn set_cleanup_normalizations(
+            params: &mut SharedPtr<CleanupParameters>,
+            what: &CxxString,
+        );
+
+        pub fn get_cleanup_acidbase_file(params: &SharedPtr<CleanupParameters>) -> String;
+        pub fn set_cleanup_acidbase_file(
+            params: &mut SharedPtr<CleanupParameters>,
+            what: &CxxString,
+        );
+
+        pub fn get_cleanup_fragment_file(params: &SharedPtr<CleanupParameters>) -> String;
+        pub fn set_cleanup_fragment_file(
+            params: &mut SharedPtr<CleanupParameters>,
+            what: &CxxString,
+        );
+
+        pub fn get_cleanup_tautomer_transforms(params: &SharedPtr<CleanupParameters>) -> String;
+        pub fn set_cleanup_tautomer_transforms(
+            params: &mut SharedPtr<CleanupParameters>,
+            what: &CxxString,
+        );
+
+        pub fn get_cleanup_max_restarts(params: &SharedPtr<CleanupParameters>) -> i32;
+        pub fn set_cleanup_max_restarts(params: &mut SharedPtr<CleanupParameters>, what: i32);
+
+        pub fn get_cleanup_max_tautomers(params: &SharedPtr<CleanupParameters>) -> i32;
+        pub fn set_cleanup_max_tautomers(params: &mut SharedPtr<CleanupParameters>, what: i32);
+
+        pub fn get_cleanup_max_transforms(params: &SharedPtr<CleanupParameters>) -> i32;
+        pub fn set_cleanup_max_transforms(params: &mut SharedPtr<CleanupParameters>, what: i32);
+
+        pub fn get_cleanup_prefer_organic(params: &SharedPtr<CleanupParameters>) -> bool;
+        pub fn set_cleanup_prefer_organic(params: &mut SharedPtr<CleanupParameters>, what: bool);
+
+        pub fn get_cleanup_do_canonical(params: &SharedPtr<CleanupParameters>) -> bool;
+        pub fn set_cleanup_do_canonical(params: &mut SharedPtr<CleanupParameters>, what: bool);
+
+        pub fn get_cleanup_tautomer_remove_sp3_stereo(
+            params: &SharedPtr<CleanupParameters>,
+        ) -> bool;
+        pub fn set_cleanup_tautomer_remove_sp3_stereo(
+            params: &mut SharedPtr<CleanupParameters>,
+            what: bool,
+        );
+
+        pub fn get_cleanup_tautomer_remove_bond_stereo(
+            params: &SharedPtr<CleanupParameters>,
+        ) -> bool;
+        pub fn set_cleanup_tautomer_remove_bond_stereo(
+            params: &mut SharedPtr<CleanupParameters>,
+            what: bool,
+        );
+
+        pub fn get_cleanup_tautomer_remove_isotopic_hs(
+            params: &SharedPtr<CleanupParameters>,
+        ) -> bool;
+        pub fn set_cleanup_tautomer_remove_isotopic_hs(
+            params: &mut SharedPtr<CleanupParameters>,
+            what: bool,
+        );
+
+        pub fn get_cleanup_tautomer_reassign_stereo(params: &SharedPtr<CleanupParameters>) -> bool;
+        pub fn set_cleanup_tautomer_reassign_stereo(
+            params: &mut SharedPtr<CleanupParameters>,
+            what: bool,
+        );
+
+        pub fn get_cleanup_largest_fragment_chooser_use_atom_count(
+            params: &SharedPtr<CleanupParameters>,
+        ) -> bool;
+        pub fn set_cleanup_largest_fragment_chooser_use_atom_count(
+            params: &mut SharedPtr<CleanupParameters>,
+            what: bool,
+        );
+
+        pub fn get_cleanup_largest_fragment_chooser_count_heavy_atoms_only(
+            params: &SharedPtr<CleanupParameters>,
+        ) -> bool;
+        pub fn set_cleanup_largest_fragment_chooser_count_heavy_atoms_only(
+            params: &mut SharedPtr<CleanupParameters>,
+            what: bool,
+        );
+
+        pub fn get_cleanup_normalization_data_names(
+            params: &SharedPtr<CleanupParameters>,
+        ) -> UniquePtr<CxxVector<CxxString>>;
+        pub fn get_cleanup_normalization_data_smirks(
+            params: &SharedPtr<CleanupParameters>,
+        ) -> UniquePtr<CxxVector<CxxString>>;
+        pub fn set_cleanup_normalization_data(
+            params: &mut SharedPtr<CleanupParameters>,
+            names: &Vec<String>,
+            smirks: &Vec<String>,
+        );
+
+        pub fn get_cleanup_acidbase_data_column(
+            params: &SharedPtr<CleanupParameters>,
+            column: u32,
+        ) -> UniquePtr<CxxVector<CxxString>>;
+        pub fn set_cleanup_acidbase_data(
+            params: &mut SharedPtr<CleanupParameters>,
+            names: &Vec<String>,
+            acids: &Vec<String>,
+            bases: &Vec<String>,
+        );
+
         pub fn new_uncharger(canonical: bool) -> SharedPtr<Uncharger>;
         pub fn uncharger_uncharge(
             uncharger: &SharedPtr<Uncharger>,
@@ -43,10 +233,128 @@ pub mod ffi {
             cleanup_params: &SharedPtr<CleanupParameters>,
             skip_standardize: bool,
         ) -> SharedPtr<RWMol>;
+        pub fn charge_parent(
+            rw_mol: &SharedPtr<RWMol>,
+            cleanup_params: &SharedPtr<CleanupParameters>,
+            skip_standardize: bool,
+        ) -> Result<SharedPtr<RWMol>>;
+        pub fn tautomer_parent(
+            rw_mol: &SharedPtr<RWMol>,
+            cleanup_params: &SharedPtr<CleanupParameters>,
+            skip_standardize: bool,
+        ) -> Result<SharedPtr<RWMol>>;
+        pub fn stereo_parent(
+            rw_mol: &SharedPtr<RWMol>,
+            cleanup_params: &SharedPtr<CleanupParameters>,
+            skip_standardize: bool,
+        ) -> Result<SharedPtr<RWMol>>;
+        pub fn isotope_parent(
+            rw_mol: &SharedPtr<RWMol>,
+            cleanup_params: &SharedPtr<CleanupParameters>,
+            skip_standardize: bool,
+        ) -> Result<SharedPtr<RWMol>>;
+        pub fn super_parent(
+            rw_mol: &SharedPtr<RWMol>,
+            cleanup_params: &SharedPtr<CleanupParameters>,
+            skip_standardize: bool,
+        ) -> Result<SharedPtr<RWMol>>;
 
         pub fn normalize(
             rw_mol: &SharedPtr<RWMol>,
             cleanup_params: &SharedPtr<CleanupParameters>,
-        ) -> SharedPtr<RWMol>;
+        ) -> Result<SharedPtr<RWMol>>;
+        pub fn reionize(
+            rw_mol: &SharedPtr<RWMol>,
+            cleanup_params: &SharedPtr<CleanupParameters>,
+        ) -> Result<SharedPtr<RWMol>>;
+        pub fn standardize(
+            rw_mol: &SharedPtr<RWMol>,
+            cleanup_params: &SharedPtr<CleanupParameters>,
+        ) -> Result<SharedPtr<RWMol>>;
+
+        pub fn new_normalizer() -> SharedPtr<Normalizer>;
+        pub fn new_normalizer_from_transforms(
+            names: &Vec<String>,
+            smirks: &Vec<String>,
+            max_restarts: u32,
+        ) -> Result<SharedPtr<Normalizer>>;
+        pub fn new_normalizer_from_params(
+            cleanup_params: &SharedPtr<CleanupParameters>,
+        ) -> Result<SharedPtr<Normalizer>>;
+        pub fn normalizer_normalize(
+            normalizer: &SharedPtr<Normalizer>,
+            mol: &SharedPtr<ROMol>,
+        ) -> Result<SharedPtr<ROMol>>;
+
+        pub fn new_reionizer() -> SharedPtr<Reionizer>;
+        pub fn new_reionizer_from_pairs(
+            names: &Vec<String>,
+            acids: &Vec<String>,
+            bases: &Vec<String>,
+        ) -> Result<SharedPtr<Reionizer>>;
+        pub fn new_reionizer_from_params(
+            cleanup_params: &SharedPtr<CleanupParameters>,
+        ) -> Result<SharedPtr<Reionizer>>;
+        pub fn reionizer_reionize(
+            reionizer: &SharedPtr<Reionizer>,
+            mol: &SharedPtr<ROMol>,
+        ) -> Result<SharedPtr<ROMol>>;
+
+        pub fn new_metal_disconnector(
+            split_grignards: bool,
+            split_aromatic_c: bool,
+            adjust_charges: bool,
+            remove_haptic_dummies: bool,
+        ) -> SharedPtr<MetalDisconnector>;
+        pub fn metal_disconnector_disconnect(
+            disconnector: &SharedPtr<MetalDisconnector>,
+            mol: &SharedPtr<ROMol>,
+        ) -> Result<SharedPtr<ROMol>>;
+
+        pub fn new_largest_fragment_chooser(
+            prefer_organic: bool,
+            use_atom_count: bool,
+            count_heavy_atoms_only: bool,
+        ) -> SharedPtr<LargestFragmentChooser>;
+        pub fn new_largest_fragment_chooser_from_params(
+            cleanup_params: &SharedPtr<CleanupParameters>,
+        ) -> SharedPtr<LargestFragmentChooser>;
+        pub fn largest_fragment_chooser_choose(
+            chooser: &SharedPtr<LargestFragmentChooser>,
+            mol: &SharedPtr<ROMol>,
+        ) -> SharedPtr<ROMol>;
+
+        pub fn new_rdkit_validation() -> SharedPtr<ValidationMethod>;
+        pub fn new_no_atom_validation() -> SharedPtr<ValidationMethod>;
+        pub fn new_fragment_validation() -> SharedPtr<ValidationMethod>;
+        pub fn new_neutral_validation() -> SharedPtr<ValidationMethod>;
+        pub fn new_isotope_validation(strict: bool) -> SharedPtr<ValidationMethod>;
+        pub fn new_molvs_validation() -> SharedPtr<ValidationMethod>;
+        pub fn new_allowed_atoms_validation(atomic_nums: &Vec<u32>) -> SharedPtr<ValidationMethod>;
+        pub fn new_disallowed_atoms_validation(
+            atomic_nums: &Vec<u32>,
+        ) -> SharedPtr<ValidationMethod>;
+        pub fn new_disallowed_radical_validation() -> SharedPtr<ValidationMethod>;
+        pub fn new_stereo_validation() -> SharedPtr<ValidationMethod>;
+        pub fn validation_method_validate(
+            method: &SharedPtr<ValidationMethod>,
+            mol: &SharedPtr<ROMol>,
+            report_all_failures: bool,
+        ) -> Result<UniquePtr<CxxVector<CxxString>>>;
     }
+
+    extern "Rust" {
+        type TautomerCallback;
+        fn tautomer_callback_call(callback: &mut TautomerCallback, num_tautomers: u32) -> bool;
+    }
+}
+
+/// Called by RDKit after each enumeration step with the number of tautomers
+/// found so far. Returning `false` or panicking cancels the enumeration.
+pub struct TautomerCallback(pub Box<dyn FnMut(u32) -> bool>);
+
+fn tautomer_callback_call(callback: &mut TautomerCallback, num_tautomers: u32) -> bool {
+    // unwinding into RDKit would abort, so a panic cancels the enumeration
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (callback.0)(num_tautomers)))
+        .unwrap_or(false)
 }
@@ -0,0 +1,35 @@
+#[cxx::bridge(namespace = "RDKit")]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("wrapper/include/ro_mol.h");
+        include!("wrapper/include/resonance.h");
+
+        pub type ROMol = crate::ro_mol_ffi::ROMol;
+        pub type ResonanceMolSupplier;
+
+        pub fn new_resonance_mol_supplier(
+            mol: &SharedPtr<ROMol>,
+            flags: u32,
+            max_structs: u32,
+        ) -> SharedPtr<ResonanceMolSupplier>;
+        pub fn resonance_mol_supplier_length(supplier: &SharedPtr<ResonanceMolSupplier>) -> u32;
+        pub fn resonance_mol_supplier_at(
+            supplier: &SharedPtr<ResonanceMolSupplier>,
+            idx: u32,
+        ) -> Result<SharedPtr<ROMol>>;
+        pub fn resonance_mol_supplier_num_conj_grps(
+            supplier: &SharedPtr<ResonanceMolSupplier>,
+        ) -> u32;
+        pub fn resonance_mol_supplier_atom_conj_grp_idx(
+            supplier: &SharedPtr<ResonanceMolSupplier>,
+            atom_idx: u32,
+        ) -> Result<i32>;
+        pub fn resonance_mol_supplier_bond_conj_grp_idx(
+            supplier: &SharedPtr<ResonanceMolSupplier>,
+            bond_idx: u32,
+        ) -> Result<i32>;
+        pub fn resonance_mol_supplier_was_canceled(
+            supplier: &SharedPtr<ResonanceMolSupplier>,
+        ) -> bool;
+    }
+}
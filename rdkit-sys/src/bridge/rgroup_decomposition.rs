@@ -0,0 +1,67 @@
+#[cxx::bridge(namespace = "RDKit")]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("wrapper/include/ro_mol.h");
+        include!("wrapper/include/rgroup_decomposition.h");
+
+        pub type ROMol = crate::ro_mol_ffi::ROMol;
+        pub type ROMolList = crate::ro_mol_ffi::ROMolList;
+        pub type RGroupDecompositionParameters;
+        pub type RGroupDecomposeResult;
+
+        pub fn new_rgroup_decomposition_parameters() -> SharedPtr<RGroupDecompositionParameters>;
+        // the enum setters take RDKit's flag values
+        pub fn rgroup_decomposition_parameters_set_labels(
+            params: &mut SharedPtr<RGroupDecompositionParameters>,
+            labels: u32,
+        );
+        pub fn rgroup_decomposition_parameters_set_matching_strategy(
+            params: &mut SharedPtr<RGroupDecompositionParameters>,
+            strategy: u32,
+        );
+        pub fn rgroup_decomposition_parameters_set_alignment(
+            params: &mut SharedPtr<RGroupDecompositionParameters>,
+            alignment: u32,
+        );
+        pub fn rgroup_decomposition_parameters_get_remove_all_hydrogen_rgroups(
+            params: &SharedPtr<RGroupDecompositionParameters>,
+        ) -> bool;
+        pub fn rgroup_decomposition_parameters_set_remove_all_hydrogen_rgroups(
+            params: &mut SharedPtr<RGroupDecompositionParameters>,
+            what: bool,
+        );
+        pub fn rgroup_decomposition_parameters_get_only_match_at_rgroups(
+            params: &SharedPtr<RGroupDecompositionParameters>,
+        ) -> bool;
+        pub fn rgroup_decomposition_parameters_set_only_match_at_rgroups(
+            params: &mut SharedPtr<RGroupDecompositionParameters>,
+            what: bool,
+        );
+        pub fn rgroup_decomposition_parameters_get_timeout(
+            params: &SharedPtr<RGroupDecompositionParameters>,
+        ) -> f64;
+        pub fn rgroup_decomposition_parameters_set_timeout(
+            params: &mut SharedPtr<RGroupDecompositionParameters>,
+            seconds: f64,
+        );
+
+        pub fn rgroup_decompose(
+            cores: &UniquePtr<ROMolList>,
+            mols: &UniquePtr<ROMolList>,
+            params: &SharedPtr<RGroupDecompositionParameters>,
+        ) -> Result<SharedPtr<RGroupDecomposeResult>>;
+        pub fn rgroup_decompose_result_num_rows(result: &SharedPtr<RGroupDecomposeResult>) -> u32;
+        pub fn rgroup_decompose_result_row_labels(
+            result: &SharedPtr<RGroupDecomposeResult>,
+            row: u32,
+        ) -> UniquePtr<CxxVector<CxxString>>;
+        pub fn rgroup_decompose_result_row_mol(
+            result: &SharedPtr<RGroupDecomposeResult>,
+            row: u32,
+            label: &CxxString,
+        ) -> Result<SharedPtr<ROMol>>;
+        pub fn rgroup_decompose_result_unmatched(
+            result: &SharedPtr<RGroupDecomposeResult>,
+        ) -> UniquePtr<CxxVector<u32>>;
+    }
+}
@@ -14,6 +14,58 @@ pub mod ffi {
         OTHER,
     }
 
+    #[repr(i32)]
+    #[derive(Debug, PartialEq)]
+    pub enum ChiralType {
+        CHI_UNSPECIFIED,
+        CHI_TETRAHEDRAL_CW,
+        CHI_TETRAHEDRAL_CCW,
+        CHI_OTHER,
+        CHI_TETRAHEDRAL,
+        CHI_ALLENE,
+        CHI_SQUAREPLANAR,
+        CHI_TRIGONALBIPYRAMIDAL,
+        CHI_OCTAHEDRAL,
+    }
+
+    #[repr(i32)]
+    #[derive(Debug, PartialEq)]
+    pub enum BondType {
+        UNSPECIFIED,
+        SINGLE,
+        DOUBLE,
+        TRIPLE,
+        QUADRUPLE,
+        QUINTUPLE,
+        HEXTUPLE,
+        ONEANDAHALF,
+        TWOANDAHALF,
+        THREEANDAHALF,
+        FOURANDAHALF,
+        FIVEANDAHALF,
+        AROMATIC,
+        IONIC,
+        HYDROGEN,
+        THREECENTER,
+        DATIVEONE,
+        DATIVE,
+        DATIVEL,
+        DATIVER,
+        OTHER,
+        ZERO,
+    }
+
+    #[repr(i32)]
+    #[derive(Debug, PartialEq)]
+    pub enum BondStereo {
+        STEREONONE,
+        STEREOANY,
+        STEREOZ,
+        STEREOE,
+        STEREOCIS,
+        STEREOTRANS,
+    }
+
     unsafe extern "C++" {
         include!("wrapper/include/ro_mol.h");
 
@@ -21,13 +73,24 @@ pub mod ffi {
         pub type ExplicitBitVect = crate::fingerprint_ffi::ExplicitBitVect;
         pub type SmilesParserParams;
         pub type Atom;
+        pub type Bond;
         pub type HybridizationType;
+        pub type ChiralType;
+        pub type BondType;
+        pub type BondStereo;
 
         pub type MolSanitizeException;
         pub type MolSanitizeExceptionUniquePtr; //  = UniquePtr<MolSanitizeException>;
 
         pub fn copy_mol(mol: &SharedPtr<ROMol>) -> SharedPtr<ROMol>;
 
+        // for RDKit functions taking several molecules
+        pub type ROMolList;
+        pub fn new_ro_mol_list() -> UniquePtr<ROMolList>;
+        pub fn ro_mol_list_push(list: Pin<&mut ROMolList>, mol: &SharedPtr<ROMol>);
+        pub fn ro_mol_list_len(list: &UniquePtr<ROMolList>) -> u32;
+        pub fn ro_mol_list_get(list: &UniquePtr<ROMolList>, idx: u32) -> SharedPtr<ROMol>;
+
         pub fn smiles_to_mol(smi: &CxxString) -> Result<SharedPtr<ROMol>>;
 
         pub fn smiles_to_mol_with_params(
@@ -42,6 +105,7 @@ pub mod ffi {
         pub fn smiles_parser_params_get_sanitize(ptr: &SharedPtr<SmilesParserParams>) -> bool;
 
         pub fn mol_to_smiles(mol: &SharedPtr<ROMol>) -> String;
+        pub fn mol_to_smarts(mol: &SharedPtr<ROMol>, isomeric: bool) -> String;
 
         pub fn mol_to_molblock(mol: &SharedPtr<ROMol>) -> String;
 
@@ -58,6 +122,7 @@ pub mod ffi {
         ) -> u32;
 
         pub fn get_num_atoms(mol: &SharedPtr<ROMol>, onlyExplicit: bool) -> u32;
+        pub fn get_num_conformers(mol: &SharedPtr<ROMol>) -> u32;
         pub fn get_atom_with_idx(mol: &mut SharedPtr<ROMol>, idx: u32) -> Pin<&mut Atom>;
         pub fn get_symbol(atom: Pin<&Atom>) -> String;
         pub fn get_is_aromatic(atom: Pin<&Atom>) -> bool;
@@ -85,5 +150,25 @@ pub mod ffi {
 
         //Returns the degree of the atom in the molecule.
         pub fn get_degree(atom: Pin<&Atom>) -> u32;
+        pub fn atom_get_chiral_tag(atom: Pin<&Atom>) -> ChiralType;
+        pub fn atom_is_in_ring(atom: Pin<&Atom>) -> Result<bool>;
+        pub fn atom_get_isotope(atom: Pin<&Atom>) -> u32;
+        pub fn atom_has_query(atom: Pin<&Atom>) -> bool;
+        pub fn atom_get_smarts(atom: Pin<&Atom>) -> String;
+        pub fn atom_describe_query(atom: Pin<&Atom>) -> String;
+
+        pub fn get_num_bonds(mol: &SharedPtr<ROMol>) -> u32;
+        pub fn get_bond_with_idx(mol: &mut SharedPtr<ROMol>, idx: u32) -> Pin<&mut Bond>;
+        pub fn get_begin_atom_idx(bond: Pin<&Bond>) -> u32;
+        pub fn get_end_atom_idx(bond: Pin<&Bond>) -> u32;
+        pub fn get_bond_type_as_double(bond: Pin<&Bond>) -> f64;
+        pub fn get_bond_is_aromatic(bond: Pin<&Bond>) -> bool;
+        pub fn get_bond_type(bond: Pin<&Bond>) -> BondType;
+        pub fn get_bond_is_conjugated(bond: Pin<&Bond>) -> bool;
+        pub fn get_bond_stereo(bond: Pin<&Bond>) -> BondStereo;
+        pub fn bond_is_in_ring(bond: Pin<&Bond>) -> Result<bool>;
+        pub fn bond_has_query(bond: Pin<&Bond>) -> bool;
+        pub fn bond_get_smarts(bond: Pin<&Bond>) -> String;
+        pub fn bond_describe_query(bond: Pin<&Bond>) -> String;
     }
 }
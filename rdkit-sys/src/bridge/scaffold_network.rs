@@ -1,8 +1,21 @@
 #[cxx::bridge(namespace = "RDKit")]
 pub mod ffi {
+    #[repr(i32)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum EdgeType {
+        Fragment = 1,
+        Generic = 2,
+        GenericBond = 3,
+        RemoveAttachment = 4,
+        Initialize = 5,
+    }
+
     unsafe extern "C++" {
+        include!("wrapper/include/ro_mol.h");
         include!("wrapper/include/scaffold_network.h");
 
+        pub type ROMolList = crate::ro_mol_ffi::ROMolList;
+
         pub type ScaffoldNetworkParams;
         //
         // pub fn default_scaffold_network_params() -> SharedPtr<ScaffoldNetworkParams>;
@@ -11,7 +24,7 @@ pub mod ffi {
 
         pub fn new_scaffold_network_params(
             bond_breaker_smarts: &Vec<String>,
-        ) -> SharedPtr<ScaffoldNetworkParams>;
+        ) -> Result<SharedPtr<ScaffoldNetworkParams>>;
 
         pub fn set_include_generic_scaffolds(
             params: &mut SharedPtr<ScaffoldNetworkParams>,
@@ -38,6 +51,29 @@ pub mod ffi {
 
         pub type ScaffoldNetworkClass;
         pub fn default_scaffold_network() -> SharedPtr<ScaffoldNetworkClass>;
+        pub fn update_scaffold_network(
+            network: &mut SharedPtr<ScaffoldNetworkClass>,
+            mols: &UniquePtr<ROMolList>,
+            params: &SharedPtr<ScaffoldNetworkParams>,
+        ) -> Result<()>;
+        pub fn scaffold_network_nodes(
+            network: &SharedPtr<ScaffoldNetworkClass>,
+        ) -> UniquePtr<CxxVector<CxxString>>;
+        pub fn scaffold_network_counts(
+            network: &SharedPtr<ScaffoldNetworkClass>,
+        ) -> UniquePtr<CxxVector<u32>>;
+        // only filled in with collect_mol_counts set
+        pub fn scaffold_network_mol_counts(
+            network: &SharedPtr<ScaffoldNetworkClass>,
+        ) -> UniquePtr<CxxVector<u32>>;
 
+        pub type EdgeType;
+        pub type NetworkEdge;
+        pub fn scaffold_network_edges(
+            network: &SharedPtr<ScaffoldNetworkClass>,
+        ) -> UniquePtr<CxxVector<NetworkEdge>>;
+        pub fn network_edge_begin_idx(edge: &NetworkEdge) -> u32;
+        pub fn network_edge_end_idx(edge: &NetworkEdge) -> u32;
+        pub fn network_edge_type(edge: &NetworkEdge) -> EdgeType;
     }
 }
@@ -0,0 +1,47 @@
+#[cxx::bridge(namespace = "RDKit")]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("wrapper/include/ro_mol.h");
+        include!("wrapper/include/substruct_match.h");
+        include!("wrapper/include/substruct_library.h");
+
+        pub type ROMol = crate::ro_mol_ffi::ROMol;
+        pub type SubstructMatchParameters = crate::substruct_match_ffi::SubstructMatchParameters;
+        pub type SubstructLibrary;
+
+        pub fn new_substruct_library() -> SharedPtr<SubstructLibrary>;
+        pub fn new_substruct_library_from_pickle(
+            pickle: &[u8],
+        ) -> Result<SharedPtr<SubstructLibrary>>;
+        pub fn substruct_library_pickle(library: &SharedPtr<SubstructLibrary>) -> Result<Vec<u8>>;
+        pub fn substruct_library_add_mol(
+            library: &mut SharedPtr<SubstructLibrary>,
+            mol: &SharedPtr<ROMol>,
+        ) -> Result<u32>;
+        pub fn substruct_library_size(library: &SharedPtr<SubstructLibrary>) -> u32;
+        pub fn substruct_library_get_mol(
+            library: &SharedPtr<SubstructLibrary>,
+            idx: u32,
+        ) -> Result<SharedPtr<ROMol>>;
+        // num_threads <= 0 is relative to the number of cores, max_results -1 means all
+        pub fn substruct_library_get_matches(
+            library: &SharedPtr<SubstructLibrary>,
+            query: &SharedPtr<ROMol>,
+            params: &SharedPtr<SubstructMatchParameters>,
+            num_threads: i32,
+            max_results: i32,
+        ) -> Result<UniquePtr<CxxVector<u32>>>;
+        pub fn substruct_library_count_matches(
+            library: &SharedPtr<SubstructLibrary>,
+            query: &SharedPtr<ROMol>,
+            params: &SharedPtr<SubstructMatchParameters>,
+            num_threads: i32,
+        ) -> Result<u32>;
+        pub fn substruct_library_has_match(
+            library: &SharedPtr<SubstructLibrary>,
+            query: &SharedPtr<ROMol>,
+            params: &SharedPtr<SubstructMatchParameters>,
+            num_threads: i32,
+        ) -> Result<bool>;
+    }
+}
@@ -1,10 +1,14 @@
+use cxx::SharedPtr;
+
 #[cxx::bridge(namespace = "RDKit")]
 pub mod ffi {
     unsafe extern "C++" {
         include!("wrapper/include/ro_mol.h");
+        include!("wrapper/include/resonance.h");
         include!("wrapper/include/substruct_match.h");
 
         pub type ROMol = crate::ro_mol_ffi::ROMol;
+        pub type ResonanceMolSupplier = crate::resonance_ffi::ResonanceMolSupplier;
         pub type SubstructMatchParameters;
         pub type SubstructMatchItem;
         pub type MatchVectType;
@@ -14,6 +18,45 @@ pub mod ffi {
             mol_query: &SharedPtr<ROMol>,
             params: &SharedPtr<SubstructMatchParameters>,
         ) -> UniquePtr<CxxVector<MatchVectType>>;
+        // the *_resonance functions match against every resonance structure
+        // the supplier enumerates, using the supplier's flags
+        pub fn substruct_match_resonance(
+            supplier: &SharedPtr<ResonanceMolSupplier>,
+            mol_query: &SharedPtr<ROMol>,
+            params: &SharedPtr<SubstructMatchParameters>,
+        ) -> UniquePtr<CxxVector<MatchVectType>>;
+
+        pub fn has_substruct_match(
+            mol: &SharedPtr<ROMol>,
+            mol_query: &SharedPtr<ROMol>,
+            params: &SharedPtr<SubstructMatchParameters>,
+        ) -> bool;
+        pub fn has_substruct_match_resonance(
+            supplier: &SharedPtr<ResonanceMolSupplier>,
+            mol_query: &SharedPtr<ROMol>,
+            params: &SharedPtr<SubstructMatchParameters>,
+        ) -> bool;
+        pub fn count_substruct_matches(
+            mol: &SharedPtr<ROMol>,
+            mol_query: &SharedPtr<ROMol>,
+            params: &SharedPtr<SubstructMatchParameters>,
+        ) -> u32;
+        pub fn count_substruct_matches_resonance(
+            supplier: &SharedPtr<ResonanceMolSupplier>,
+            mol_query: &SharedPtr<ROMol>,
+            params: &SharedPtr<SubstructMatchParameters>,
+        ) -> u32;
+        // empty if there is no match
+        pub fn substruct_match_first(
+            mol: &SharedPtr<ROMol>,
+            mol_query: &SharedPtr<ROMol>,
+            params: &SharedPtr<SubstructMatchParameters>,
+        ) -> UniquePtr<CxxVector<SubstructMatchItem>>;
+        pub fn substruct_match_first_resonance(
+            supplier: &SharedPtr<ResonanceMolSupplier>,
+            mol_query: &SharedPtr<ROMol>,
+            params: &SharedPtr<SubstructMatchParameters>,
+        ) -> UniquePtr<CxxVector<SubstructMatchItem>>;
 
         pub fn new_substruct_match_parameters() -> SharedPtr<SubstructMatchParameters>;
         pub fn get_use_chirality(params: &SharedPtr<SubstructMatchParameters>) -> bool;
@@ -26,6 +69,17 @@ pub mod ffi {
         pub fn get_recursion_possible(params: &SharedPtr<SubstructMatchParameters>) -> bool;
         pub fn get_uniquify(params: &SharedPtr<SubstructMatchParameters>) -> bool;
         pub fn get_max_matches(params: &SharedPtr<SubstructMatchParameters>) -> u32;
+        pub fn get_atom_properties(
+            params: &SharedPtr<SubstructMatchParameters>,
+        ) -> UniquePtr<CxxVector<CxxString>>;
+        pub fn get_bond_properties(
+            params: &SharedPtr<SubstructMatchParameters>,
+        ) -> UniquePtr<CxxVector<CxxString>>;
+        pub fn get_specified_stereo_query_matches_unspecified(
+            params: &SharedPtr<SubstructMatchParameters>,
+        ) -> bool;
+        pub fn get_max_recursive_matches(params: &SharedPtr<SubstructMatchParameters>) -> u32;
+        pub fn get_num_threads(params: &SharedPtr<SubstructMatchParameters>) -> i32;
 
         pub fn set_use_chirality(params: &mut SharedPtr<SubstructMatchParameters>, what: bool);
         pub fn set_use_enhanced_stereo(
@@ -47,6 +101,27 @@ pub mod ffi {
         pub fn set_recursion_possible(params: &mut SharedPtr<SubstructMatchParameters>, what: bool);
         pub fn set_uniquify(params: &mut SharedPtr<SubstructMatchParameters>, what: bool);
         pub fn set_max_matches(params: &mut SharedPtr<SubstructMatchParameters>, value: u32);
+        pub fn set_atom_properties(
+            params: &mut SharedPtr<SubstructMatchParameters>,
+            props: &Vec<String>,
+        );
+        pub fn set_bond_properties(
+            params: &mut SharedPtr<SubstructMatchParameters>,
+            props: &Vec<String>,
+        );
+        pub fn set_specified_stereo_query_matches_unspecified(
+            params: &mut SharedPtr<SubstructMatchParameters>,
+            what: bool,
+        );
+        pub fn set_max_recursive_matches(
+            params: &mut SharedPtr<SubstructMatchParameters>,
+            value: u32,
+        );
+        pub fn set_num_threads(params: &mut SharedPtr<SubstructMatchParameters>, value: i32);
+        pub fn set_extra_final_check(
+            params: &mut SharedPtr<SubstructMatchParameters>,
+            check: Box<SubstructFinalCheck>,
+        );
 
         pub fn substruct_matchvect_type_to_vec_substruct_match_item(
             matchvect: &MatchVectType,
@@ -57,4 +132,33 @@ pub mod ffi {
         ) -> i32;
         pub fn substruct_match_item_mol_atom_idx(substruct_match_item: &SubstructMatchItem) -> i32;
     }
+
+    extern "Rust" {
+        type SubstructFinalCheck;
+        fn substruct_final_check_call(
+            check: &SubstructFinalCheck,
+            mol: &SharedPtr<ROMol>,
+            atom_indices: &[u32],
+        ) -> bool;
+    }
+}
+
+/// Called with the molecule and the matched atom indices, in query atom
+/// order, for every match. Returning `false` rejects the match. May be called
+/// from several threads when matching resonance structures.
+#[allow(clippy::type_complexity)]
+pub struct SubstructFinalCheck(
+    pub Box<dyn Fn(&SharedPtr<ffi::ROMol>, &[u32]) -> bool + Send + Sync>,
+);
+
+fn substruct_final_check_call(
+    check: &SubstructFinalCheck,
+    mol: &SharedPtr<ffi::ROMol>,
+    atom_indices: &[u32],
+) -> bool {
+    // unwinding into RDKit would abort, so a panic rejects the match
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        (check.0)(mol, atom_indices)
+    }))
+    .unwrap_or(false)
 }
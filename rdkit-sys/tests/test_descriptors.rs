@@ -12,7 +12,7 @@ fn test_descriptors() {
         .into_iter()
         .map(|stringy| stringy.to_string())
         .collect::<Vec<_>>();
-    let computed = rdkit_sys::descriptors_ffi::compute_properties(&properties, &mol);
+    let computed = rdkit_sys::descriptors_ffi::compute_properties(&properties, &mol).unwrap();
     let computed = computed
         .into_iter()
         .map(|floaty| *floaty)
@@ -112,3 +112,40 @@ fn test_descriptors() {
     assert_eq!(names, expected_names);
     assert_eq!(computed, expected_computed);
 }
+
+#[test]
+fn test_descriptors_with_names() {
+    let available = rdkit_sys::descriptors_ffi::get_available_properties();
+    assert!(available
+        .iter()
+        .any(|name| name.to_str().unwrap() == "tpsa"));
+
+    let names = vec!["NumAtoms".to_string(), "tpsa".to_string()];
+    let properties = rdkit_sys::descriptors_ffi::new_properties_with_names(&names).unwrap();
+    let computed_names = rdkit_sys::descriptors_ffi::get_property_names(&properties);
+    assert_eq!(computed_names.len(), 2);
+
+    let names = vec!["NotADescriptor".to_string()];
+    assert!(rdkit_sys::descriptors_ffi::new_properties_with_names(&names).is_err());
+}
+
+#[test]
+fn test_atom_contribs() {
+    let_cxx_string!(smiles = "CCO");
+    let mol = rdkit_sys::ro_mol_ffi::smiles_to_mol(&smiles).unwrap();
+
+    let crippen = rdkit_sys::descriptors_ffi::get_crippen_atom_contribs(&mol);
+    assert_eq!(crippen.logp.len(), 3);
+    assert_eq!(crippen.mr.len(), 3);
+    assert_eq!(crippen.atom_type_labels[2], "O2");
+
+    let labute = rdkit_sys::descriptors_ffi::get_labute_atom_contribs(&mol, true);
+    assert_eq!(labute.atoms.len(), 3);
+    assert!(labute.hydrogens > 0.0);
+
+    let tpsa = rdkit_sys::descriptors_ffi::get_tpsa_atom_contribs(&mol, false);
+    assert_eq!(tpsa.as_slice(), &[0.0, 0.0, 20.23]);
+
+    let charges = rdkit_sys::descriptors_ffi::compute_gasteiger_charges(&mol, 12, false).unwrap();
+    assert_eq!(charges.len(), 3);
+}
@@ -3,7 +3,7 @@ use rdkit_sys::scaffold_network_ffi::*;
 #[test]
 fn test_scaffold_network() {
     default_scaffold_network_params();
-    let mut params = new_scaffold_network_params(&vec![]);
+    let mut params = new_scaffold_network_params(&vec![]).unwrap();
 
     set_include_generic_scaffolds(&mut params, true);
     include_generic_bond_scaffolds(&mut params, true);
@@ -0,0 +1,24 @@
+#pragma once
+
+#include "rust/cxx.h"
+#include <GraphMol/ChemTransforms/ChemTransforms.h>
+#include <GraphMol/GraphMol.h>
+#include <GraphMol/MolOps.h>
+
+namespace RDKit {
+using ROMolList = std::vector<ROMOL_SPTR>;
+
+std::shared_ptr<ROMol> delete_substructs(const std::shared_ptr<ROMol> &mol, const std::shared_ptr<ROMol> &query,
+                                         bool only_frags, bool use_chirality);
+std::unique_ptr<ROMolList> replace_substructs(const std::shared_ptr<ROMol> &mol, const std::shared_ptr<ROMol> &query,
+                                              const std::shared_ptr<ROMol> &replacement, bool replace_all,
+                                              unsigned int replacement_connection_point, bool use_chirality);
+std::shared_ptr<ROMol> replace_sidechains(const std::shared_ptr<ROMol> &mol, const std::shared_ptr<ROMol> &core,
+                                          bool use_chirality);
+std::shared_ptr<ROMol> replace_core(const std::shared_ptr<ROMol> &mol, const std::shared_ptr<ROMol> &core,
+                                    bool replace_dummies, bool label_by_index, bool require_dummy_match,
+                                    bool use_chirality);
+
+std::shared_ptr<ROMol> murcko_decompose(const std::shared_ptr<ROMol> &mol);
+std::shared_ptr<ROMol> make_scaffold_generic(const std::shared_ptr<ROMol> &mol);
+} // namespace RDKit
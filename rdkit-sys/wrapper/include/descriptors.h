@@ -1,13 +1,64 @@
 #pragma once
 
 #include "rust/cxx.h"
+#include <GraphMol/Descriptors/AUTOCORR2D.h>
+#include <GraphMol/Descriptors/AUTOCORR3D.h>
+#include <GraphMol/Descriptors/BCUT.h>
+#include <GraphMol/Descriptors/Crippen.h>
+#include <GraphMol/Descriptors/GETAWAY.h>
+#include <GraphMol/Descriptors/MORSE.h>
+#include <GraphMol/Descriptors/MQN.h>
+#include <GraphMol/Descriptors/MolDescriptors.h>
+#include <GraphMol/Descriptors/MolSurf.h>
+#include <GraphMol/Descriptors/PBF.h>
+#include <GraphMol/Descriptors/PMI.h>
 #include <GraphMol/Descriptors/Property.h>
+#include <GraphMol/Descriptors/RDF.h>
+#include <GraphMol/Descriptors/WHIM.h>
 
 namespace RDKit {
 using Descriptors::Properties;
 
+struct CrippenAtomContribs;
+struct LabuteAtomContribs;
+
 std::shared_ptr<Properties> new_properties();
+std::shared_ptr<Properties> new_properties_with_names(const rust::Vec<rust::String> &names);
+std::unique_ptr<std::vector<std::string>> get_available_properties();
 std::unique_ptr<std::vector<std::string>> get_property_names(const std::shared_ptr<Properties> &props);
 std::unique_ptr<std::vector<double>> compute_properties(const std::shared_ptr<Properties> &props,
                                                         const std::shared_ptr<ROMol> &mol);
+
+rust::String calc_mol_formula(const std::shared_ptr<ROMol> &mol, bool separate_isotopes, bool abbreviate_h_isotopes);
+double calc_exact_mw(const std::shared_ptr<ROMol> &mol, bool only_heavy);
+
+CrippenAtomContribs get_crippen_atom_contribs(const std::shared_ptr<ROMol> &mol);
+std::unique_ptr<std::vector<double>> get_tpsa_atom_contribs(const std::shared_ptr<ROMol> &mol, bool include_s_and_p);
+LabuteAtomContribs get_labute_atom_contribs(const std::shared_ptr<ROMol> &mol, bool include_hs);
+std::unique_ptr<std::vector<double>> compute_gasteiger_charges(const std::shared_ptr<ROMol> &mol, int n_iter,
+                                                               bool throw_on_param_failure);
+
+double calc_pmi1(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses);
+double calc_pmi2(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses);
+double calc_pmi3(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses);
+double calc_npr1(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses);
+double calc_npr2(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses);
+double calc_radius_of_gyration(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses);
+double calc_inertial_shape_factor(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses);
+double calc_eccentricity(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses);
+double calc_asphericity(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses);
+double calc_spherocity_index(const std::shared_ptr<ROMol> &mol, int conf_id);
+double calc_pbf(const std::shared_ptr<ROMol> &mol, int conf_id);
+std::unique_ptr<std::vector<double>> calc_bcut2d(const std::shared_ptr<ROMol> &mol);
+std::unique_ptr<std::vector<double>> calc_autocorr_2d(const std::shared_ptr<ROMol> &mol);
+std::unique_ptr<std::vector<unsigned int>> calc_mqns(const std::shared_ptr<ROMol> &mol);
+std::unique_ptr<std::vector<double>> calc_peoe_vsa(const std::shared_ptr<ROMol> &mol);
+std::unique_ptr<std::vector<double>> calc_smr_vsa(const std::shared_ptr<ROMol> &mol);
+std::unique_ptr<std::vector<double>> calc_slogp_vsa(const std::shared_ptr<ROMol> &mol);
+
+std::unique_ptr<std::vector<double>> calc_autocorr_3d(const std::shared_ptr<ROMol> &mol, int conf_id);
+std::unique_ptr<std::vector<double>> calc_rdf(const std::shared_ptr<ROMol> &mol, int conf_id);
+std::unique_ptr<std::vector<double>> calc_morse(const std::shared_ptr<ROMol> &mol, int conf_id);
+std::unique_ptr<std::vector<double>> calc_whim(const std::shared_ptr<ROMol> &mol, int conf_id, double threshold);
+std::unique_ptr<std::vector<double>> calc_getaway(const std::shared_ptr<ROMol> &mol, int conf_id, unsigned int precision);
 } // namespace RDKit
\ No newline at end of file
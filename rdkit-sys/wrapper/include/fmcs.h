@@ -0,0 +1,33 @@
+#pragma once
+
+#include "rust/cxx.h"
+#include <GraphMol/FMCS/FMCS.h>
+#include <GraphMol/GraphMol.h>
+
+namespace RDKit {
+using ROMolList = std::vector<ROMOL_SPTR>;
+
+std::shared_ptr<MCSParameters> new_mcs_parameters();
+void mcs_parameters_set_atom_comparator(std::shared_ptr<MCSParameters> &params, AtomComparator comparator);
+void mcs_parameters_set_bond_comparator(std::shared_ptr<MCSParameters> &params, BondComparator comparator);
+bool mcs_parameters_get_ring_matches_ring_only(const std::shared_ptr<MCSParameters> &params);
+void mcs_parameters_set_ring_matches_ring_only(std::shared_ptr<MCSParameters> &params, bool what);
+bool mcs_parameters_get_complete_rings_only(const std::shared_ptr<MCSParameters> &params);
+void mcs_parameters_set_complete_rings_only(std::shared_ptr<MCSParameters> &params, bool what);
+bool mcs_parameters_get_match_valences(const std::shared_ptr<MCSParameters> &params);
+void mcs_parameters_set_match_valences(std::shared_ptr<MCSParameters> &params, bool what);
+bool mcs_parameters_get_match_chiral_tag(const std::shared_ptr<MCSParameters> &params);
+void mcs_parameters_set_match_chiral_tag(std::shared_ptr<MCSParameters> &params, bool what);
+unsigned int mcs_parameters_get_timeout(const std::shared_ptr<MCSParameters> &params);
+void mcs_parameters_set_timeout(std::shared_ptr<MCSParameters> &params, unsigned int seconds);
+double mcs_parameters_get_threshold(const std::shared_ptr<MCSParameters> &params);
+void mcs_parameters_set_threshold(std::shared_ptr<MCSParameters> &params, double threshold);
+
+std::shared_ptr<MCSResult> find_mcs(const std::unique_ptr<ROMolList> &mols,
+                                    const std::shared_ptr<MCSParameters> &params);
+rust::String mcs_result_smarts(const std::shared_ptr<MCSResult> &result);
+unsigned int mcs_result_num_atoms(const std::shared_ptr<MCSResult> &result);
+unsigned int mcs_result_num_bonds(const std::shared_ptr<MCSResult> &result);
+bool mcs_result_canceled(const std::shared_ptr<MCSResult> &result);
+std::shared_ptr<ROMol> mcs_result_query_mol(const std::shared_ptr<MCSResult> &result);
+} // namespace RDKit
@@ -65,6 +65,11 @@ std::shared_ptr<ROMol> add_hs(const std::shared_ptr<ROMol> &mol, bool explicit_o
 
 void romol_set_hybridization(std::shared_ptr<ROMol> &mol);
 
+int mol_get_formal_charge(const std::shared_ptr<ROMol> &mol);
+
+std::unique_ptr<std::vector<double>> get_distance_matrix(const std::shared_ptr<ROMol> &mol, bool use_bond_order);
+double compute_balaban_j(const std::shared_ptr<ROMol> &mol);
+
 // pub fn clean_up(rw_mol: &mut SharedPtr<RWMol>)
 void clean_up(std::shared_ptr<RWMol> &rw_mol);
 } // namespace RDKit
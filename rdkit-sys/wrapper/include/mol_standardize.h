@@ -3,15 +3,46 @@
 #include "rust/cxx.h"
 #include <GraphMol/GraphMol.h>
 #include <GraphMol/MolStandardize/Charge.h>
+#include <GraphMol/MolStandardize/Fragment.h>
+#include <GraphMol/MolStandardize/MetalDisconnector.h>
+#include <GraphMol/MolStandardize/MolStandardize.h>
+#include <GraphMol/MolStandardize/Normalize.h>
 #include <GraphMol/MolStandardize/Tautomer.h>
+#include <GraphMol/MolStandardize/Validate.h>
 
 namespace RDKit {
+struct TautomerCallback;
+
 using TautomerEnumerator       = RDKit::MolStandardize::TautomerEnumerator;
+using TautomerEnumeratorStatus = MolStandardize::TautomerEnumeratorStatus;
 using TautomerEnumeratorResult = RDKit::MolStandardize::TautomerEnumeratorResult;
 using CleanupParameters        = MolStandardize::CleanupParameters;
 using Uncharger                = MolStandardize::Uncharger;
+using Normalizer               = MolStandardize::Normalizer;
+using Reionizer                = MolStandardize::Reionizer;
+using MetalDisconnector        = MolStandardize::MetalDisconnector;
+using LargestFragmentChooser   = MolStandardize::LargestFragmentChooser;
+using ValidationMethod         = MolStandardize::ValidationMethod;
 
 std::shared_ptr<TautomerEnumerator> tautomer_enumerator();
+std::shared_ptr<TautomerEnumerator>
+new_tautomer_enumerator_from_params(const std::shared_ptr<CleanupParameters> &cleanup_params);
+
+unsigned int tautomer_enumerator_get_max_tautomers(const std::shared_ptr<TautomerEnumerator> &enumerator);
+void tautomer_enumerator_set_max_tautomers(std::shared_ptr<TautomerEnumerator> &enumerator, unsigned int what);
+unsigned int tautomer_enumerator_get_max_transforms(const std::shared_ptr<TautomerEnumerator> &enumerator);
+void tautomer_enumerator_set_max_transforms(std::shared_ptr<TautomerEnumerator> &enumerator, unsigned int what);
+bool tautomer_enumerator_get_remove_sp3_stereo(const std::shared_ptr<TautomerEnumerator> &enumerator);
+void tautomer_enumerator_set_remove_sp3_stereo(std::shared_ptr<TautomerEnumerator> &enumerator, bool what);
+bool tautomer_enumerator_get_remove_bond_stereo(const std::shared_ptr<TautomerEnumerator> &enumerator);
+void tautomer_enumerator_set_remove_bond_stereo(std::shared_ptr<TautomerEnumerator> &enumerator, bool what);
+bool tautomer_enumerator_get_remove_isotopic_hs(const std::shared_ptr<TautomerEnumerator> &enumerator);
+void tautomer_enumerator_set_remove_isotopic_hs(std::shared_ptr<TautomerEnumerator> &enumerator, bool what);
+bool tautomer_enumerator_get_reassign_stereo(const std::shared_ptr<TautomerEnumerator> &enumerator);
+void tautomer_enumerator_set_reassign_stereo(std::shared_ptr<TautomerEnumerator> &enumerator, bool what);
+void tautomer_enumerator_set_callback(std::shared_ptr<TautomerEnumerator> &enumerator,
+                                      rust::Box<TautomerCallback> callback);
+int tautomer_enumerator_score_tautomer(const std::shared_ptr<ROMol> &mol);
 std::shared_ptr<TautomerEnumeratorResult> tautomer_enumerate(const std::shared_ptr<TautomerEnumerator> &enumerator,
                                                              const std::shared_ptr<ROMol> &mol);
 std::shared_ptr<ROMol> tautomer_enumerator_canonicalize(const std::shared_ptr<TautomerEnumerator> &enumerator,
@@ -21,17 +52,142 @@ std::shared_ptr<ROMol> tautomer_enumerator_canonicalize(const std::shared_ptr<Ta
 int tautomer_enumerator_result_tautomers_size(const std::shared_ptr<TautomerEnumeratorResult> &enumerator_result);
 std::shared_ptr<ROMol>
 tautomer_enumerator_result_tautomers_at(const std::shared_ptr<TautomerEnumeratorResult> &enumerator_result, size_t at);
+TautomerEnumeratorStatus
+tautomer_enumerator_result_status(const std::shared_ptr<TautomerEnumeratorResult> &enumerator_result);
+std::unique_ptr<std::vector<std::string>>
+tautomer_enumerator_result_smiles(const std::shared_ptr<TautomerEnumeratorResult> &enumerator_result);
+std::unique_ptr<std::vector<unsigned int>>
+tautomer_enumerator_result_modified_atoms(const std::shared_ptr<TautomerEnumeratorResult> &enumerator_result);
+std::unique_ptr<std::vector<unsigned int>>
+tautomer_enumerator_result_modified_bonds(const std::shared_ptr<TautomerEnumeratorResult> &enumerator_result);
 
 // rdkit-Release_2022_03_1/Code/MinimalLib/common.h
 std::shared_ptr<CleanupParameters> default_cleanup_parameters();
 
+rust::String get_cleanup_rdbase(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_rdbase(std::shared_ptr<CleanupParameters> &params, const std::string &what);
+
+rust::String get_cleanup_normalizations(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_normalizations(std::shared_ptr<CleanupParameters> &params, const std::string &what);
+
+rust::String get_cleanup_acidbase_file(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_acidbase_file(std::shared_ptr<CleanupParameters> &params, const std::string &what);
+
+rust::String get_cleanup_fragment_file(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_fragment_file(std::shared_ptr<CleanupParameters> &params, const std::string &what);
+
+rust::String get_cleanup_tautomer_transforms(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_tautomer_transforms(std::shared_ptr<CleanupParameters> &params, const std::string &what);
+
+int get_cleanup_max_restarts(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_max_restarts(std::shared_ptr<CleanupParameters> &params, int what);
+
+int get_cleanup_max_tautomers(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_max_tautomers(std::shared_ptr<CleanupParameters> &params, int what);
+
+int get_cleanup_max_transforms(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_max_transforms(std::shared_ptr<CleanupParameters> &params, int what);
+
+bool get_cleanup_prefer_organic(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_prefer_organic(std::shared_ptr<CleanupParameters> &params, bool what);
+
+bool get_cleanup_do_canonical(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_do_canonical(std::shared_ptr<CleanupParameters> &params, bool what);
+
+bool get_cleanup_tautomer_remove_sp3_stereo(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_tautomer_remove_sp3_stereo(std::shared_ptr<CleanupParameters> &params, bool what);
+
+bool get_cleanup_tautomer_remove_bond_stereo(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_tautomer_remove_bond_stereo(std::shared_ptr<CleanupParameters> &params, bool what);
+
+bool get_cleanup_tautomer_remove_isotopic_hs(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_tautomer_remove_isotopic_hs(std::shared_ptr<CleanupParameters> &params, bool what);
+
+bool get_cleanup_tautomer_reassign_stereo(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_tautomer_reassign_stereo(std::shared_ptr<CleanupParameters> &params, bool what);
+
+bool get_cleanup_largest_fragment_chooser_use_atom_count(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_largest_fragment_chooser_use_atom_count(std::shared_ptr<CleanupParameters> &params, bool what);
+
+bool get_cleanup_largest_fragment_chooser_count_heavy_atoms_only(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_largest_fragment_chooser_count_heavy_atoms_only(std::shared_ptr<CleanupParameters> &params, bool what);
+
+std::unique_ptr<std::vector<std::string>>
+get_cleanup_normalization_data_names(const std::shared_ptr<CleanupParameters> &params);
+std::unique_ptr<std::vector<std::string>>
+get_cleanup_normalization_data_smirks(const std::shared_ptr<CleanupParameters> &params);
+void set_cleanup_normalization_data(std::shared_ptr<CleanupParameters> &params, const rust::Vec<rust::String> &names,
+                                    const rust::Vec<rust::String> &smirks);
+
+// column 0 holds the names, 1 the acids and 2 the bases
+std::unique_ptr<std::vector<std::string>>
+get_cleanup_acidbase_data_column(const std::shared_ptr<CleanupParameters> &params, unsigned int column);
+void set_cleanup_acidbase_data(std::shared_ptr<CleanupParameters> &params, const rust::Vec<rust::String> &names,
+                               const rust::Vec<rust::String> &acids, const rust::Vec<rust::String> &bases);
+
 std::shared_ptr<Uncharger> new_uncharger(bool canonical);
 std::shared_ptr<ROMol> uncharger_uncharge(const std::shared_ptr<Uncharger> &uncharger,
                                           const std::shared_ptr<ROMol> &mol);
 
 std::shared_ptr<RWMol> fragment_parent(const std::shared_ptr<RWMol> &rw_mol,
                                        const std::shared_ptr<CleanupParameters> &cleanup_params, bool skip_standardize);
+std::shared_ptr<RWMol> charge_parent(const std::shared_ptr<RWMol> &rw_mol,
+                                     const std::shared_ptr<CleanupParameters> &cleanup_params, bool skip_standardize);
+std::shared_ptr<RWMol> tautomer_parent(const std::shared_ptr<RWMol> &rw_mol,
+                                       const std::shared_ptr<CleanupParameters> &cleanup_params, bool skip_standardize);
+std::shared_ptr<RWMol> stereo_parent(const std::shared_ptr<RWMol> &rw_mol,
+                                     const std::shared_ptr<CleanupParameters> &cleanup_params, bool skip_standardize);
+std::shared_ptr<RWMol> isotope_parent(const std::shared_ptr<RWMol> &rw_mol,
+                                      const std::shared_ptr<CleanupParameters> &cleanup_params, bool skip_standardize);
+std::shared_ptr<RWMol> super_parent(const std::shared_ptr<RWMol> &rw_mol,
+                                    const std::shared_ptr<CleanupParameters> &cleanup_params, bool skip_standardize);
 
 std::shared_ptr<RWMol> normalize(const std::shared_ptr<RWMol> &mol,
                                  const std::shared_ptr<CleanupParameters> &cleanup_params);
+std::shared_ptr<RWMol> reionize(const std::shared_ptr<RWMol> &mol,
+                                const std::shared_ptr<CleanupParameters> &cleanup_params);
+std::shared_ptr<RWMol> standardize(const std::shared_ptr<RWMol> &mol,
+                                   const std::shared_ptr<CleanupParameters> &cleanup_params);
+
+std::shared_ptr<Normalizer> new_normalizer();
+std::shared_ptr<Normalizer> new_normalizer_from_transforms(const rust::Vec<rust::String> &names,
+                                                           const rust::Vec<rust::String> &smirks,
+                                                           unsigned int max_restarts);
+std::shared_ptr<Normalizer> new_normalizer_from_params(const std::shared_ptr<CleanupParameters> &cleanup_params);
+std::shared_ptr<ROMol> normalizer_normalize(const std::shared_ptr<Normalizer> &normalizer,
+                                            const std::shared_ptr<ROMol> &mol);
+
+std::shared_ptr<Reionizer> new_reionizer();
+std::shared_ptr<Reionizer> new_reionizer_from_pairs(const rust::Vec<rust::String> &names,
+                                                    const rust::Vec<rust::String> &acids,
+                                                    const rust::Vec<rust::String> &bases);
+std::shared_ptr<Reionizer> new_reionizer_from_params(const std::shared_ptr<CleanupParameters> &cleanup_params);
+std::shared_ptr<ROMol> reionizer_reionize(const std::shared_ptr<Reionizer> &reionizer,
+                                          const std::shared_ptr<ROMol> &mol);
+
+std::shared_ptr<MetalDisconnector> new_metal_disconnector(bool split_grignards, bool split_aromatic_c,
+                                                          bool adjust_charges, bool remove_haptic_dummies);
+std::shared_ptr<ROMol> metal_disconnector_disconnect(const std::shared_ptr<MetalDisconnector> &disconnector,
+                                                     const std::shared_ptr<ROMol> &mol);
+
+std::shared_ptr<LargestFragmentChooser> new_largest_fragment_chooser(bool prefer_organic, bool use_atom_count,
+                                                                     bool count_heavy_atoms_only);
+std::shared_ptr<LargestFragmentChooser>
+new_largest_fragment_chooser_from_params(const std::shared_ptr<CleanupParameters> &cleanup_params);
+std::shared_ptr<ROMol> largest_fragment_chooser_choose(const std::shared_ptr<LargestFragmentChooser> &chooser,
+                                                       const std::shared_ptr<ROMol> &mol);
+
+std::shared_ptr<ValidationMethod> new_rdkit_validation();
+std::shared_ptr<ValidationMethod> new_no_atom_validation();
+std::shared_ptr<ValidationMethod> new_fragment_validation();
+std::shared_ptr<ValidationMethod> new_neutral_validation();
+std::shared_ptr<ValidationMethod> new_isotope_validation(bool strict);
+std::shared_ptr<ValidationMethod> new_molvs_validation();
+std::shared_ptr<ValidationMethod> new_allowed_atoms_validation(const rust::Vec<unsigned int> &atomic_nums);
+std::shared_ptr<ValidationMethod> new_disallowed_atoms_validation(const rust::Vec<unsigned int> &atomic_nums);
+std::shared_ptr<ValidationMethod> new_disallowed_radical_validation();
+std::shared_ptr<ValidationMethod> new_stereo_validation();
+std::unique_ptr<std::vector<std::string>> validation_method_validate(const std::shared_ptr<ValidationMethod> &method,
+                                                                     const std::shared_ptr<ROMol> &mol,
+                                                                     bool report_all_failures);
 } // namespace RDKit
\ No newline at end of file
@@ -0,0 +1,19 @@
+#pragma once
+
+#include "rust/cxx.h"
+#include <GraphMol/GraphMol.h>
+#include <GraphMol/Resonance.h>
+
+namespace RDKit {
+std::shared_ptr<ResonanceMolSupplier> new_resonance_mol_supplier(const std::shared_ptr<ROMol> &mol, unsigned int flags,
+                                                                 unsigned int max_structs);
+unsigned int resonance_mol_supplier_length(const std::shared_ptr<ResonanceMolSupplier> &supplier);
+std::shared_ptr<ROMol> resonance_mol_supplier_at(const std::shared_ptr<ResonanceMolSupplier> &supplier,
+                                                 unsigned int idx);
+unsigned int resonance_mol_supplier_num_conj_grps(const std::shared_ptr<ResonanceMolSupplier> &supplier);
+int resonance_mol_supplier_atom_conj_grp_idx(const std::shared_ptr<ResonanceMolSupplier> &supplier,
+                                             unsigned int atom_idx);
+int resonance_mol_supplier_bond_conj_grp_idx(const std::shared_ptr<ResonanceMolSupplier> &supplier,
+                                             unsigned int bond_idx);
+bool resonance_mol_supplier_was_canceled(const std::shared_ptr<ResonanceMolSupplier> &supplier);
+} // namespace RDKit
@@ -0,0 +1,44 @@
+#pragma once
+
+#include "rust/cxx.h"
+#include <GraphMol/GraphMol.h>
+#include <GraphMol/RGroupDecomposition/RGroupDecomp.h>
+
+namespace RDKit {
+using ROMolList = std::vector<ROMOL_SPTR>;
+
+struct RGroupDecomposeResult {
+	RGroupRows rows;
+	std::vector<unsigned int> unmatched;
+};
+
+std::shared_ptr<RGroupDecompositionParameters> new_rgroup_decomposition_parameters();
+void rgroup_decomposition_parameters_set_labels(std::shared_ptr<RGroupDecompositionParameters> &params,
+                                                unsigned int labels);
+void rgroup_decomposition_parameters_set_matching_strategy(std::shared_ptr<RGroupDecompositionParameters> &params,
+                                                           unsigned int strategy);
+void rgroup_decomposition_parameters_set_alignment(std::shared_ptr<RGroupDecompositionParameters> &params,
+                                                   unsigned int alignment);
+bool rgroup_decomposition_parameters_get_remove_all_hydrogen_rgroups(
+    const std::shared_ptr<RGroupDecompositionParameters> &params);
+void rgroup_decomposition_parameters_set_remove_all_hydrogen_rgroups(
+    std::shared_ptr<RGroupDecompositionParameters> &params, bool what);
+bool rgroup_decomposition_parameters_get_only_match_at_rgroups(
+    const std::shared_ptr<RGroupDecompositionParameters> &params);
+void rgroup_decomposition_parameters_set_only_match_at_rgroups(std::shared_ptr<RGroupDecompositionParameters> &params,
+                                                               bool what);
+double rgroup_decomposition_parameters_get_timeout(const std::shared_ptr<RGroupDecompositionParameters> &params);
+void rgroup_decomposition_parameters_set_timeout(std::shared_ptr<RGroupDecompositionParameters> &params,
+                                                 double seconds);
+
+std::shared_ptr<RGroupDecomposeResult> rgroup_decompose(const std::unique_ptr<ROMolList> &cores,
+                                                        const std::unique_ptr<ROMolList> &mols,
+                                                        const std::shared_ptr<RGroupDecompositionParameters> &params);
+unsigned int rgroup_decompose_result_num_rows(const std::shared_ptr<RGroupDecomposeResult> &result);
+std::unique_ptr<std::vector<std::string>>
+rgroup_decompose_result_row_labels(const std::shared_ptr<RGroupDecomposeResult> &result, unsigned int row);
+std::shared_ptr<ROMol> rgroup_decompose_result_row_mol(const std::shared_ptr<RGroupDecomposeResult> &result,
+                                                       unsigned int row, const std::string &label);
+std::unique_ptr<std::vector<unsigned int>>
+rgroup_decompose_result_unmatched(const std::shared_ptr<RGroupDecomposeResult> &result);
+} // namespace RDKit
@@ -6,13 +6,22 @@
 #include <GraphMol/Fingerprints/Fingerprints.h>
 #include <GraphMol/GraphMol.h>
 #include <GraphMol/MolStandardize/Tautomer.h>
+#include <GraphMol/QueryOps.h>
+#include <GraphMol/SmilesParse/SmartsWrite.h>
 #include <GraphMol/SmilesParse/SmilesParse.h>
 #include <GraphMol/SmilesParse/SmilesWrite.h>
 
 namespace RDKit {
 std::shared_ptr<ROMol> copy_mol(const std::shared_ptr<ROMol> &mol);
+
+using ROMolList = std::vector<ROMOL_SPTR>;
+std::unique_ptr<ROMolList> new_ro_mol_list();
+void ro_mol_list_push(ROMolList &list, const std::shared_ptr<ROMol> &mol);
+unsigned int ro_mol_list_len(const std::unique_ptr<ROMolList> &list);
+std::shared_ptr<ROMol> ro_mol_list_get(const std::unique_ptr<ROMolList> &list, unsigned int idx);
 std::shared_ptr<ROMol> smiles_to_mol(const std::string &smiles);
 rust::String mol_to_smiles(const std::shared_ptr<ROMol> &mol);
+rust::String mol_to_smarts(const std::shared_ptr<ROMol> &mol, bool isomeric);
 rust::String mol_to_molblock(const std::shared_ptr<ROMol> &mol);
 
 std::shared_ptr<ROMol> smiles_to_mol_with_params(const std::string &smiles,
@@ -28,6 +37,7 @@ rust::String mol_sanitize_exception_type(const MolSanitizeExceptionUniquePtr &mo
 unsigned int atom_sanitize_exception_get_atom_idx(const MolSanitizeExceptionUniquePtr &mol_except);
 
 unsigned int get_num_atoms(const std::shared_ptr<ROMol> &mol, bool only_explicit);
+unsigned int get_num_conformers(const std::shared_ptr<ROMol> &mol);
 Atom &get_atom_with_idx(std::shared_ptr<ROMol> &mol, unsigned int idx);
 rust::String get_symbol(const Atom &atom);
 bool get_is_aromatic(const Atom &atom);
@@ -56,4 +66,29 @@ rust::String get_prop(const Atom &atom, const std::string &key);
 
 unsigned int get_num_radical_electrons(const Atom &atom);
 unsigned int get_degree(const Atom &atom);
+
+using ChiralType = Atom::ChiralType;
+ChiralType atom_get_chiral_tag(const Atom &atom);
+bool atom_is_in_ring(const Atom &atom);
+unsigned int atom_get_isotope(const Atom &atom);
+bool atom_has_query(const Atom &atom);
+rust::String atom_get_smarts(const Atom &atom);
+rust::String atom_describe_query(const Atom &atom);
+
+unsigned int get_num_bonds(const std::shared_ptr<ROMol> &mol);
+Bond &get_bond_with_idx(std::shared_ptr<ROMol> &mol, unsigned int idx);
+unsigned int get_begin_atom_idx(const Bond &bond);
+unsigned int get_end_atom_idx(const Bond &bond);
+double get_bond_type_as_double(const Bond &bond);
+bool get_bond_is_aromatic(const Bond &bond);
+
+using BondType   = Bond::BondType;
+using BondStereo = Bond::BondStereo;
+BondType get_bond_type(const Bond &bond);
+bool get_bond_is_conjugated(const Bond &bond);
+BondStereo get_bond_stereo(const Bond &bond);
+bool bond_is_in_ring(const Bond &bond);
+bool bond_has_query(const Bond &bond);
+rust::String bond_get_smarts(const Bond &bond);
+rust::String bond_describe_query(const Bond &bond);
 } // namespace RDKit
@@ -24,4 +24,20 @@ void collect_mol_counts(std::shared_ptr<ScaffoldNetworkParams> &params, bool inp
 using ScaffoldNetworkClass = ScaffoldNetwork::ScaffoldNetwork;
 
 std::shared_ptr<ScaffoldNetworkClass> default_scaffold_network();
+void update_scaffold_network(std::shared_ptr<ScaffoldNetworkClass> &network,
+                             const std::unique_ptr<std::vector<ROMOL_SPTR>> &mols,
+                             const std::shared_ptr<ScaffoldNetworkParams> &params);
+std::unique_ptr<std::vector<std::string>> scaffold_network_nodes(const std::shared_ptr<ScaffoldNetworkClass> &network);
+std::unique_ptr<std::vector<unsigned int>>
+scaffold_network_counts(const std::shared_ptr<ScaffoldNetworkClass> &network);
+std::unique_ptr<std::vector<unsigned int>>
+scaffold_network_mol_counts(const std::shared_ptr<ScaffoldNetworkClass> &network);
+
+using EdgeType    = ScaffoldNetwork::EdgeType;
+using NetworkEdge = ScaffoldNetwork::NetworkEdge;
+
+std::unique_ptr<std::vector<NetworkEdge>> scaffold_network_edges(const std::shared_ptr<ScaffoldNetworkClass> &network);
+unsigned int network_edge_begin_idx(const NetworkEdge &edge);
+unsigned int network_edge_end_idx(const NetworkEdge &edge);
+EdgeType network_edge_type(const NetworkEdge &edge);
 } // namespace RDKit
\ No newline at end of file
@@ -0,0 +1,25 @@
+#pragma once
+
+#include "rust/cxx.h"
+#include <GraphMol/GraphMol.h>
+#include <GraphMol/Substruct/SubstructMatch.h>
+#include <GraphMol/SubstructLibrary/SubstructLibrary.h>
+
+namespace RDKit {
+std::shared_ptr<SubstructLibrary> new_substruct_library();
+std::shared_ptr<SubstructLibrary> new_substruct_library_from_pickle(rust::Slice<const uint8_t> pickle);
+rust::Vec<uint8_t> substruct_library_pickle(const std::shared_ptr<SubstructLibrary> &library);
+unsigned int substruct_library_add_mol(std::shared_ptr<SubstructLibrary> &library, const std::shared_ptr<ROMol> &mol);
+unsigned int substruct_library_size(const std::shared_ptr<SubstructLibrary> &library);
+std::shared_ptr<ROMol> substruct_library_get_mol(const std::shared_ptr<SubstructLibrary> &library, unsigned int idx);
+std::unique_ptr<std::vector<unsigned int>>
+substruct_library_get_matches(const std::shared_ptr<SubstructLibrary> &library, const std::shared_ptr<ROMol> &query,
+                              const std::shared_ptr<SubstructMatchParameters> &params, int num_threads,
+                              int max_results);
+unsigned int substruct_library_count_matches(const std::shared_ptr<SubstructLibrary> &library,
+                                             const std::shared_ptr<ROMol> &query,
+                                             const std::shared_ptr<SubstructMatchParameters> &params,
+                                             int num_threads);
+bool substruct_library_has_match(const std::shared_ptr<SubstructLibrary> &library, const std::shared_ptr<ROMol> &query,
+                                 const std::shared_ptr<SubstructMatchParameters> &params, int num_threads);
+} // namespace RDKit
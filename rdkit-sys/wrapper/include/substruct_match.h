@@ -2,15 +2,40 @@
 
 #include "rust/cxx.h"
 #include <GraphMol/GraphMol.h>
+#include <GraphMol/Resonance.h>
 #include <GraphMol/Substruct/SubstructMatch.h>
 
 namespace RDKit {
+struct SubstructFinalCheck;
+
 using SubstructMatchItem = std::pair<int, int>;
 using MatchVectType      = MatchVectType;
 
 std::unique_ptr<std::vector<MatchVectType>> substruct_match(const std::shared_ptr<ROMol> &mol,
                                                             const std::shared_ptr<ROMol> &other_mol,
                                                             const std::shared_ptr<SubstructMatchParameters> &params);
+std::unique_ptr<std::vector<MatchVectType>>
+substruct_match_resonance(const std::shared_ptr<ResonanceMolSupplier> &supplier,
+                          const std::shared_ptr<ROMol> &other_mol,
+                          const std::shared_ptr<SubstructMatchParameters> &params);
+
+bool has_substruct_match(const std::shared_ptr<ROMol> &mol, const std::shared_ptr<ROMol> &other_mol,
+                         const std::shared_ptr<SubstructMatchParameters> &params);
+bool has_substruct_match_resonance(const std::shared_ptr<ResonanceMolSupplier> &supplier,
+                                   const std::shared_ptr<ROMol> &other_mol,
+                                   const std::shared_ptr<SubstructMatchParameters> &params);
+unsigned int count_substruct_matches(const std::shared_ptr<ROMol> &mol, const std::shared_ptr<ROMol> &other_mol,
+                                     const std::shared_ptr<SubstructMatchParameters> &params);
+unsigned int count_substruct_matches_resonance(const std::shared_ptr<ResonanceMolSupplier> &supplier,
+                                               const std::shared_ptr<ROMol> &other_mol,
+                                               const std::shared_ptr<SubstructMatchParameters> &params);
+std::unique_ptr<std::vector<SubstructMatchItem>>
+substruct_match_first(const std::shared_ptr<ROMol> &mol, const std::shared_ptr<ROMol> &other_mol,
+                      const std::shared_ptr<SubstructMatchParameters> &params);
+std::unique_ptr<std::vector<SubstructMatchItem>>
+substruct_match_first_resonance(const std::shared_ptr<ResonanceMolSupplier> &supplier,
+                                const std::shared_ptr<ROMol> &other_mol,
+                                const std::shared_ptr<SubstructMatchParameters> &params);
 
 std::shared_ptr<SubstructMatchParameters> new_substruct_match_parameters();
 bool get_use_chirality(const std::shared_ptr<SubstructMatchParameters> &params);
@@ -21,6 +46,11 @@ bool get_use_generic_matchers(const std::shared_ptr<SubstructMatchParameters> &p
 bool get_recursion_possible(const std::shared_ptr<SubstructMatchParameters> &params);
 bool get_uniquify(const std::shared_ptr<SubstructMatchParameters> &params);
 unsigned int get_max_matches(const std::shared_ptr<SubstructMatchParameters> &params);
+std::unique_ptr<std::vector<std::string>> get_atom_properties(const std::shared_ptr<SubstructMatchParameters> &params);
+std::unique_ptr<std::vector<std::string>> get_bond_properties(const std::shared_ptr<SubstructMatchParameters> &params);
+bool get_specified_stereo_query_matches_unspecified(const std::shared_ptr<SubstructMatchParameters> &params);
+unsigned int get_max_recursive_matches(const std::shared_ptr<SubstructMatchParameters> &params);
+int get_num_threads(const std::shared_ptr<SubstructMatchParameters> &params);
 void set_use_chirality(std::shared_ptr<SubstructMatchParameters> &params, bool what);
 void set_use_enhanced_stereo(std::shared_ptr<SubstructMatchParameters> &params, bool what);
 void set_aromatic_matches_conjugated(std::shared_ptr<SubstructMatchParameters> &params, bool what);
@@ -34,4 +64,10 @@ substruct_matchvect_type_to_vec_substruct_match_item(const MatchVectType &match_
 int substruct_match_item_query_atom_idx(const SubstructMatchItem &item);
 int substruct_match_item_mol_atom_idx(const SubstructMatchItem &item);
 void set_max_matches(std::shared_ptr<SubstructMatchParameters> &params, unsigned int value);
+void set_atom_properties(std::shared_ptr<SubstructMatchParameters> &params, const rust::Vec<rust::String> &props);
+void set_bond_properties(std::shared_ptr<SubstructMatchParameters> &params, const rust::Vec<rust::String> &props);
+void set_specified_stereo_query_matches_unspecified(std::shared_ptr<SubstructMatchParameters> &params, bool what);
+void set_max_recursive_matches(std::shared_ptr<SubstructMatchParameters> &params, unsigned int value);
+void set_num_threads(std::shared_ptr<SubstructMatchParameters> &params, int value);
+void set_extra_final_check(std::shared_ptr<SubstructMatchParameters> &params, rust::Box<SubstructFinalCheck> check);
 } // namespace RDKit
\ No newline at end of file
@@ -0,0 +1,60 @@
+#include "rust/cxx.h"
+#include <GraphMol/ChemTransforms/ChemTransforms.h>
+#include <GraphMol/GraphMol.h>
+#include <GraphMol/MolOps.h>
+
+namespace RDKit {
+using ROMolList = std::vector<ROMOL_SPTR>;
+
+std::shared_ptr<ROMol> delete_substructs(const std::shared_ptr<ROMol> &mol, const std::shared_ptr<ROMol> &query,
+                                         bool only_frags, bool use_chirality) {
+	return std::shared_ptr<ROMol>(deleteSubstructs(*mol, *query, only_frags, use_chirality));
+}
+
+std::unique_ptr<ROMolList> replace_substructs(const std::shared_ptr<ROMol> &mol, const std::shared_ptr<ROMol> &query,
+                                              const std::shared_ptr<ROMol> &replacement, bool replace_all,
+                                              unsigned int replacement_connection_point, bool use_chirality) {
+	ROMolList products =
+	    replaceSubstructs(*mol, *query, *replacement, replace_all, replacement_connection_point, use_chirality);
+	return std::unique_ptr<ROMolList>(new ROMolList(products));
+}
+
+std::shared_ptr<ROMol> replace_sidechains(const std::shared_ptr<ROMol> &mol, const std::shared_ptr<ROMol> &core,
+                                          bool use_chirality) {
+	return std::shared_ptr<ROMol>(replaceSidechains(*mol, *core, use_chirality));
+}
+
+std::shared_ptr<ROMol> replace_core(const std::shared_ptr<ROMol> &mol, const std::shared_ptr<ROMol> &core,
+                                    bool replace_dummies, bool label_by_index, bool require_dummy_match,
+                                    bool use_chirality) {
+	return std::shared_ptr<ROMol>(
+	    replaceCore(*mol, *core, replace_dummies, label_by_index, require_dummy_match, use_chirality));
+}
+
+std::shared_ptr<ROMol> murcko_decompose(const std::shared_ptr<ROMol> &mol) {
+	return std::shared_ptr<ROMol>(MurckoDecompose(*mol));
+}
+
+// same as MakeScaffoldGeneric from rdkit.Chem.Scaffolds.MurckoScaffold
+std::shared_ptr<ROMol> make_scaffold_generic(const std::shared_ptr<ROMol> &mol) {
+	RWMol *generic = new RWMol(*mol);
+	for (Atom *atom : generic->atoms()) {
+		if (atom->getAtomicNum() != 1) {
+			atom->setAtomicNum(6);
+		}
+		atom->setIsAromatic(false);
+		atom->setFormalCharge(0);
+		atom->setIsotope(0);
+		atom->setChiralTag(Atom::CHI_UNSPECIFIED);
+		atom->setNoImplicit(false);
+		atom->setNumExplicitHs(0);
+	}
+	for (Bond *bond : generic->bonds()) {
+		bond->setBondType(Bond::SINGLE);
+		bond->setIsAromatic(false);
+	}
+	std::shared_ptr<ROMol> result(generic);
+	MolOps::removeHs(*generic);
+	return result;
+}
+} // namespace RDKit
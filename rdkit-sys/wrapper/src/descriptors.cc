@@ -1,5 +1,20 @@
 #include "rust/cxx.h"
+#include "rdkit-sys/src/bridge/descriptors.rs.h"
+#include <GraphMol/Descriptors/AUTOCORR2D.h>
+#include <GraphMol/Descriptors/AUTOCORR3D.h>
+#include <GraphMol/Descriptors/BCUT.h>
+#include <GraphMol/Descriptors/Crippen.h>
+#include <GraphMol/Descriptors/GETAWAY.h>
+#include <GraphMol/Descriptors/MORSE.h>
+#include <GraphMol/Descriptors/MQN.h>
+#include <GraphMol/Descriptors/MolDescriptors.h>
+#include <GraphMol/Descriptors/MolSurf.h>
+#include <GraphMol/Descriptors/PBF.h>
+#include <GraphMol/Descriptors/PMI.h>
 #include <GraphMol/Descriptors/Property.h>
+#include <GraphMol/Descriptors/RDF.h>
+#include <GraphMol/Descriptors/WHIM.h>
+#include <GraphMol/PartialCharges/GasteigerCharges.h>
 #include <GraphMol/ROMol.h>
 
 namespace RDKit {
@@ -7,6 +22,19 @@ using Descriptors::Properties;
 
 std::shared_ptr<Properties> new_properties() { return std::shared_ptr<Properties>(new Properties()); }
 
+std::shared_ptr<Properties> new_properties_with_names(const rust::Vec<rust::String> &names) {
+	std::vector<std::string> cc_names;
+	cc_names.reserve(names.size());
+	for (auto name : names) { cc_names.push_back(std::string(name)); }
+
+	return std::shared_ptr<Properties>(new Properties(cc_names));
+}
+
+std::unique_ptr<std::vector<std::string>> get_available_properties() {
+	std::vector<std::string> names = Properties::getAvailableProperties();
+	return std::unique_ptr<std::vector<std::string>>(new std::vector<std::string>(names));
+}
+
 std::unique_ptr<std::vector<std::string>> get_property_names(const std::shared_ptr<Properties> &props) {
 	std::vector<std::string> names       = props->getPropertyNames();
 	std::vector<std::string> *names_heap = new std::vector<std::string>(names);
@@ -19,4 +47,153 @@ std::unique_ptr<std::vector<double>> compute_properties(const std::shared_ptr<Pr
 	auto computed_heap           = new std::vector<double>(computed);
 	return std::unique_ptr<std::vector<double>>(computed_heap);
 }
+
+rust::String calc_mol_formula(const std::shared_ptr<ROMol> &mol, bool separate_isotopes, bool abbreviate_h_isotopes) {
+	return Descriptors::calcMolFormula(*mol, separate_isotopes, abbreviate_h_isotopes);
+}
+
+double calc_exact_mw(const std::shared_ptr<ROMol> &mol, bool only_heavy) {
+	return Descriptors::calcExactMW(*mol, only_heavy);
+}
+
+CrippenAtomContribs get_crippen_atom_contribs(const std::shared_ptr<ROMol> &mol) {
+	std::vector<double> logp_contribs(mol->getNumAtoms());
+	std::vector<double> mr_contribs(mol->getNumAtoms());
+	std::vector<unsigned int> atom_types(mol->getNumAtoms());
+	std::vector<std::string> atom_type_labels(mol->getNumAtoms());
+	Descriptors::getCrippenAtomContribs(*mol, logp_contribs, mr_contribs, true, &atom_types, &atom_type_labels);
+
+	CrippenAtomContribs contribs;
+	for (double logp : logp_contribs) { contribs.logp.push_back(logp); }
+	for (double mr : mr_contribs) { contribs.mr.push_back(mr); }
+	for (const auto &label : atom_type_labels) { contribs.atom_type_labels.push_back(rust::String(label)); }
+	return contribs;
+}
+
+std::unique_ptr<std::vector<double>> get_tpsa_atom_contribs(const std::shared_ptr<ROMol> &mol, bool include_s_and_p) {
+	std::vector<double> contribs(mol->getNumAtoms());
+	Descriptors::getTPSAAtomContribs(*mol, contribs, true, include_s_and_p);
+	return std::unique_ptr<std::vector<double>>(new std::vector<double>(contribs));
+}
+
+LabuteAtomContribs get_labute_atom_contribs(const std::shared_ptr<ROMol> &mol, bool include_hs) {
+	std::vector<double> atom_contribs(mol->getNumAtoms());
+	double h_contrib = 0.0;
+	Descriptors::getLabuteAtomContribs(*mol, atom_contribs, h_contrib, include_hs, true);
+
+	LabuteAtomContribs contribs;
+	for (double contrib : atom_contribs) { contribs.atoms.push_back(contrib); }
+	contribs.hydrogens = h_contrib;
+	return contribs;
+}
+
+std::unique_ptr<std::vector<double>> compute_gasteiger_charges(const std::shared_ptr<ROMol> &mol, int n_iter,
+                                                               bool throw_on_param_failure) {
+	computeGasteigerCharges(*mol, n_iter, throw_on_param_failure);
+
+	std::vector<double> *charges = new std::vector<double>();
+	charges->reserve(mol->getNumAtoms());
+	for (const auto atom : mol->atoms()) {
+		charges->push_back(atom->getProp<double>(common_properties::_GasteigerCharge));
+	}
+	return std::unique_ptr<std::vector<double>>(charges);
+}
+
+double calc_pmi1(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses) {
+	return Descriptors::calcPMI1(*mol, conf_id, use_atomic_masses, true);
+}
+
+double calc_pmi2(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses) {
+	return Descriptors::calcPMI2(*mol, conf_id, use_atomic_masses, true);
+}
+
+double calc_pmi3(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses) {
+	return Descriptors::calcPMI3(*mol, conf_id, use_atomic_masses, true);
+}
+
+double calc_npr1(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses) {
+	return Descriptors::calcNPR1(*mol, conf_id, use_atomic_masses, true);
+}
+
+double calc_npr2(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses) {
+	return Descriptors::calcNPR2(*mol, conf_id, use_atomic_masses, true);
+}
+
+double calc_radius_of_gyration(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses) {
+	return Descriptors::calcRadiusOfGyration(*mol, conf_id, use_atomic_masses, true);
+}
+
+double calc_inertial_shape_factor(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses) {
+	return Descriptors::calcInertialShapeFactor(*mol, conf_id, use_atomic_masses, true);
+}
+
+double calc_eccentricity(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses) {
+	return Descriptors::calcEccentricity(*mol, conf_id, use_atomic_masses, true);
+}
+
+double calc_asphericity(const std::shared_ptr<ROMol> &mol, int conf_id, bool use_atomic_masses) {
+	return Descriptors::calcAsphericity(*mol, conf_id, use_atomic_masses, true);
+}
+
+double calc_spherocity_index(const std::shared_ptr<ROMol> &mol, int conf_id) {
+	return Descriptors::calcSpherocityIndex(*mol, conf_id, true);
+}
+
+double calc_pbf(const std::shared_ptr<ROMol> &mol, int conf_id) { return Descriptors::calcPBF(*mol, conf_id); }
+
+std::unique_ptr<std::vector<double>> calc_bcut2d(const std::shared_ptr<ROMol> &mol) {
+	return std::unique_ptr<std::vector<double>>(new std::vector<double>(Descriptors::BCUT2D(*mol)));
+}
+
+std::unique_ptr<std::vector<double>> calc_autocorr_2d(const std::shared_ptr<ROMol> &mol) {
+	std::vector<double> *res = new std::vector<double>();
+	Descriptors::AUTOCORR2D(*mol, *res);
+	return std::unique_ptr<std::vector<double>>(res);
+}
+
+std::unique_ptr<std::vector<unsigned int>> calc_mqns(const std::shared_ptr<ROMol> &mol) {
+	return std::unique_ptr<std::vector<unsigned int>>(new std::vector<unsigned int>(Descriptors::calcMQNs(*mol, true)));
+}
+
+std::unique_ptr<std::vector<double>> calc_peoe_vsa(const std::shared_ptr<ROMol> &mol) {
+	return std::unique_ptr<std::vector<double>>(new std::vector<double>(Descriptors::calcPEOE_VSA(*mol, nullptr, true)));
+}
+
+std::unique_ptr<std::vector<double>> calc_smr_vsa(const std::shared_ptr<ROMol> &mol) {
+	return std::unique_ptr<std::vector<double>>(new std::vector<double>(Descriptors::calcSMR_VSA(*mol, nullptr, true)));
+}
+
+std::unique_ptr<std::vector<double>> calc_slogp_vsa(const std::shared_ptr<ROMol> &mol) {
+	return std::unique_ptr<std::vector<double>>(new std::vector<double>(Descriptors::calcSlogP_VSA(*mol, nullptr, true)));
+}
+
+std::unique_ptr<std::vector<double>> calc_autocorr_3d(const std::shared_ptr<ROMol> &mol, int conf_id) {
+	std::vector<double> *res = new std::vector<double>();
+	Descriptors::AUTOCORR3D(*mol, *res, conf_id);
+	return std::unique_ptr<std::vector<double>>(res);
+}
+
+std::unique_ptr<std::vector<double>> calc_rdf(const std::shared_ptr<ROMol> &mol, int conf_id) {
+	std::vector<double> *res = new std::vector<double>();
+	Descriptors::RDF(*mol, *res, conf_id);
+	return std::unique_ptr<std::vector<double>>(res);
+}
+
+std::unique_ptr<std::vector<double>> calc_morse(const std::shared_ptr<ROMol> &mol, int conf_id) {
+	std::vector<double> *res = new std::vector<double>();
+	Descriptors::MORSE(*mol, *res, conf_id);
+	return std::unique_ptr<std::vector<double>>(res);
+}
+
+std::unique_ptr<std::vector<double>> calc_whim(const std::shared_ptr<ROMol> &mol, int conf_id, double threshold) {
+	std::vector<double> *res = new std::vector<double>();
+	Descriptors::WHIM(*mol, *res, conf_id, threshold);
+	return std::unique_ptr<std::vector<double>>(res);
+}
+
+std::unique_ptr<std::vector<double>> calc_getaway(const std::shared_ptr<ROMol> &mol, int conf_id, unsigned int precision) {
+	std::vector<double> *res = new std::vector<double>();
+	Descriptors::GETAWAY(*mol, *res, conf_id, precision);
+	return std::unique_ptr<std::vector<double>>(res);
+}
 } // namespace RDKit
\ No newline at end of file
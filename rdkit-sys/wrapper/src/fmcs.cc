@@ -0,0 +1,86 @@
+#include "rust/cxx.h"
+#include <GraphMol/FMCS/FMCS.h>
+#include <GraphMol/GraphMol.h>
+
+namespace RDKit {
+using ROMolList = std::vector<ROMOL_SPTR>;
+
+std::shared_ptr<MCSParameters> new_mcs_parameters() {
+	return std::shared_ptr<MCSParameters>(new MCSParameters());
+}
+
+void mcs_parameters_set_atom_comparator(std::shared_ptr<MCSParameters> &params, AtomComparator comparator) {
+	params->setMCSAtomTyperFromEnum(comparator);
+}
+
+void mcs_parameters_set_bond_comparator(std::shared_ptr<MCSParameters> &params, BondComparator comparator) {
+	params->setMCSBondTyperFromEnum(comparator);
+}
+
+bool mcs_parameters_get_ring_matches_ring_only(const std::shared_ptr<MCSParameters> &params) {
+	return params->AtomCompareParameters.RingMatchesRingOnly;
+}
+
+void mcs_parameters_set_ring_matches_ring_only(std::shared_ptr<MCSParameters> &params, bool what) {
+	params->AtomCompareParameters.RingMatchesRingOnly = what;
+	params->BondCompareParameters.RingMatchesRingOnly = what;
+}
+
+bool mcs_parameters_get_complete_rings_only(const std::shared_ptr<MCSParameters> &params) {
+	return params->AtomCompareParameters.CompleteRingsOnly;
+}
+
+void mcs_parameters_set_complete_rings_only(std::shared_ptr<MCSParameters> &params, bool what) {
+	params->AtomCompareParameters.CompleteRingsOnly = what;
+	params->BondCompareParameters.CompleteRingsOnly = what;
+}
+
+bool mcs_parameters_get_match_valences(const std::shared_ptr<MCSParameters> &params) {
+	return params->AtomCompareParameters.MatchValences;
+}
+
+void mcs_parameters_set_match_valences(std::shared_ptr<MCSParameters> &params, bool what) {
+	params->AtomCompareParameters.MatchValences = what;
+}
+
+bool mcs_parameters_get_match_chiral_tag(const std::shared_ptr<MCSParameters> &params) {
+	return params->AtomCompareParameters.MatchChiralTag;
+}
+
+void mcs_parameters_set_match_chiral_tag(std::shared_ptr<MCSParameters> &params, bool what) {
+	params->AtomCompareParameters.MatchChiralTag = what;
+}
+
+unsigned int mcs_parameters_get_timeout(const std::shared_ptr<MCSParameters> &params) { return params->Timeout; }
+
+void mcs_parameters_set_timeout(std::shared_ptr<MCSParameters> &params, unsigned int seconds) {
+	params->Timeout = seconds;
+}
+
+double mcs_parameters_get_threshold(const std::shared_ptr<MCSParameters> &params) { return params->Threshold; }
+
+void mcs_parameters_set_threshold(std::shared_ptr<MCSParameters> &params, double threshold) {
+	params->Threshold = threshold;
+}
+
+std::shared_ptr<MCSResult> find_mcs(const std::unique_ptr<ROMolList> &mols,
+                                    const std::shared_ptr<MCSParameters> &params) {
+	return std::shared_ptr<MCSResult>(new MCSResult(findMCS(*mols, params.get())));
+}
+
+rust::String mcs_result_smarts(const std::shared_ptr<MCSResult> &result) { return result->SmartsString; }
+
+unsigned int mcs_result_num_atoms(const std::shared_ptr<MCSResult> &result) { return result->NumAtoms; }
+
+unsigned int mcs_result_num_bonds(const std::shared_ptr<MCSResult> &result) { return result->NumBonds; }
+
+// set when the timeout was hit, the result is then the best found so far
+bool mcs_result_canceled(const std::shared_ptr<MCSResult> &result) { return result->Canceled; }
+
+std::shared_ptr<ROMol> mcs_result_query_mol(const std::shared_ptr<MCSResult> &result) {
+	if (!result->QueryMol) {
+		return std::shared_ptr<ROMol>();
+	}
+	return std::shared_ptr<ROMol>(new ROMol(*result->QueryMol));
+}
+} // namespace RDKit
@@ -105,5 +105,16 @@ std::shared_ptr<ROMol> add_hs(const std::shared_ptr<ROMol> &mol, bool explicit_o
 
 void romol_set_hybridization(std::shared_ptr<ROMol> &mol) { MolOps::setHybridization(*mol); }
 
+int mol_get_formal_charge(const std::shared_ptr<ROMol> &mol) { return MolOps::getFormalCharge(*mol); }
+
+std::unique_ptr<std::vector<double>> get_distance_matrix(const std::shared_ptr<ROMol> &mol, bool use_bond_order) {
+	unsigned int num_atoms = mol->getNumAtoms();
+	// the matrix is owned and cached by the molecule, so copy it out
+	const double *distances = MolOps::getDistanceMat(*mol, use_bond_order, false, true);
+	return std::unique_ptr<std::vector<double>>(new std::vector<double>(distances, distances + num_atoms * num_atoms));
+}
+
+double compute_balaban_j(const std::shared_ptr<ROMol> &mol) { return MolOps::computeBalabanJ(*mol, true, true); }
+
 void clean_up(std::shared_ptr<RWMol> &rw_mol) { MolOps::cleanUp(*rw_mol); }
 } // namespace RDKit
\ No newline at end of file
@@ -1,24 +1,106 @@
 #include <vector>
 
 #include "rust/cxx.h"
+#include "rdkit-sys/src/bridge/mol_standardize.rs.h"
 #include <GraphMol/GraphMol.h>
 #include <GraphMol/MolStandardize/Charge.h>
+#include <GraphMol/MolStandardize/Fragment.h>
+#include <GraphMol/MolStandardize/MetalDisconnector.h>
+#include <GraphMol/MolStandardize/MolStandardize.h>
+#include <GraphMol/MolStandardize/Normalize.h>
 #include <GraphMol/MolStandardize/Tautomer.h>
+#include <GraphMol/MolStandardize/Validate.h>
 
 namespace RDKit {
 using ROMol                    = RDKit::ROMol;
 using TautomerCatalogParams    = MolStandardize::TautomerCatalogParams;
 using TautomerCatalog          = MolStandardize::TautomerCatalog;
 using TautomerEnumerator       = MolStandardize::TautomerEnumerator;
+using TautomerEnumeratorStatus = MolStandardize::TautomerEnumeratorStatus;
 using TautomerEnumeratorResult = MolStandardize::TautomerEnumeratorResult;
 using CleanupParameters        = MolStandardize::CleanupParameters;
 using Uncharger                = MolStandardize::Uncharger;
+using Normalizer               = MolStandardize::Normalizer;
+using Reionizer                = MolStandardize::Reionizer;
+using MetalDisconnector        = MolStandardize::MetalDisconnector;
+using LargestFragmentChooser   = MolStandardize::LargestFragmentChooser;
+using ValidationMethod         = MolStandardize::ValidationMethod;
 
 std::shared_ptr<TautomerEnumerator> tautomer_enumerator() {
 	TautomerEnumerator *enumerator = new MolStandardize::TautomerEnumerator();
 	return std::shared_ptr<TautomerEnumerator>(enumerator);
 }
 
+std::shared_ptr<TautomerEnumerator>
+new_tautomer_enumerator_from_params(const std::shared_ptr<CleanupParameters> &cleanup_params) {
+	return std::shared_ptr<TautomerEnumerator>(new TautomerEnumerator(*cleanup_params));
+}
+
+unsigned int tautomer_enumerator_get_max_tautomers(const std::shared_ptr<TautomerEnumerator> &enumerator) {
+	return enumerator->getMaxTautomers();
+}
+void tautomer_enumerator_set_max_tautomers(std::shared_ptr<TautomerEnumerator> &enumerator, unsigned int what) {
+	enumerator->setMaxTautomers(what);
+}
+
+unsigned int tautomer_enumerator_get_max_transforms(const std::shared_ptr<TautomerEnumerator> &enumerator) {
+	return enumerator->getMaxTransforms();
+}
+void tautomer_enumerator_set_max_transforms(std::shared_ptr<TautomerEnumerator> &enumerator, unsigned int what) {
+	enumerator->setMaxTransforms(what);
+}
+
+bool tautomer_enumerator_get_remove_sp3_stereo(const std::shared_ptr<TautomerEnumerator> &enumerator) {
+	return enumerator->getRemoveSp3Stereo();
+}
+void tautomer_enumerator_set_remove_sp3_stereo(std::shared_ptr<TautomerEnumerator> &enumerator, bool what) {
+	enumerator->setRemoveSp3Stereo(what);
+}
+
+bool tautomer_enumerator_get_remove_bond_stereo(const std::shared_ptr<TautomerEnumerator> &enumerator) {
+	return enumerator->getRemoveBondStereo();
+}
+void tautomer_enumerator_set_remove_bond_stereo(std::shared_ptr<TautomerEnumerator> &enumerator, bool what) {
+	enumerator->setRemoveBondStereo(what);
+}
+
+bool tautomer_enumerator_get_remove_isotopic_hs(const std::shared_ptr<TautomerEnumerator> &enumerator) {
+	return enumerator->getRemoveIsotopicHs();
+}
+void tautomer_enumerator_set_remove_isotopic_hs(std::shared_ptr<TautomerEnumerator> &enumerator, bool what) {
+	enumerator->setRemoveIsotopicHs(what);
+}
+
+bool tautomer_enumerator_get_reassign_stereo(const std::shared_ptr<TautomerEnumerator> &enumerator) {
+	return enumerator->getReassignStereo();
+}
+void tautomer_enumerator_set_reassign_stereo(std::shared_ptr<TautomerEnumerator> &enumerator, bool what) {
+	enumerator->setReassignStereo(what);
+}
+
+// forwards RDKit's progress callbacks to a Rust closure
+class RustTautomerCallback : public MolStandardize::TautomerEnumeratorCallback {
+public:
+	RustTautomerCallback(rust::Box<TautomerCallback> callback) : callback(std::move(callback)) {}
+
+	bool operator()(const ROMol &, const TautomerEnumeratorResult &result) override {
+		return tautomer_callback_call(*callback, result.size());
+	}
+
+private:
+	rust::Box<TautomerCallback> callback;
+};
+
+void tautomer_enumerator_set_callback(std::shared_ptr<TautomerEnumerator> &enumerator,
+                                      rust::Box<TautomerCallback> callback) {
+	// the enumerator takes ownership of the callback
+	enumerator->setCallback(new RustTautomerCallback(std::move(callback)));
+}
+
+int tautomer_enumerator_score_tautomer(const std::shared_ptr<ROMol> &mol) {
+	return TautomerEnumerator::scoreTautomer(*mol);
+}
+
 std::shared_ptr<TautomerEnumeratorResult> tautomer_enumerate(const std::shared_ptr<TautomerEnumerator> &enumerator,
                                                              const std::shared_ptr<ROMol> &mol) {
 	TautomerEnumeratorResult stacked_enumerator = enumerator->enumerate(*mol);
@@ -42,11 +124,183 @@ tautomer_enumerator_result_tautomers_at(const std::shared_ptr<TautomerEnumerator
 	return std::shared_ptr<ROMol>(new ROMol(*sptr));
 }
 
+TautomerEnumeratorStatus
+tautomer_enumerator_result_status(const std::shared_ptr<TautomerEnumeratorResult> &enumerator_result) {
+	return enumerator_result->status();
+}
+
+// in the same order as the tautomers
+std::unique_ptr<std::vector<std::string>>
+tautomer_enumerator_result_smiles(const std::shared_ptr<TautomerEnumeratorResult> &enumerator_result) {
+	return std::unique_ptr<std::vector<std::string>>(new std::vector<std::string>(enumerator_result->smiles()));
+}
+
+static std::unique_ptr<std::vector<unsigned int>> set_bits(const boost::dynamic_bitset<> &bits) {
+	auto indices = std::unique_ptr<std::vector<unsigned int>>(new std::vector<unsigned int>);
+	for (size_t idx = bits.find_first(); idx != boost::dynamic_bitset<>::npos; idx = bits.find_next(idx)) {
+		indices->push_back(idx);
+	}
+	return indices;
+}
+
+std::unique_ptr<std::vector<unsigned int>>
+tautomer_enumerator_result_modified_atoms(const std::shared_ptr<TautomerEnumeratorResult> &enumerator_result) {
+	return set_bits(enumerator_result->modifiedAtoms());
+}
+
+std::unique_ptr<std::vector<unsigned int>>
+tautomer_enumerator_result_modified_bonds(const std::shared_ptr<TautomerEnumeratorResult> &enumerator_result) {
+	return set_bits(enumerator_result->modifiedBonds());
+}
+
 std::shared_ptr<CleanupParameters> default_cleanup_parameters() {
 	CleanupParameters *heap_cp = new CleanupParameters(MolStandardize::defaultCleanupParameters);
 	return std::shared_ptr<CleanupParameters>(heap_cp);
 }
 
+rust::String get_cleanup_rdbase(const std::shared_ptr<CleanupParameters> &params) {
+	return rust::String(params->rdbaseDir);
+}
+void set_cleanup_rdbase(std::shared_ptr<CleanupParameters> &params, const std::string &what) {
+	params->rdbaseDir = what;
+}
+
+rust::String get_cleanup_normalizations(const std::shared_ptr<CleanupParameters> &params) {
+	return rust::String(params->normalizations);
+}
+void set_cleanup_normalizations(std::shared_ptr<CleanupParameters> &params, const std::string &what) {
+	params->normalizations = what;
+}
+
+rust::String get_cleanup_acidbase_file(const std::shared_ptr<CleanupParameters> &params) {
+	return rust::String(params->acidbaseFile);
+}
+void set_cleanup_acidbase_file(std::shared_ptr<CleanupParameters> &params, const std::string &what) {
+	params->acidbaseFile = what;
+}
+
+rust::String get_cleanup_fragment_file(const std::shared_ptr<CleanupParameters> &params) {
+	return rust::String(params->fragmentFile);
+}
+void set_cleanup_fragment_file(std::shared_ptr<CleanupParameters> &params, const std::string &what) {
+	params->fragmentFile = what;
+}
+
+rust::String get_cleanup_tautomer_transforms(const std::shared_ptr<CleanupParameters> &params) {
+	return rust::String(params->tautomerTransforms);
+}
+void set_cleanup_tautomer_transforms(std::shared_ptr<CleanupParameters> &params, const std::string &what) {
+	params->tautomerTransforms = what;
+}
+
+int get_cleanup_max_restarts(const std::shared_ptr<CleanupParameters> &params) { return params->maxRestarts; }
+void set_cleanup_max_restarts(std::shared_ptr<CleanupParameters> &params, int what) { params->maxRestarts = what; }
+
+int get_cleanup_max_tautomers(const std::shared_ptr<CleanupParameters> &params) { return params->maxTautomers; }
+void set_cleanup_max_tautomers(std::shared_ptr<CleanupParameters> &params, int what) { params->maxTautomers = what; }
+
+int get_cleanup_max_transforms(const std::shared_ptr<CleanupParameters> &params) { return params->maxTransforms; }
+void set_cleanup_max_transforms(std::shared_ptr<CleanupParameters> &params, int what) { params->maxTransforms = what; }
+
+bool get_cleanup_prefer_organic(const std::shared_ptr<CleanupParameters> &params) { return params->preferOrganic; }
+void set_cleanup_prefer_organic(std::shared_ptr<CleanupParameters> &params, bool what) { params->preferOrganic = what; }
+
+bool get_cleanup_do_canonical(const std::shared_ptr<CleanupParameters> &params) { return params->doCanonical; }
+void set_cleanup_do_canonical(std::shared_ptr<CleanupParameters> &params, bool what) { params->doCanonical = what; }
+
+bool get_cleanup_tautomer_remove_sp3_stereo(const std::shared_ptr<CleanupParameters> &params) {
+	return params->tautomerRemoveSp3Stereo;
+}
+void set_cleanup_tautomer_remove_sp3_stereo(std::shared_ptr<CleanupParameters> &params, bool what) {
+	params->tautomerRemoveSp3Stereo = what;
+}
+
+bool get_cleanup_tautomer_remove_bond_stereo(const std::shared_ptr<CleanupParameters> &params) {
+	return params->tautomerRemoveBondStereo;
+}
+void set_cleanup_tautomer_remove_bond_stereo(std::shared_ptr<CleanupParameters> &params, bool what) {
+	params->tautomerRemoveBondStereo = what;
+}
+
+bool get_cleanup_tautomer_remove_isotopic_hs(const std::shared_ptr<CleanupParameters> &params) {
+	return params->tautomerRemoveIsotopicHs;
+}
+void set_cleanup_tautomer_remove_isotopic_hs(std::shared_ptr<CleanupParameters> &params, bool what) {
+	params->tautomerRemoveIsotopicHs = what;
+}
+
+bool get_cleanup_tautomer_reassign_stereo(const std::shared_ptr<CleanupParameters> &params) {
+	return params->tautomerReassignStereo;
+}
+void set_cleanup_tautomer_reassign_stereo(std::shared_ptr<CleanupParameters> &params, bool what) {
+	params->tautomerReassignStereo = what;
+}
+
+bool get_cleanup_largest_fragment_chooser_use_atom_count(const std::shared_ptr<CleanupParameters> &params) {
+	return params->largestFragmentChooserUseAtomCount;
+}
+void set_cleanup_largest_fragment_chooser_use_atom_count(std::shared_ptr<CleanupParameters> &params, bool what) {
+	params->largestFragmentChooserUseAtomCount = what;
+}
+
+bool get_cleanup_largest_fragment_chooser_count_heavy_atoms_only(const std::shared_ptr<CleanupParameters> &params) {
+	return params->largestFragmentChooserCountHeavyAtomsOnly;
+}
+void set_cleanup_largest_fragment_chooser_count_heavy_atoms_only(std::shared_ptr<CleanupParameters> &params,
+                                                                 bool what) {
+	params->largestFragmentChooserCountHeavyAtomsOnly = what;
+}
+
+std::unique_ptr<std::vector<std::string>>
+get_cleanup_normalization_data_names(const std::shared_ptr<CleanupParameters> &params) {
+	auto names = std::unique_ptr<std::vector<std::string>>(new std::vector<std::string>);
+	for (const auto &transform : params->normalizationData) {
+		names->push_back(transform.first);
+	}
+	return names;
+}
+std::unique_ptr<std::vector<std::string>>
+get_cleanup_normalization_data_smirks(const std::shared_ptr<CleanupParameters> &params) {
+	auto smirks = std::unique_ptr<std::vector<std::string>>(new std::vector<std::string>);
+	for (const auto &transform : params->normalizationData) {
+		smirks->push_back(transform.second);
+	}
+	return smirks;
+}
+void set_cleanup_normalization_data(std::shared_ptr<CleanupParameters> &params, const rust::Vec<rust::String> &names,
+                                    const rust::Vec<rust::String> &smirks) {
+	params->normalizationData.clear();
+	for (size_t i = 0; i < names.size(); i++) {
+		params->normalizationData.push_back(std::make_pair(std::string(names[i]), std::string(smirks[i])));
+	}
+}
+
+std::unique_ptr<std::vector<std::string>>
+get_cleanup_acidbase_data_column(const std::shared_ptr<CleanupParameters> &params, unsigned int column) {
+	auto values = std::unique_ptr<std::vector<std::string>>(new std::vector<std::string>);
+	for (const auto &pair : params->acidbaseData) {
+		switch (column) {
+		case 0:
+			values->push_back(std::get<0>(pair));
+			break;
+		case 1:
+			values->push_back(std::get<1>(pair));
+			break;
+		default:
+			values->push_back(std::get<2>(pair));
+		}
+	}
+	return values;
+}
+void set_cleanup_acidbase_data(std::shared_ptr<CleanupParameters> &params, const rust::Vec<rust::String> &names,
+                               const rust::Vec<rust::String> &acids, const rust::Vec<rust::String> &bases) {
+	params->acidbaseData.clear();
+	for (size_t i = 0; i < names.size(); i++) {
+		params->acidbaseData.push_back(
+		    std::make_tuple(std::string(names[i]), std::string(acids[i]), std::string(bases[i])));
+	}
+}
+
 std::shared_ptr<Uncharger> new_uncharger(bool canonical) {
 	return std::shared_ptr<Uncharger>(new Uncharger(canonical));
 }
@@ -64,9 +318,184 @@ std::shared_ptr<RWMol> fragment_parent(const std::shared_ptr<RWMol> &rw_mol,
 	return std::shared_ptr<RWMol>(parent);
 }
 
+std::shared_ptr<RWMol> charge_parent(const std::shared_ptr<RWMol> &rw_mol,
+                                     const std::shared_ptr<CleanupParameters> &cleanup_params, bool skip_standardize) {
+	RWMol *parent = MolStandardize::chargeParent(*rw_mol, *cleanup_params, skip_standardize);
+	return std::shared_ptr<RWMol>(parent);
+}
+
+std::shared_ptr<RWMol> tautomer_parent(const std::shared_ptr<RWMol> &rw_mol,
+                                       const std::shared_ptr<CleanupParameters> &cleanup_params,
+                                       bool skip_standardize) {
+	RWMol *parent = MolStandardize::tautomerParent(*rw_mol, *cleanup_params, skip_standardize);
+	return std::shared_ptr<RWMol>(parent);
+}
+
+std::shared_ptr<RWMol> stereo_parent(const std::shared_ptr<RWMol> &rw_mol,
+                                     const std::shared_ptr<CleanupParameters> &cleanup_params, bool skip_standardize) {
+	RWMol *parent = MolStandardize::stereoParent(*rw_mol, *cleanup_params, skip_standardize);
+	return std::shared_ptr<RWMol>(parent);
+}
+
+std::shared_ptr<RWMol> isotope_parent(const std::shared_ptr<RWMol> &rw_mol,
+                                      const std::shared_ptr<CleanupParameters> &cleanup_params, bool skip_standardize) {
+	RWMol *parent = MolStandardize::isotopeParent(*rw_mol, *cleanup_params, skip_standardize);
+	return std::shared_ptr<RWMol>(parent);
+}
+
+std::shared_ptr<RWMol> super_parent(const std::shared_ptr<RWMol> &rw_mol,
+                                    const std::shared_ptr<CleanupParameters> &cleanup_params, bool skip_standardize) {
+	RWMol *parent = MolStandardize::superParent(*rw_mol, *cleanup_params, skip_standardize);
+	return std::shared_ptr<RWMol>(parent);
+}
+
 std::shared_ptr<RWMol> normalize(const std::shared_ptr<RWMol> &mol,
                                  const std::shared_ptr<CleanupParameters> &cleanup_params) {
 	RWMol *normalized = RDKit::MolStandardize::normalize(&*mol, *cleanup_params);
 	return std::shared_ptr<RWMol>(normalized);
 }
+
+std::shared_ptr<RWMol> reionize(const std::shared_ptr<RWMol> &mol,
+                                const std::shared_ptr<CleanupParameters> &cleanup_params) {
+	RWMol *reionized = RDKit::MolStandardize::reionize(&*mol, *cleanup_params);
+	return std::shared_ptr<RWMol>(reionized);
+}
+
+std::shared_ptr<RWMol> standardize(const std::shared_ptr<RWMol> &mol,
+                                   const std::shared_ptr<CleanupParameters> &cleanup_params) {
+	RWMol *standardized = RDKit::MolStandardize::cleanup(&*mol, *cleanup_params);
+	return std::shared_ptr<RWMol>(standardized);
+}
+
+std::shared_ptr<Normalizer> new_normalizer() { return std::shared_ptr<Normalizer>(new Normalizer()); }
+
+std::shared_ptr<Normalizer> new_normalizer_from_transforms(const rust::Vec<rust::String> &names,
+                                                           const rust::Vec<rust::String> &smirks,
+                                                           unsigned int max_restarts) {
+	std::vector<std::pair<std::string, std::string>> transforms;
+	for (size_t i = 0; i < names.size(); i++) {
+		transforms.push_back(std::make_pair(std::string(names[i]), std::string(smirks[i])));
+	}
+	return std::shared_ptr<Normalizer>(new Normalizer(transforms, max_restarts));
+}
+
+std::shared_ptr<Normalizer> new_normalizer_from_params(const std::shared_ptr<CleanupParameters> &cleanup_params) {
+	return std::shared_ptr<Normalizer>(MolStandardize::normalizerFromParams(*cleanup_params));
+}
+
+std::shared_ptr<ROMol> normalizer_normalize(const std::shared_ptr<Normalizer> &normalizer,
+                                            const std::shared_ptr<ROMol> &mol) {
+	return std::shared_ptr<ROMol>(normalizer->normalize(*mol));
+}
+
+std::shared_ptr<Reionizer> new_reionizer() { return std::shared_ptr<Reionizer>(new Reionizer()); }
+
+std::shared_ptr<Reionizer> new_reionizer_from_pairs(const rust::Vec<rust::String> &names,
+                                                    const rust::Vec<rust::String> &acids,
+                                                    const rust::Vec<rust::String> &bases) {
+	std::vector<std::tuple<std::string, std::string, std::string>> pairs;
+	for (size_t i = 0; i < names.size(); i++) {
+		pairs.push_back(std::make_tuple(std::string(names[i]), std::string(acids[i]), std::string(bases[i])));
+	}
+	return std::shared_ptr<Reionizer>(new Reionizer(pairs));
+}
+
+std::shared_ptr<Reionizer> new_reionizer_from_params(const std::shared_ptr<CleanupParameters> &cleanup_params) {
+	return std::shared_ptr<Reionizer>(MolStandardize::reionizerFromParams(*cleanup_params));
+}
+
+std::shared_ptr<ROMol> reionizer_reionize(const std::shared_ptr<Reionizer> &reionizer,
+                                          const std::shared_ptr<ROMol> &mol) {
+	return std::shared_ptr<ROMol>(reionizer->reionize(*mol));
+}
+
+std::shared_ptr<MetalDisconnector> new_metal_disconnector(bool split_grignards, bool split_aromatic_c,
+                                                          bool adjust_charges, bool remove_haptic_dummies) {
+	MolStandardize::MetalDisconnectorOptions options;
+	options.splitGrignards      = split_grignards;
+	options.splitAromaticC      = split_aromatic_c;
+	options.adjustCharges       = adjust_charges;
+	options.removeHapticDummies = remove_haptic_dummies;
+	return std::shared_ptr<MetalDisconnector>(new MetalDisconnector(options));
+}
+
+std::shared_ptr<ROMol> metal_disconnector_disconnect(const std::shared_ptr<MetalDisconnector> &disconnector,
+                                                     const std::shared_ptr<ROMol> &mol) {
+	return std::shared_ptr<ROMol>(disconnector->disconnect(*mol));
+}
+
+std::shared_ptr<LargestFragmentChooser> new_largest_fragment_chooser(bool prefer_organic, bool use_atom_count,
+                                                                     bool count_heavy_atoms_only) {
+	LargestFragmentChooser *chooser = new LargestFragmentChooser(prefer_organic);
+	chooser->useAtomCount           = use_atom_count;
+	chooser->countHeavyAtomsOnly    = count_heavy_atoms_only;
+	return std::shared_ptr<LargestFragmentChooser>(chooser);
+}
+
+std::shared_ptr<LargestFragmentChooser>
+new_largest_fragment_chooser_from_params(const std::shared_ptr<CleanupParameters> &cleanup_params) {
+	return std::shared_ptr<LargestFragmentChooser>(new LargestFragmentChooser(*cleanup_params));
+}
+
+std::shared_ptr<ROMol> largest_fragment_chooser_choose(const std::shared_ptr<LargestFragmentChooser> &chooser,
+                                                       const std::shared_ptr<ROMol> &mol) {
+	return std::shared_ptr<ROMol>(chooser->choose(*mol));
+}
+
+std::shared_ptr<ValidationMethod> new_rdkit_validation() {
+	return std::shared_ptr<ValidationMethod>(new MolStandardize::RDKitValidation());
+}
+
+std::shared_ptr<ValidationMethod> new_no_atom_validation() {
+	return std::shared_ptr<ValidationMethod>(new MolStandardize::NoAtomValidation());
+}
+
+std::shared_ptr<ValidationMethod> new_fragment_validation() {
+	return std::shared_ptr<ValidationMethod>(new MolStandardize::FragmentValidation());
+}
+
+std::shared_ptr<ValidationMethod> new_neutral_validation() {
+	return std::shared_ptr<ValidationMethod>(new MolStandardize::NeutralValidation());
+}
+
+std::shared_ptr<ValidationMethod> new_isotope_validation(bool strict) {
+	return std::shared_ptr<ValidationMethod>(new MolStandardize::IsotopeValidation(strict));
+}
+
+std::shared_ptr<ValidationMethod> new_molvs_validation() {
+	return std::shared_ptr<ValidationMethod>(new MolStandardize::MolVSValidation());
+}
+
+static std::vector<std::shared_ptr<Atom>> atoms_from_atomic_nums(const rust::Vec<unsigned int> &atomic_nums) {
+	std::vector<std::shared_ptr<Atom>> atoms;
+	for (auto atomic_num : atomic_nums) {
+		atoms.push_back(std::make_shared<Atom>(atomic_num));
+	}
+	return atoms;
+}
+
+std::shared_ptr<ValidationMethod> new_allowed_atoms_validation(const rust::Vec<unsigned int> &atomic_nums) {
+	return std::shared_ptr<ValidationMethod>(
+	    new MolStandardize::AllowedAtomsValidation(atoms_from_atomic_nums(atomic_nums)));
+}
+
+std::shared_ptr<ValidationMethod> new_disallowed_atoms_validation(const rust::Vec<unsigned int> &atomic_nums) {
+	return std::shared_ptr<ValidationMethod>(
+	    new MolStandardize::DisallowedAtomsValidation(atoms_from_atomic_nums(atomic_nums)));
+}
+
+std::shared_ptr<ValidationMethod> new_disallowed_radical_validation() {
+	return std::shared_ptr<ValidationMethod>(new MolStandardize::DisallowedRadicalValidation());
+}
+
+std::shared_ptr<ValidationMethod> new_stereo_validation() {
+	return std::shared_ptr<ValidationMethod>(new MolStandardize::StereoValidation());
+}
+
+std::unique_ptr<std::vector<std::string>> validation_method_validate(const std::shared_ptr<ValidationMethod> &method,
+                                                                     const std::shared_ptr<ROMol> &mol,
+                                                                     bool report_all_failures) {
+	std::vector<MolStandardize::ValidationErrorInfo> errors = method->validate(*mol, report_all_failures);
+	return std::unique_ptr<std::vector<std::string>>(new std::vector<std::string>(errors.begin(), errors.end()));
+}
 } // namespace RDKit
\ No newline at end of file
@@ -0,0 +1,39 @@
+#include "rust/cxx.h"
+#include <GraphMol/GraphMol.h>
+#include <GraphMol/Resonance.h>
+
+namespace RDKit {
+std::shared_ptr<ResonanceMolSupplier> new_resonance_mol_supplier(const std::shared_ptr<ROMol> &mol, unsigned int flags,
+                                                                 unsigned int max_structs) {
+	return std::shared_ptr<ResonanceMolSupplier>(new ResonanceMolSupplier(*mol, flags, max_structs));
+}
+
+// enumerates the resonance structures on first use
+unsigned int resonance_mol_supplier_length(const std::shared_ptr<ResonanceMolSupplier> &supplier) {
+	return supplier->length();
+}
+
+std::shared_ptr<ROMol> resonance_mol_supplier_at(const std::shared_ptr<ResonanceMolSupplier> &supplier,
+                                                 unsigned int idx) {
+	return std::shared_ptr<ROMol>((*supplier)[idx]);
+}
+
+unsigned int resonance_mol_supplier_num_conj_grps(const std::shared_ptr<ResonanceMolSupplier> &supplier) {
+	return supplier->getNumConjGrps();
+}
+
+// atoms and bonds outside of any conjugated group are reported as -1
+int resonance_mol_supplier_atom_conj_grp_idx(const std::shared_ptr<ResonanceMolSupplier> &supplier,
+                                             unsigned int atom_idx) {
+	return static_cast<int>(supplier->getAtomConjGrpIdx(atom_idx));
+}
+
+int resonance_mol_supplier_bond_conj_grp_idx(const std::shared_ptr<ResonanceMolSupplier> &supplier,
+                                             unsigned int bond_idx) {
+	return static_cast<int>(supplier->getBondConjGrpIdx(bond_idx));
+}
+
+bool resonance_mol_supplier_was_canceled(const std::shared_ptr<ResonanceMolSupplier> &supplier) {
+	return supplier->wasCanceled();
+}
+} // namespace RDKit
@@ -0,0 +1,84 @@
+#include "rust/cxx.h"
+#include "wrapper/include/rgroup_decomposition.h"
+#include <GraphMol/GraphMol.h>
+#include <GraphMol/RGroupDecomposition/RGroupDecomp.h>
+
+namespace RDKit {
+std::shared_ptr<RGroupDecompositionParameters> new_rgroup_decomposition_parameters() {
+	return std::shared_ptr<RGroupDecompositionParameters>(new RGroupDecompositionParameters());
+}
+
+void rgroup_decomposition_parameters_set_labels(std::shared_ptr<RGroupDecompositionParameters> &params,
+                                                unsigned int labels) {
+	params->labels = labels;
+}
+
+void rgroup_decomposition_parameters_set_matching_strategy(std::shared_ptr<RGroupDecompositionParameters> &params,
+                                                           unsigned int strategy) {
+	params->matchingStrategy = strategy;
+}
+
+void rgroup_decomposition_parameters_set_alignment(std::shared_ptr<RGroupDecompositionParameters> &params,
+                                                   unsigned int alignment) {
+	params->alignment = alignment;
+}
+
+bool rgroup_decomposition_parameters_get_remove_all_hydrogen_rgroups(
+    const std::shared_ptr<RGroupDecompositionParameters> &params) {
+	return params->removeAllHydrogenRGroups;
+}
+
+void rgroup_decomposition_parameters_set_remove_all_hydrogen_rgroups(
+    std::shared_ptr<RGroupDecompositionParameters> &params, bool what) {
+	params->removeAllHydrogenRGroups = what;
+}
+
+bool rgroup_decomposition_parameters_get_only_match_at_rgroups(
+    const std::shared_ptr<RGroupDecompositionParameters> &params) {
+	return params->onlyMatchAtRGroups;
+}
+
+void rgroup_decomposition_parameters_set_only_match_at_rgroups(std::shared_ptr<RGroupDecompositionParameters> &params,
+                                                               bool what) {
+	params->onlyMatchAtRGroups = what;
+}
+
+double rgroup_decomposition_parameters_get_timeout(const std::shared_ptr<RGroupDecompositionParameters> &params) {
+	return params->timeout;
+}
+
+void rgroup_decomposition_parameters_set_timeout(std::shared_ptr<RGroupDecompositionParameters> &params,
+                                                 double seconds) {
+	params->timeout = seconds;
+}
+
+std::shared_ptr<RGroupDecomposeResult> rgroup_decompose(const std::unique_ptr<ROMolList> &cores,
+                                                        const std::unique_ptr<ROMolList> &mols,
+                                                        const std::shared_ptr<RGroupDecompositionParameters> &params) {
+	std::shared_ptr<RGroupDecomposeResult> result(new RGroupDecomposeResult());
+	RGroupDecompose(*cores, *mols, result->rows, &result->unmatched, *params);
+	return result;
+}
+
+unsigned int rgroup_decompose_result_num_rows(const std::shared_ptr<RGroupDecomposeResult> &result) {
+	return result->rows.size();
+}
+
+// sorted as strings, so R10 comes before R2
+std::unique_ptr<std::vector<std::string>>
+rgroup_decompose_result_row_labels(const std::shared_ptr<RGroupDecomposeResult> &result, unsigned int row) {
+	std::vector<std::string> *labels = new std::vector<std::string>();
+	for (const auto &column : result->rows.at(row)) { labels->push_back(column.first); }
+	return std::unique_ptr<std::vector<std::string>>(labels);
+}
+
+std::shared_ptr<ROMol> rgroup_decompose_result_row_mol(const std::shared_ptr<RGroupDecomposeResult> &result,
+                                                       unsigned int row, const std::string &label) {
+	return std::shared_ptr<ROMol>(new ROMol(*result->rows.at(row).at(label)));
+}
+
+std::unique_ptr<std::vector<unsigned int>>
+rgroup_decompose_result_unmatched(const std::shared_ptr<RGroupDecomposeResult> &result) {
+	return std::unique_ptr<std::vector<unsigned int>>(new std::vector<unsigned int>(result->unmatched));
+}
+} // namespace RDKit
@@ -5,6 +5,8 @@
 #include <GraphMol/GraphMol.h>
 #include <GraphMol/MolOps.h>
 #include <GraphMol/MolStandardize/Tautomer.h>
+#include <GraphMol/QueryOps.h>
+#include <GraphMol/SmilesParse/SmartsWrite.h>
 #include <GraphMol/SmilesParse/SmilesParse.h>
 #include <GraphMol/SmilesParse/SmilesWrite.h>
 
@@ -15,6 +17,19 @@ using ExplicitBitVect = ::ExplicitBitVect;
 
 std::shared_ptr<ROMol> copy_mol(const std::shared_ptr<ROMol> &mol) { return std::shared_ptr<ROMol>(new ROMol(*mol)); }
 
+std::unique_ptr<ROMolList> new_ro_mol_list() { return std::unique_ptr<ROMolList>(new ROMolList()); }
+
+// shares the molecule instead of copying it, the boost pointer keeps the std one alive
+void ro_mol_list_push(ROMolList &list, const std::shared_ptr<ROMol> &mol) {
+	list.push_back(ROMOL_SPTR(mol.get(), [mol](ROMol *) {}));
+}
+
+unsigned int ro_mol_list_len(const std::unique_ptr<ROMolList> &list) { return list->size(); }
+
+std::shared_ptr<ROMol> ro_mol_list_get(const std::unique_ptr<ROMolList> &list, unsigned int idx) {
+	return std::shared_ptr<ROMol>(new ROMol(*list->at(idx)));
+}
+
 std::shared_ptr<ROMol> smiles_to_mol(const std::string &smiles) {
 	ROMol *mol = SmilesToMol(smiles);
 
@@ -23,6 +38,8 @@ std::shared_ptr<ROMol> smiles_to_mol(const std::string &smiles) {
 
 rust::String mol_to_smiles(const std::shared_ptr<ROMol> &mol) { return MolToSmiles(*mol); }
 
+rust::String mol_to_smarts(const std::shared_ptr<ROMol> &mol, bool isomeric) { return MolToSmarts(*mol, isomeric); }
+
 rust::String mol_to_molblock(const std::shared_ptr<ROMol> &mol) { return MolToMolBlock(*mol); }
 
 std::shared_ptr<ROMol> smiles_to_mol_with_params(const std::string &smiles,
@@ -67,6 +84,8 @@ unsigned int get_num_atoms(const std::shared_ptr<ROMol> &mol, bool only_explicit
 	return mol->getNumAtoms(only_explicit);
 }
 
+unsigned int get_num_conformers(const std::shared_ptr<ROMol> &mol) { return mol->getNumConformers(); }
+
 Atom &get_atom_with_idx(std::shared_ptr<ROMol> &mol, unsigned int idx) { return *mol->getAtomWithIdx(idx); }
 
 rust::String get_symbol(const Atom &atom) { return atom.getSymbol(); }
@@ -98,4 +117,51 @@ rust::String get_prop(const Atom &atom, const std::string &key) { return atom.ge
 
 unsigned int get_num_radical_electrons(const Atom &atom) { return atom.getNumRadicalElectrons(); }
 unsigned int get_degree(const Atom &atom) { return atom.getDegree(); }
+
+using ChiralType = Atom::ChiralType;
+ChiralType atom_get_chiral_tag(const Atom &atom) { return atom.getChiralTag(); }
+
+// throws if the ring info of the owning molecule was never initialized, e.g. for unsanitized molecules
+bool atom_is_in_ring(const Atom &atom) {
+	return atom.getOwningMol().getRingInfo()->numAtomRings(atom.getIdx()) != 0;
+}
+
+unsigned int atom_get_isotope(const Atom &atom) { return atom.getIsotope(); }
+bool atom_has_query(const Atom &atom) { return atom.hasQuery(); }
+
+// plain atoms, e.g. from a SMILES, are written as their SMILES
+rust::String atom_get_smarts(const Atom &atom) {
+	if (atom.hasQuery()) {
+		return SmartsWrite::GetAtomSmarts(static_cast<const QueryAtom *>(&atom));
+	}
+	return SmilesWrite::GetAtomSmiles(&atom);
+}
+
+rust::String atom_describe_query(const Atom &atom) { return atom.hasQuery() ? describeQuery(&atom) : ""; }
+
+unsigned int get_num_bonds(const std::shared_ptr<ROMol> &mol) { return mol->getNumBonds(); }
+Bond &get_bond_with_idx(std::shared_ptr<ROMol> &mol, unsigned int idx) { return *mol->getBondWithIdx(idx); }
+unsigned int get_begin_atom_idx(const Bond &bond) { return bond.getBeginAtomIdx(); }
+unsigned int get_end_atom_idx(const Bond &bond) { return bond.getEndAtomIdx(); }
+double get_bond_type_as_double(const Bond &bond) { return bond.getBondTypeAsDouble(); }
+bool get_bond_is_aromatic(const Bond &bond) { return bond.getIsAromatic(); }
+
+using BondType   = Bond::BondType;
+using BondStereo = Bond::BondStereo;
+BondType get_bond_type(const Bond &bond) { return bond.getBondType(); }
+bool get_bond_is_conjugated(const Bond &bond) { return bond.getIsConjugated(); }
+BondStereo get_bond_stereo(const Bond &bond) { return bond.getStereo(); }
+bool bond_is_in_ring(const Bond &bond) {
+	return bond.getOwningMol().getRingInfo()->numBondRings(bond.getIdx()) != 0;
+}
+bool bond_has_query(const Bond &bond) { return bond.hasQuery(); }
+
+rust::String bond_get_smarts(const Bond &bond) {
+	if (bond.hasQuery()) {
+		return SmartsWrite::GetBondSmarts(static_cast<const QueryBond *>(&bond));
+	}
+	return SmilesWrite::GetBondSmiles(&bond);
+}
+
+rust::String bond_describe_query(const Bond &bond) { return bond.hasQuery() ? describeQuery(&bond) : ""; }
 } // namespace RDKit
\ No newline at end of file
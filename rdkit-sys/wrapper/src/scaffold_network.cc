@@ -62,4 +62,37 @@ std::shared_ptr<ScaffoldNetworkClass> default_scaffold_network() {
 	return std::shared_ptr<ScaffoldNetworkClass>(scaffold_network);
 }
 
+void update_scaffold_network(std::shared_ptr<ScaffoldNetworkClass> &network,
+                             const std::unique_ptr<std::vector<ROMOL_SPTR>> &mols,
+                             const std::shared_ptr<ScaffoldNetworkParams> &params) {
+	ScaffoldNetwork::updateScaffoldNetwork(*mols, *network, *params);
+}
+
+std::unique_ptr<std::vector<std::string>> scaffold_network_nodes(const std::shared_ptr<ScaffoldNetworkClass> &network) {
+	return std::unique_ptr<std::vector<std::string>>(new std::vector<std::string>(network->nodes));
+}
+
+std::unique_ptr<std::vector<unsigned int>>
+scaffold_network_counts(const std::shared_ptr<ScaffoldNetworkClass> &network) {
+	return std::unique_ptr<std::vector<unsigned int>>(new std::vector<unsigned int>(network->counts));
+}
+
+std::unique_ptr<std::vector<unsigned int>>
+scaffold_network_mol_counts(const std::shared_ptr<ScaffoldNetworkClass> &network) {
+	return std::unique_ptr<std::vector<unsigned int>>(new std::vector<unsigned int>(network->molCounts));
+}
+
+using NetworkEdge = ScaffoldNetwork::NetworkEdge;
+using EdgeType    = ScaffoldNetwork::EdgeType;
+
+std::unique_ptr<std::vector<NetworkEdge>> scaffold_network_edges(const std::shared_ptr<ScaffoldNetworkClass> &network) {
+	return std::unique_ptr<std::vector<NetworkEdge>>(new std::vector<NetworkEdge>(network->edges));
+}
+
+unsigned int network_edge_begin_idx(const NetworkEdge &edge) { return edge.beginIdx; }
+
+unsigned int network_edge_end_idx(const NetworkEdge &edge) { return edge.endIdx; }
+
+EdgeType network_edge_type(const NetworkEdge &edge) { return edge.type; }
+
 } // namespace RDKit
\ No newline at end of file
@@ -0,0 +1,60 @@
+#include "rust/cxx.h"
+#include <GraphMol/GraphMol.h>
+#include <GraphMol/Substruct/SubstructMatch.h>
+#include <GraphMol/SubstructLibrary/SubstructLibrary.h>
+
+namespace RDKit {
+// molecules are kept as trusted SMILES and screened with pattern fingerprints
+std::shared_ptr<SubstructLibrary> new_substruct_library() {
+	return std::shared_ptr<SubstructLibrary>(
+	    new SubstructLibrary(boost::make_shared<CachedTrustedSmilesMolHolder>(), boost::make_shared<PatternHolder>()));
+}
+
+std::shared_ptr<SubstructLibrary> new_substruct_library_from_pickle(rust::Slice<const uint8_t> pickle) {
+	std::string data(reinterpret_cast<const char *>(pickle.data()), pickle.size());
+	return std::shared_ptr<SubstructLibrary>(new SubstructLibrary(data));
+}
+
+rust::Vec<uint8_t> substruct_library_pickle(const std::shared_ptr<SubstructLibrary> &library) {
+	std::string data = library->Serialize();
+	rust::Vec<uint8_t> pickle;
+	pickle.reserve(data.size());
+	for (char c : data) {
+		pickle.push_back(static_cast<uint8_t>(c));
+	}
+	return pickle;
+}
+
+unsigned int substruct_library_add_mol(std::shared_ptr<SubstructLibrary> &library, const std::shared_ptr<ROMol> &mol) {
+	return library->addMol(*mol);
+}
+
+unsigned int substruct_library_size(const std::shared_ptr<SubstructLibrary> &library) {
+	return library->size();
+}
+
+std::shared_ptr<ROMol> substruct_library_get_mol(const std::shared_ptr<SubstructLibrary> &library, unsigned int idx) {
+	boost::shared_ptr<ROMol> mol = library->getMol(idx);
+	return std::shared_ptr<ROMol>(new ROMol(*mol));
+}
+
+std::unique_ptr<std::vector<unsigned int>>
+substruct_library_get_matches(const std::shared_ptr<SubstructLibrary> &library, const std::shared_ptr<ROMol> &query,
+                              const std::shared_ptr<SubstructMatchParameters> &params, int num_threads,
+                              int max_results) {
+	std::vector<unsigned int> matches = library->getMatches(*query, *params, num_threads, max_results);
+	return std::unique_ptr<std::vector<unsigned int>>(new std::vector<unsigned int>(matches));
+}
+
+unsigned int substruct_library_count_matches(const std::shared_ptr<SubstructLibrary> &library,
+                                             const std::shared_ptr<ROMol> &query,
+                                             const std::shared_ptr<SubstructMatchParameters> &params,
+                                             int num_threads) {
+	return library->countMatches(*query, *params, num_threads);
+}
+
+bool substruct_library_has_match(const std::shared_ptr<SubstructLibrary> &library, const std::shared_ptr<ROMol> &query,
+                                 const std::shared_ptr<SubstructMatchParameters> &params, int num_threads) {
+	return library->hasMatch(*query, *params, num_threads);
+}
+} // namespace RDKit
@@ -1,6 +1,9 @@
 #include "rust/cxx.h"
+#include "rdkit-sys/src/bridge/substruct_match.rs.h"
 #include <GraphMol/GraphMol.h>
+#include <GraphMol/Resonance.h>
 #include <GraphMol/Substruct/SubstructMatch.h>
+#include <limits>
 
 namespace RDKit {
 using SubstructMatchItem = std::pair<int, int>;
@@ -13,6 +16,71 @@ std::unique_ptr<std::vector<MatchVectType>> substruct_match(const std::shared_pt
 	return std::unique_ptr<std::vector<MatchVectType>>(heap_match);
 }
 
+std::unique_ptr<std::vector<MatchVectType>>
+substruct_match_resonance(const std::shared_ptr<ResonanceMolSupplier> &supplier,
+                          const std::shared_ptr<ROMol> &other_mol,
+                          const std::shared_ptr<SubstructMatchParameters> &params) {
+	std::vector<MatchVectType> match = SubstructMatch(*supplier, *other_mol, *params);
+	return std::unique_ptr<std::vector<MatchVectType>>(new std::vector<MatchVectType>(match));
+}
+
+// target is either a ROMol or a ResonanceMolSupplier
+template <typename T>
+static std::vector<MatchVectType> matches_up_to(T &target, const ROMol &query, const SubstructMatchParameters &params,
+                                                unsigned int max_matches) {
+	SubstructMatchParameters limited = params;
+	limited.maxMatches               = max_matches;
+	return SubstructMatch(target, query, limited);
+}
+
+template <typename T>
+static std::unique_ptr<std::vector<SubstructMatchItem>> first_match(T &target, const ROMol &query,
+                                                                    const SubstructMatchParameters &params) {
+	std::vector<MatchVectType> matches = matches_up_to(target, query, params, 1);
+	std::vector<SubstructMatchItem> *match_items = new std::vector<SubstructMatchItem>();
+	if (!matches.empty()) {
+		match_items->assign(matches[0].begin(), matches[0].end());
+	}
+	return std::unique_ptr<std::vector<SubstructMatchItem>>(match_items);
+}
+
+bool has_substruct_match(const std::shared_ptr<ROMol> &mol, const std::shared_ptr<ROMol> &other_mol,
+                         const std::shared_ptr<SubstructMatchParameters> &params) {
+	return !matches_up_to(*mol, *other_mol, *params, 1).empty();
+}
+
+bool has_substruct_match_resonance(const std::shared_ptr<ResonanceMolSupplier> &supplier,
+                                   const std::shared_ptr<ROMol> &other_mol,
+                                   const std::shared_ptr<SubstructMatchParameters> &params) {
+	return !matches_up_to(*supplier, *other_mol, *params, 1).empty();
+}
+
+// params.maxMatches is ignored so large counts aren't capped at its default of 1000. The matches are materialized
+// because uniquify needs them, so memory is O(matches).
+unsigned int count_substruct_matches(const std::shared_ptr<ROMol> &mol, const std::shared_ptr<ROMol> &other_mol,
+                                     const std::shared_ptr<SubstructMatchParameters> &params) {
+	return matches_up_to(*mol, *other_mol, *params, std::numeric_limits<unsigned int>::max()).size();
+}
+
+unsigned int count_substruct_matches_resonance(const std::shared_ptr<ResonanceMolSupplier> &supplier,
+                                               const std::shared_ptr<ROMol> &other_mol,
+                                               const std::shared_ptr<SubstructMatchParameters> &params) {
+	return matches_up_to(*supplier, *other_mol, *params, std::numeric_limits<unsigned int>::max()).size();
+}
+
+std::unique_ptr<std::vector<SubstructMatchItem>>
+substruct_match_first(const std::shared_ptr<ROMol> &mol, const std::shared_ptr<ROMol> &other_mol,
+                      const std::shared_ptr<SubstructMatchParameters> &params) {
+	return first_match(*mol, *other_mol, *params);
+}
+
+std::unique_ptr<std::vector<SubstructMatchItem>>
+substruct_match_first_resonance(const std::shared_ptr<ResonanceMolSupplier> &supplier,
+                                const std::shared_ptr<ROMol> &other_mol,
+                                const std::shared_ptr<SubstructMatchParameters> &params) {
+	return first_match(*supplier, *other_mol, *params);
+}
+
 std::shared_ptr<SubstructMatchParameters> new_substruct_match_parameters() {
 	return std::shared_ptr<SubstructMatchParameters>(new SubstructMatchParameters());
 }
@@ -35,6 +103,19 @@ bool get_recursion_possible(const std::shared_ptr<SubstructMatchParameters> &par
 }
 bool get_uniquify(const std::shared_ptr<SubstructMatchParameters> &params) { return params->uniquify; }
 unsigned int get_max_matches(const std::shared_ptr<SubstructMatchParameters> &params) { return params->maxMatches; }
+std::unique_ptr<std::vector<std::string>> get_atom_properties(const std::shared_ptr<SubstructMatchParameters> &params) {
+	return std::unique_ptr<std::vector<std::string>>(new std::vector<std::string>(params->atomProperties));
+}
+std::unique_ptr<std::vector<std::string>> get_bond_properties(const std::shared_ptr<SubstructMatchParameters> &params) {
+	return std::unique_ptr<std::vector<std::string>>(new std::vector<std::string>(params->bondProperties));
+}
+bool get_specified_stereo_query_matches_unspecified(const std::shared_ptr<SubstructMatchParameters> &params) {
+	return params->specifiedStereoQueryMatchesUnspecified;
+}
+unsigned int get_max_recursive_matches(const std::shared_ptr<SubstructMatchParameters> &params) {
+	return params->maxRecursiveMatches;
+}
+int get_num_threads(const std::shared_ptr<SubstructMatchParameters> &params) { return params->numThreads; }
 
 void set_use_chirality(std::shared_ptr<SubstructMatchParameters> &params, bool what) { params->useChirality = what; }
 void set_use_enhanced_stereo(std::shared_ptr<SubstructMatchParameters> &params, bool what) {
@@ -56,6 +137,32 @@ void set_uniquify(std::shared_ptr<SubstructMatchParameters> &params, bool what)
 void set_max_matches(std::shared_ptr<SubstructMatchParameters> &params, unsigned int value) {
 	params->maxMatches = value;
 }
+void set_atom_properties(std::shared_ptr<SubstructMatchParameters> &params, const rust::Vec<rust::String> &props) {
+	params->atomProperties.clear();
+	for (const rust::String &prop : props) { params->atomProperties.push_back(std::string(prop)); }
+}
+void set_bond_properties(std::shared_ptr<SubstructMatchParameters> &params, const rust::Vec<rust::String> &props) {
+	params->bondProperties.clear();
+	for (const rust::String &prop : props) { params->bondProperties.push_back(std::string(prop)); }
+}
+void set_specified_stereo_query_matches_unspecified(std::shared_ptr<SubstructMatchParameters> &params, bool what) {
+	params->specifiedStereoQueryMatchesUnspecified = what;
+}
+void set_max_recursive_matches(std::shared_ptr<SubstructMatchParameters> &params, unsigned int value) {
+	params->maxRecursiveMatches = value;
+}
+void set_num_threads(std::shared_ptr<SubstructMatchParameters> &params, int value) { params->numThreads = value; }
+
+// std::function has to be copyable, so the closure is shared between copies of the parameters
+void set_extra_final_check(std::shared_ptr<SubstructMatchParameters> &params, rust::Box<SubstructFinalCheck> check) {
+	auto shared_check      = std::make_shared<rust::Box<SubstructFinalCheck>>(std::move(check));
+	params->extraFinalCheck = [shared_check](const ROMol &mol, const std::vector<unsigned int> &match) {
+		// non-owning, RDKit keeps the molecule alive for the duration of the call
+		std::shared_ptr<ROMol> mol_ptr(std::shared_ptr<ROMol>(), const_cast<ROMol *>(&mol));
+		return substruct_final_check_call(**shared_check, mol_ptr,
+		                                  rust::Slice<const uint32_t>(match.data(), match.size()));
+	};
+}
 std::unique_ptr<std::vector<SubstructMatchItem>>
 substruct_matchvect_type_to_vec_substruct_match_item(const MatchVectType &match_vect) {
 	std::vector<SubstructMatchItem> *match_items = new std::vector<SubstructMatchItem>();
@@ -0,0 +1,100 @@
+//! Transformations driven by a query match. RDKit's implementations only
+//! take chirality into account, the other `SubstructMatchParameters` are
+//! ignored.
+
+use rdkit_sys::chem_transforms_ffi;
+
+use crate::{ro_mols_from_list, ROMol, SubstructMatchParameters};
+
+/// Removes every match of `query`. With `only_frags` only fragments that
+/// match completely are removed, e.g. to strip salts.
+pub fn delete_substructs(
+    mol: &ROMol,
+    query: &ROMol,
+    only_frags: bool,
+    params: &SubstructMatchParameters,
+) -> Result<ROMol, cxx::Exception> {
+    let ptr = chem_transforms_ffi::delete_substructs(
+        &mol.ptr,
+        &query.ptr,
+        only_frags,
+        params.get_use_chirality(),
+    )?;
+    Ok(ROMol { ptr })
+}
+
+/// Replaces matches of `query` with `replacement`, bonded through its atom
+/// `replacement_connection_point`. Returns one product per match, or a
+/// single product with all matches replaced if `replace_all` is set. The
+/// molecule itself is returned if nothing matches.
+pub fn replace_substructs(
+    mol: &ROMol,
+    query: &ROMol,
+    replacement: &ROMol,
+    replace_all: bool,
+    replacement_connection_point: u32,
+    params: &SubstructMatchParameters,
+) -> Result<Vec<ROMol>, cxx::Exception> {
+    let products = chem_transforms_ffi::replace_substructs(
+        &mol.ptr,
+        &query.ptr,
+        &replacement.ptr,
+        replace_all,
+        replacement_connection_point,
+        params.get_use_chirality(),
+    )?;
+    Ok(ro_mols_from_list(&products))
+}
+
+/// Keeps the atoms matching `core` and replaces each side chain with a
+/// dummy atom. `None` if the core doesn't match.
+pub fn replace_sidechains(
+    mol: &ROMol,
+    core: &ROMol,
+    params: &SubstructMatchParameters,
+) -> Result<Option<ROMol>, cxx::Exception> {
+    let ptr =
+        chem_transforms_ffi::replace_sidechains(&mol.ptr, &core.ptr, params.get_use_chirality())?;
+    Ok((!ptr.is_null()).then_some(ROMol { ptr }))
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReplaceCoreOptions {
+    /// Also remove atoms matching dummy atoms of the core
+    pub replace_dummies: bool,
+    /// Label the dummy atoms with the index of the core atom they were
+    /// attached to, instead of numbering them in order
+    pub label_by_index: bool,
+    /// Only replace the core if every attachment point of the molecule is on
+    /// a dummy atom of the core
+    pub require_dummy_match: bool,
+}
+
+impl Default for ReplaceCoreOptions {
+    fn default() -> Self {
+        ReplaceCoreOptions {
+            replace_dummies: true,
+            label_by_index: false,
+            require_dummy_match: false,
+        }
+    }
+}
+
+/// Removes the atoms matching `core`, leaving the side chains with dummy
+/// atoms at their attachment points. `None` if the core doesn't match.
+pub fn replace_core(
+    mol: &ROMol,
+    core: &ROMol,
+    options: &ReplaceCoreOptions,
+    params: &SubstructMatchParameters,
+) -> Result<Option<ROMol>, cxx::Exception> {
+    let ptr = chem_transforms_ffi::replace_core(
+        &mol.ptr,
+        &core.ptr,
+        options.replace_dummies,
+        options.label_by_index,
+        options.require_dummy_match,
+        params.get_use_chirality(),
+    )?;
+    Ok((!ptr.is_null()).then_some(ROMol { ptr }))
+}
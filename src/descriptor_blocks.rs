@@ -0,0 +1,441 @@
+use std::collections::HashMap;
+
+use rdkit_sys::descriptors_ffi;
+
+use crate::{LabuteAsaContribs, PeriodicTable, ROMol};
+
+const BCUT2D_NAMES: [&str; 8] = [
+    "BCUT2D_MWHI",
+    "BCUT2D_MWLOW",
+    "BCUT2D_CHGHI",
+    "BCUT2D_CHGLO",
+    "BCUT2D_LOGPHI",
+    "BCUT2D_LOGPLOW",
+    "BCUT2D_MRHI",
+    "BCUT2D_MRLOW",
+];
+
+/// E-state bin edges used by RDKit's `EState_VSA` descriptors
+const ESTATE_BINS: [f64; 10] = [
+    -0.39, 0.29, 0.717, 1.165, 1.54, 1.807, 2.05, 4.69, 9.17, 15.0,
+];
+
+/// Number of sorted distances compared when assigning symmetry classes in
+/// [`bertz_ct`]
+const BERTZ_CUTOFF: usize = 100;
+
+/// RDKit places atoms of disconnected fragments this far apart
+const DISCONNECTED_DISTANCE: f64 = 1e8;
+
+/// A group of 2D descriptors that is computed as a whole and returned as a
+/// block of named values
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DescriptorBlock {
+    Bcut2d,
+    Autocorr2d,
+    Mqn,
+    BalabanJ,
+    BertzCt,
+    Ipc,
+    WienerIndex,
+    EStateVsa,
+    PeoeVsa,
+    SmrVsa,
+    SlogPVsa,
+}
+
+impl DescriptorBlock {
+    pub const ALL: [DescriptorBlock; 11] = [
+        DescriptorBlock::Bcut2d,
+        DescriptorBlock::Autocorr2d,
+        DescriptorBlock::Mqn,
+        DescriptorBlock::BalabanJ,
+        DescriptorBlock::BertzCt,
+        DescriptorBlock::Ipc,
+        DescriptorBlock::WienerIndex,
+        DescriptorBlock::EStateVsa,
+        DescriptorBlock::PeoeVsa,
+        DescriptorBlock::SmrVsa,
+        DescriptorBlock::SlogPVsa,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DescriptorBlock::Bcut2d => "BCUT2D",
+            DescriptorBlock::Autocorr2d => "AUTOCORR2D",
+            DescriptorBlock::Mqn => "MQN",
+            DescriptorBlock::BalabanJ => "BalabanJ",
+            DescriptorBlock::BertzCt => "BertzCT",
+            DescriptorBlock::Ipc => "Ipc",
+            DescriptorBlock::WienerIndex => "WienerIndex",
+            DescriptorBlock::EStateVsa => "EState_VSA",
+            DescriptorBlock::PeoeVsa => "PEOE_VSA",
+            DescriptorBlock::SmrVsa => "SMR_VSA",
+            DescriptorBlock::SlogPVsa => "SlogP_VSA",
+        }
+    }
+
+    /// Number of values in the block
+    pub fn num_values(&self) -> usize {
+        match self {
+            DescriptorBlock::Bcut2d => BCUT2D_NAMES.len(),
+            DescriptorBlock::Autocorr2d => 192,
+            DescriptorBlock::Mqn => 42,
+            DescriptorBlock::BalabanJ
+            | DescriptorBlock::BertzCt
+            | DescriptorBlock::Ipc
+            | DescriptorBlock::WienerIndex => 1,
+            DescriptorBlock::EStateVsa => ESTATE_BINS.len() + 1,
+            DescriptorBlock::PeoeVsa => 14,
+            DescriptorBlock::SmrVsa => 10,
+            DescriptorBlock::SlogPVsa => 12,
+        }
+    }
+
+    /// Names of the individual values, following the RDKit descriptor names
+    /// where there are any (`MQN1`, `SlogP_VSA3`, ...)
+    pub fn value_names(&self) -> Vec<String> {
+        match self {
+            DescriptorBlock::Bcut2d => BCUT2D_NAMES.iter().map(|name| name.to_string()).collect(),
+            DescriptorBlock::Autocorr2d => (1..=self.num_values())
+                .map(|idx| format!("AUTOCORR2D_{idx}"))
+                .collect(),
+            DescriptorBlock::BalabanJ
+            | DescriptorBlock::BertzCt
+            | DescriptorBlock::Ipc
+            | DescriptorBlock::WienerIndex => vec![self.name().to_string()],
+            _ => (1..=self.num_values())
+                .map(|idx| format!("{}{idx}", self.name()))
+                .collect(),
+        }
+    }
+
+    pub fn compute(&self, ro_mol: &ROMol) -> Result<Vec<f64>, cxx::Exception> {
+        let values = match self {
+            DescriptorBlock::Bcut2d => descriptors_ffi::calc_bcut2d(&ro_mol.ptr)?
+                .as_slice()
+                .to_vec(),
+            DescriptorBlock::Autocorr2d => descriptors_ffi::calc_autocorr_2d(&ro_mol.ptr)
+                .as_slice()
+                .to_vec(),
+            DescriptorBlock::Mqn => descriptors_ffi::calc_mqns(&ro_mol.ptr)
+                .iter()
+                .map(|value| *value as f64)
+                .collect(),
+            DescriptorBlock::BalabanJ => vec![balaban_j(ro_mol)],
+            DescriptorBlock::BertzCt => vec![bertz_ct(ro_mol)],
+            DescriptorBlock::Ipc => vec![ipc(ro_mol)],
+            DescriptorBlock::WienerIndex => vec![wiener_index(ro_mol)],
+            DescriptorBlock::EStateVsa => estate_vsa(ro_mol),
+            DescriptorBlock::PeoeVsa => descriptors_ffi::calc_peoe_vsa(&ro_mol.ptr)?
+                .as_slice()
+                .to_vec(),
+            DescriptorBlock::SmrVsa => descriptors_ffi::calc_smr_vsa(&ro_mol.ptr)
+                .as_slice()
+                .to_vec(),
+            DescriptorBlock::SlogPVsa => descriptors_ffi::calc_slogp_vsa(&ro_mol.ptr)
+                .as_slice()
+                .to_vec(),
+        };
+        Ok(values)
+    }
+}
+
+impl std::fmt::Display for DescriptorBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The values of one [`DescriptorBlock`] for a molecule, `names` and
+/// `values` running in parallel
+#[derive(Clone, Debug, PartialEq)]
+pub struct DescriptorValues {
+    pub block: DescriptorBlock,
+    pub names: Vec<String>,
+    pub values: Vec<f64>,
+}
+
+/// Computes a fixed selection of descriptor blocks, defaulting to all of
+/// them
+#[derive(Clone, Debug, PartialEq)]
+pub struct DescriptorCalculator {
+    blocks: Vec<DescriptorBlock>,
+}
+
+impl Default for DescriptorCalculator {
+    fn default() -> Self {
+        Self::new(&DescriptorBlock::ALL)
+    }
+}
+
+impl DescriptorCalculator {
+    pub fn new(blocks: &[DescriptorBlock]) -> Self {
+        DescriptorCalculator {
+            blocks: blocks.to_vec(),
+        }
+    }
+
+    pub fn blocks(&self) -> &[DescriptorBlock] {
+        &self.blocks
+    }
+
+    /// Names of all values, in the order [`DescriptorCalculator::compute_values`]
+    /// returns them
+    pub fn names(&self) -> Vec<String> {
+        self.blocks
+            .iter()
+            .flat_map(|block| block.value_names())
+            .collect()
+    }
+
+    pub fn compute(&self, ro_mol: &ROMol) -> Result<Vec<DescriptorValues>, cxx::Exception> {
+        self.blocks
+            .iter()
+            .map(|block| {
+                Ok(DescriptorValues {
+                    block: *block,
+                    names: block.value_names(),
+                    values: block.compute(ro_mol)?,
+                })
+            })
+            .collect()
+    }
+
+    /// All blocks concatenated into a single row
+    pub fn compute_values(&self, ro_mol: &ROMol) -> Result<Vec<f64>, cxx::Exception> {
+        let mut values = Vec::with_capacity(self.blocks.iter().map(|b| b.num_values()).sum());
+        for block in &self.blocks {
+            values.extend(block.compute(ro_mol)?);
+        }
+        Ok(values)
+    }
+}
+
+pub fn balaban_j(ro_mol: &ROMol) -> f64 {
+    rdkit_sys::mol_ops_ffi::compute_balaban_j(&ro_mol.ptr)
+}
+
+/// Sum of the topological distances between all pairs of connected atoms
+pub fn wiener_index(ro_mol: &ROMol) -> f64 {
+    let num_atoms = ro_mol.num_atoms(true) as usize;
+    let distances = ro_mol.distance_matrix(false);
+
+    let mut wiener_index = 0.0;
+    for i in 0..num_atoms {
+        for j in i + 1..num_atoms {
+            let distance = distances[i * num_atoms + j];
+            if distance < DISCONNECTED_DISTANCE {
+                wiener_index += distance;
+            }
+        }
+    }
+    wiener_index
+}
+
+/// Bonchev and Trinajstić information content of the coefficients of the
+/// characteristic polynomial of the adjacency matrix, as in RDKit's Python
+/// `Ipc`
+pub fn ipc(ro_mol: &ROMol) -> f64 {
+    let num_atoms = ro_mol.num_atoms(true) as usize;
+    let adjacency = ro_mol
+        .distance_matrix(false)
+        .into_iter()
+        .map(|distance| if distance == 1.0 { 1.0 } else { 0.0 })
+        .collect::<Vec<_>>();
+
+    // Faddeev-LeVerrier, only the magnitudes of the coefficients are used
+    let mut coefficients = vec![1.0];
+    let mut power = adjacency.clone();
+    for k in 1..=num_atoms {
+        let trace = (0..num_atoms)
+            .map(|i| power[i * num_atoms + i])
+            .sum::<f64>();
+        let coefficient = trace / k as f64;
+        coefficients.push(coefficient.abs());
+
+        for i in 0..num_atoms {
+            power[i * num_atoms + i] -= coefficient;
+        }
+        power = mat_mul(&adjacency, &power, num_atoms);
+    }
+
+    coefficients.iter().sum::<f64>() * info_entropy(&coefficients)
+}
+
+/// Bertz molecular complexity, a port of RDKit's Python `BertzCT`
+pub fn bertz_ct(ro_mol: &ROMol) -> f64 {
+    let mut mol = ro_mol.clone();
+    let num_atoms = mol.num_atoms(true) as usize;
+    if num_atoms < 2 {
+        return 0.0;
+    }
+
+    // atoms with the same sorted bond order weighted distances are equivalent
+    let distances = mol.distance_matrix(true);
+    let mut keys_seen: Vec<Vec<String>> = vec![];
+    let symmetry_classes = (0..num_atoms)
+        .map(|i| {
+            let mut row = distances[i * num_atoms..(i + 1) * num_atoms].to_vec();
+            row.sort_by(f64::total_cmp);
+            let key = row
+                .iter()
+                .take(BERTZ_CUTOFF)
+                .map(|distance| format!("{distance:.4}"))
+                .collect::<Vec<_>>();
+            match keys_seen.iter().position(|seen| *seen == key) {
+                Some(idx) => idx + 1,
+                None => {
+                    keys_seen.push(key);
+                    keys_seen.len()
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut neighbors = vec![vec![]; num_atoms];
+    let mut bond_orders = HashMap::new();
+    for idx in 0..mol.num_bonds() {
+        let bond = mol.bond_with_idx(idx);
+        let begin = bond.get_begin_atom_idx() as usize;
+        let end = bond.get_end_atom_idx() as usize;
+        let order = if bond.get_is_aromatic() {
+            1.5
+        } else {
+            bond.get_bond_type_as_double()
+        };
+        bond_orders.insert((begin.min(end), begin.max(end)), order);
+        if !neighbors[begin].contains(&end) {
+            neighbors[begin].push(end);
+        }
+        if !neighbors[end].contains(&begin) {
+            neighbors[end].push(begin);
+        }
+    }
+    neighbors
+        .iter_mut()
+        .for_each(|atom_neighbors| atom_neighbors.sort());
+    let bond_order = |a: usize, b: usize| bond_orders[&(a.min(b), a.max(b))];
+
+    let mut atom_types = HashMap::<i32, f64>::new();
+    let mut connections = HashMap::<Vec<usize>, f64>::new();
+    for (atom_idx, atom_neighbors) in neighbors.iter().enumerate() {
+        let atomic_num = mol.atom_with_idx(atom_idx as u32).get_atomic_num();
+        *atom_types.entry(atomic_num).or_default() += 1.0;
+
+        let hinge_class = symmetry_classes[atom_idx];
+        for (i, &neighbor_i) in atom_neighbors.iter().enumerate() {
+            let class_i = symmetry_classes[neighbor_i];
+            let order_i = bond_order(atom_idx, neighbor_i);
+            if order_i > 1.0 && neighbor_i > atom_idx {
+                let key = vec![hinge_class.min(class_i), hinge_class.max(class_i)];
+                *connections.entry(key).or_default() += order_i * (order_i - 1.0) / 2.0;
+            }
+
+            for &neighbor_j in &atom_neighbors[i + 1..] {
+                let class_j = symmetry_classes[neighbor_j];
+                let order_j = bond_order(atom_idx, neighbor_j);
+                let key = vec![class_i.min(class_j), hinge_class, class_i.max(class_j)];
+                *connections.entry(key).or_default() += order_i * order_j;
+            }
+        }
+    }
+
+    let connections = if connections.is_empty() {
+        vec![1.0]
+    } else {
+        connections.into_values().collect()
+    };
+    let total_connections = connections.iter().sum::<f64>();
+    let connection_ie = total_connections * (info_entropy(&connections) + total_connections.log2());
+
+    let atom_types = atom_types.into_values().collect::<Vec<_>>();
+    let atom_type_ie = num_atoms as f64 * info_entropy(&atom_types);
+
+    atom_type_ie + connection_ie
+}
+
+/// Kier and Hall electrotopological state index of each atom
+pub fn estate_indices(ro_mol: &ROMol) -> Vec<f64> {
+    let mut mol = ro_mol.clone();
+    let num_atoms = mol.num_atoms(true) as usize;
+
+    let intrinsic_states = (0..num_atoms)
+        .map(|idx| {
+            let atom = mol.atom_with_idx(idx as u32);
+            let degree = atom.get_degree();
+            if degree == 0 {
+                return 0.0;
+            }
+            let atomic_num = atom.get_atomic_num() as u32;
+            let valence_electrons = PeriodicTable::get_n_outer_elecs(atomic_num) as f64
+                - atom.get_total_num_hs() as f64;
+            let principal_quantum_number = PeriodicTable::get_row(atomic_num).max(1) as f64;
+            (4.0 / principal_quantum_number.powi(2) * valence_electrons + 1.0) / degree as f64
+        })
+        .collect::<Vec<_>>();
+
+    let distances = mol.distance_matrix(false);
+    let mut perturbations = vec![0.0; num_atoms];
+    for i in 0..num_atoms {
+        for j in i + 1..num_atoms {
+            let path_length = distances[i * num_atoms + j] + 1.0;
+            if path_length < DISCONNECTED_DISTANCE {
+                let perturbation =
+                    (intrinsic_states[i] - intrinsic_states[j]) / path_length.powi(2);
+                perturbations[i] += perturbation;
+                perturbations[j] -= perturbation;
+            }
+        }
+    }
+
+    intrinsic_states
+        .into_iter()
+        .zip(perturbations)
+        .map(|(intrinsic_state, perturbation)| intrinsic_state + perturbation)
+        .collect()
+}
+
+/// Labute surface area contributions binned by E-state index
+pub fn estate_vsa(ro_mol: &ROMol) -> Vec<f64> {
+    let estates = estate_indices(ro_mol);
+    let surface_areas = LabuteAsaContribs::from_molecule(ro_mol, true).atoms;
+
+    let mut bins = vec![0.0; ESTATE_BINS.len() + 1];
+    for (estate, surface_area) in estates.iter().zip(surface_areas) {
+        bins[ESTATE_BINS.partition_point(|edge| edge <= estate)] += surface_area;
+    }
+    bins
+}
+
+/// Shannon entropy in bits of the distribution given by `counts`
+fn info_entropy(counts: &[f64]) -> f64 {
+    let total = counts.iter().sum::<f64>();
+    if total == 0.0 {
+        return 0.0;
+    }
+    -counts
+        .iter()
+        .filter(|count| **count != 0.0)
+        .map(|count| {
+            let p = count / total;
+            p * p.log2()
+        })
+        .sum::<f64>()
+}
+
+fn mat_mul(a: &[f64], b: &[f64], n: usize) -> Vec<f64> {
+    let mut product = vec![0.0; n * n];
+    for i in 0..n {
+        for k in 0..n {
+            let a_ik = a[i * n + k];
+            if a_ik == 0.0 {
+                continue;
+            }
+            for j in 0..n {
+                product[i * n + j] += a_ik * b[k * n + j];
+            }
+        }
+    }
+    product
+}
@@ -4,6 +4,180 @@ use cxx::SharedPtr;
 
 use crate::ROMol;
 
+/// The descriptors RDKit registers with `Descriptors::Properties`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Descriptor {
+    ExactMw,
+    Amw,
+    LipinskiHba,
+    LipinskiHbd,
+    NumRotatableBonds,
+    NumHbd,
+    NumHba,
+    NumHeavyAtoms,
+    NumAtoms,
+    NumHeteroatoms,
+    NumAmideBonds,
+    FractionCSp3,
+    NumRings,
+    NumAromaticRings,
+    NumAliphaticRings,
+    NumSaturatedRings,
+    NumHeterocycles,
+    NumAromaticHeterocycles,
+    NumSaturatedHeterocycles,
+    NumAliphaticHeterocycles,
+    NumSpiroAtoms,
+    NumBridgeheadAtoms,
+    NumAtomStereoCenters,
+    NumUnspecifiedAtomStereoCenters,
+    LabuteAsa,
+    Tpsa,
+    CrippenClogP,
+    CrippenMr,
+    Chi0v,
+    Chi1v,
+    Chi2v,
+    Chi3v,
+    Chi4v,
+    Chi0n,
+    Chi1n,
+    Chi2n,
+    Chi3n,
+    Chi4n,
+    HallKierAlpha,
+    Kappa1,
+    Kappa2,
+    Kappa3,
+    Phi,
+}
+
+impl Descriptor {
+    pub const ALL: [Descriptor; 43] = [
+        Descriptor::ExactMw,
+        Descriptor::Amw,
+        Descriptor::LipinskiHba,
+        Descriptor::LipinskiHbd,
+        Descriptor::NumRotatableBonds,
+        Descriptor::NumHbd,
+        Descriptor::NumHba,
+        Descriptor::NumHeavyAtoms,
+        Descriptor::NumAtoms,
+        Descriptor::NumHeteroatoms,
+        Descriptor::NumAmideBonds,
+        Descriptor::FractionCSp3,
+        Descriptor::NumRings,
+        Descriptor::NumAromaticRings,
+        Descriptor::NumAliphaticRings,
+        Descriptor::NumSaturatedRings,
+        Descriptor::NumHeterocycles,
+        Descriptor::NumAromaticHeterocycles,
+        Descriptor::NumSaturatedHeterocycles,
+        Descriptor::NumAliphaticHeterocycles,
+        Descriptor::NumSpiroAtoms,
+        Descriptor::NumBridgeheadAtoms,
+        Descriptor::NumAtomStereoCenters,
+        Descriptor::NumUnspecifiedAtomStereoCenters,
+        Descriptor::LabuteAsa,
+        Descriptor::Tpsa,
+        Descriptor::CrippenClogP,
+        Descriptor::CrippenMr,
+        Descriptor::Chi0v,
+        Descriptor::Chi1v,
+        Descriptor::Chi2v,
+        Descriptor::Chi3v,
+        Descriptor::Chi4v,
+        Descriptor::Chi0n,
+        Descriptor::Chi1n,
+        Descriptor::Chi2n,
+        Descriptor::Chi3n,
+        Descriptor::Chi4n,
+        Descriptor::HallKierAlpha,
+        Descriptor::Kappa1,
+        Descriptor::Kappa2,
+        Descriptor::Kappa3,
+        Descriptor::Phi,
+    ];
+
+    /// Returns the name RDKit uses for this descriptor
+    pub fn name(&self) -> &'static str {
+        match self {
+            Descriptor::ExactMw => "exactmw",
+            Descriptor::Amw => "amw",
+            Descriptor::LipinskiHba => "lipinskiHBA",
+            Descriptor::LipinskiHbd => "lipinskiHBD",
+            Descriptor::NumRotatableBonds => "NumRotatableBonds",
+            Descriptor::NumHbd => "NumHBD",
+            Descriptor::NumHba => "NumHBA",
+            Descriptor::NumHeavyAtoms => "NumHeavyAtoms",
+            Descriptor::NumAtoms => "NumAtoms",
+            Descriptor::NumHeteroatoms => "NumHeteroatoms",
+            Descriptor::NumAmideBonds => "NumAmideBonds",
+            Descriptor::FractionCSp3 => "FractionCSP3",
+            Descriptor::NumRings => "NumRings",
+            Descriptor::NumAromaticRings => "NumAromaticRings",
+            Descriptor::NumAliphaticRings => "NumAliphaticRings",
+            Descriptor::NumSaturatedRings => "NumSaturatedRings",
+            Descriptor::NumHeterocycles => "NumHeterocycles",
+            Descriptor::NumAromaticHeterocycles => "NumAromaticHeterocycles",
+            Descriptor::NumSaturatedHeterocycles => "NumSaturatedHeterocycles",
+            Descriptor::NumAliphaticHeterocycles => "NumAliphaticHeterocycles",
+            Descriptor::NumSpiroAtoms => "NumSpiroAtoms",
+            Descriptor::NumBridgeheadAtoms => "NumBridgeheadAtoms",
+            Descriptor::NumAtomStereoCenters => "NumAtomStereoCenters",
+            Descriptor::NumUnspecifiedAtomStereoCenters => "NumUnspecifiedAtomStereoCenters",
+            Descriptor::LabuteAsa => "labuteASA",
+            Descriptor::Tpsa => "tpsa",
+            Descriptor::CrippenClogP => "CrippenClogP",
+            Descriptor::CrippenMr => "CrippenMR",
+            Descriptor::Chi0v => "chi0v",
+            Descriptor::Chi1v => "chi1v",
+            Descriptor::Chi2v => "chi2v",
+            Descriptor::Chi3v => "chi3v",
+            Descriptor::Chi4v => "chi4v",
+            Descriptor::Chi0n => "chi0n",
+            Descriptor::Chi1n => "chi1n",
+            Descriptor::Chi2n => "chi2n",
+            Descriptor::Chi3n => "chi3n",
+            Descriptor::Chi4n => "chi4n",
+            Descriptor::HallKierAlpha => "hallKierAlpha",
+            Descriptor::Kappa1 => "kappa1",
+            Descriptor::Kappa2 => "kappa2",
+            Descriptor::Kappa3 => "kappa3",
+            Descriptor::Phi => "Phi",
+        }
+    }
+}
+
+impl std::fmt::Display for Descriptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for Descriptor {
+    type Err = PropertiesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Descriptor::ALL
+            .into_iter()
+            .find(|d| d.name() == s)
+            .ok_or_else(|| PropertiesError::UnknownDescriptor(s.to_string()))
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum PropertiesError {
+    #[error("unknown descriptor `{0}`")]
+    UnknownDescriptor(String),
+    #[error("descriptor `{0}` is missing from the computed properties")]
+    MissingDescriptor(Descriptor),
+    #[error("unexpected descriptor `{0}` in the computed properties")]
+    UnexpectedDescriptor(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MoleculeProperties {
     pub exact_mw: f64,
     pub amw: f64,
@@ -51,57 +225,79 @@ pub struct MoleculeProperties {
 }
 
 impl MoleculeProperties {
-    pub fn from_molecule(ro_mol: &ROMol) -> Self {
-        let props = Properties::new();
-        let values = rdkit_sys::descriptors_ffi::compute_properties(&props.ptr, &ro_mol.ptr);
-        let values_slice = values.as_slice();
-        assert_eq!(values.len(), 43, "expected 43 descriptors, got {}", values.len());
-
-
-        Self {
-            exact_mw: values_slice[0],
-            amw: values_slice[1],
-            lipinski_hba: values_slice[2].round() as usize,
-            lipinski_hbd: values_slice[3].round() as usize,
-            num_rotatable_bonds: values_slice[4].round() as usize,
-            num_hbd: values_slice[5].round() as usize,
-            num_hba: values_slice[6].round() as usize,
-            num_heavy_atoms: values_slice[7].round() as usize,
-            num_atoms: values_slice[8].round() as usize,
-            num_heteroatoms: values_slice[9].round() as usize,
-            num_amide_bonds: values_slice[10].round() as usize,
-            fraction_c_sp3: values_slice[11],
-            num_rings: values_slice[12].round() as usize,
-            num_aromatic_rings: values_slice[13].round() as usize,
-            num_aliphatic_rings: values_slice[14].round() as usize,
-            num_saturated_rings: values_slice[15].round() as usize,
-            num_heterocycles: values_slice[16].round() as usize,
-            num_aromatic_heterocycles: values_slice[17].round() as usize,
-            num_saturated_heterocycles: values_slice[18].round() as usize,
-            num_aliphatic_heterocycles: values_slice[19].round() as usize,
-            num_spiro_atoms: values_slice[20].round() as usize,
-            num_bridgehead_atoms: values_slice[21].round() as usize,
-            num_atom_stereo_centers: values_slice[22].round() as usize,
-            num_unspecified_atom_stereo_centers: values_slice[23].round() as usize,
-            labute_asa: values_slice[24],
-            tpsa: values_slice[25],
-            crippen_clog_p: values_slice[26],
-            crippen_mr: values_slice[27],
-            chi0v: values_slice[28],
-            chi1v: values_slice[29],
-            chi2v: values_slice[30],
-            chi3v: values_slice[31],
-            chi4v: values_slice[32],
-            chi0n: values_slice[33],
-            chi1n: values_slice[34],
-            chi2n: values_slice[35],
-            chi3n: values_slice[36],
-            chi4n: values_slice[37],
-            hall_kier_alpha: values_slice[38],
-            kappa1: values_slice[39],
-            kappa2: values_slice[40],
-            kappa3: values_slice[41],
-            phi: values_slice[42],
+    pub fn from_molecule(ro_mol: &ROMol) -> Result<Self, PropertiesError> {
+        // Only ask for the descriptors we have fields for, newer RDKit releases
+        // may register more
+        let props = Properties::with_descriptors(&Descriptor::ALL)?;
+        Self::try_from(props.compute_properties(ro_mol))
+    }
+}
+
+impl TryFrom<HashMap<String, f64>> for MoleculeProperties {
+    type Error = PropertiesError;
+
+    /// Builds the struct from the name to value map returned by
+    /// [`Properties::compute_properties`]. Every field must be present and no
+    /// other names are allowed.
+    fn try_from(mut values: HashMap<String, f64>) -> Result<Self, Self::Error> {
+        let mut take = |descriptor: Descriptor| {
+            values
+                .remove(descriptor.name())
+                .ok_or(PropertiesError::MissingDescriptor(descriptor))
+        };
+
+        let molecule_properties = Self {
+            exact_mw: take(Descriptor::ExactMw)?,
+            amw: take(Descriptor::Amw)?,
+            lipinski_hba: take(Descriptor::LipinskiHba)?.round() as usize,
+            lipinski_hbd: take(Descriptor::LipinskiHbd)?.round() as usize,
+            num_rotatable_bonds: take(Descriptor::NumRotatableBonds)?.round() as usize,
+            num_hbd: take(Descriptor::NumHbd)?.round() as usize,
+            num_hba: take(Descriptor::NumHba)?.round() as usize,
+            num_heavy_atoms: take(Descriptor::NumHeavyAtoms)?.round() as usize,
+            num_atoms: take(Descriptor::NumAtoms)?.round() as usize,
+            num_heteroatoms: take(Descriptor::NumHeteroatoms)?.round() as usize,
+            num_amide_bonds: take(Descriptor::NumAmideBonds)?.round() as usize,
+            fraction_c_sp3: take(Descriptor::FractionCSp3)?,
+            num_rings: take(Descriptor::NumRings)?.round() as usize,
+            num_aromatic_rings: take(Descriptor::NumAromaticRings)?.round() as usize,
+            num_aliphatic_rings: take(Descriptor::NumAliphaticRings)?.round() as usize,
+            num_saturated_rings: take(Descriptor::NumSaturatedRings)?.round() as usize,
+            num_heterocycles: take(Descriptor::NumHeterocycles)?.round() as usize,
+            num_aromatic_heterocycles: take(Descriptor::NumAromaticHeterocycles)?.round() as usize,
+            num_saturated_heterocycles: take(Descriptor::NumSaturatedHeterocycles)?.round()
+                as usize,
+            num_aliphatic_heterocycles: take(Descriptor::NumAliphaticHeterocycles)?.round()
+                as usize,
+            num_spiro_atoms: take(Descriptor::NumSpiroAtoms)?.round() as usize,
+            num_bridgehead_atoms: take(Descriptor::NumBridgeheadAtoms)?.round() as usize,
+            num_atom_stereo_centers: take(Descriptor::NumAtomStereoCenters)?.round() as usize,
+            num_unspecified_atom_stereo_centers: take(Descriptor::NumUnspecifiedAtomStereoCenters)?
+                .round() as usize,
+            labute_asa: take(Descriptor::LabuteAsa)?,
+            tpsa: take(Descriptor::Tpsa)?,
+            crippen_clog_p: take(Descriptor::CrippenClogP)?,
+            crippen_mr: take(Descriptor::CrippenMr)?,
+            chi0v: take(Descriptor::Chi0v)?,
+            chi1v: take(Descriptor::Chi1v)?,
+            chi2v: take(Descriptor::Chi2v)?,
+            chi3v: take(Descriptor::Chi3v)?,
+            chi4v: take(Descriptor::Chi4v)?,
+            chi0n: take(Descriptor::Chi0n)?,
+            chi1n: take(Descriptor::Chi1n)?,
+            chi2n: take(Descriptor::Chi2n)?,
+            chi3n: take(Descriptor::Chi3n)?,
+            chi4n: take(Descriptor::Chi4n)?,
+            hall_kier_alpha: take(Descriptor::HallKierAlpha)?,
+            kappa1: take(Descriptor::Kappa1)?,
+            kappa2: take(Descriptor::Kappa2)?,
+            kappa3: take(Descriptor::Kappa3)?,
+            phi: take(Descriptor::Phi)?,
+        };
+
+        match values.into_keys().min() {
+            Some(unexpected) => Err(PropertiesError::UnexpectedDescriptor(unexpected)),
+            None => Ok(molecule_properties),
         }
     }
 }
@@ -123,17 +319,134 @@ impl Properties {
         }
     }
 
+    /// Creates a calculator for the given subset of descriptor names, in the
+    /// order given. See [`Properties::available_names`] for valid names.
+    pub fn with_names<S: AsRef<str>>(names: &[S]) -> Result<Self, PropertiesError> {
+        let available = Self::available_names();
+        let names = names
+            .iter()
+            .map(|name| name.as_ref().to_string())
+            .collect::<Vec<_>>();
+
+        if let Some(unknown) = names.iter().find(|name| !available.contains(name)) {
+            return Err(PropertiesError::UnknownDescriptor(unknown.clone()));
+        }
+
+        let ptr = rdkit_sys::descriptors_ffi::new_properties_with_names(&names)
+            .map_err(|e| PropertiesError::UnknownDescriptor(e.what().to_string()))?;
+        Ok(Properties { ptr })
+    }
+
+    /// Creates a calculator for the given subset of descriptors
+    pub fn with_descriptors(descriptors: &[Descriptor]) -> Result<Self, PropertiesError> {
+        let names = descriptors.iter().map(Descriptor::name).collect::<Vec<_>>();
+        Self::with_names(&names)
+    }
+
+    /// Returns the names of all descriptors registered with RDKit
+    pub fn available_names() -> Vec<String> {
+        rdkit_sys::descriptors_ffi::get_available_properties()
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect()
+    }
+
+    /// Returns the names of the descriptors this calculator computes
+    pub fn names(&self) -> Vec<String> {
+        rdkit_sys::descriptors_ffi::get_property_names(&self.ptr)
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect()
+    }
+
     pub fn compute_properties(&self, ro_mol: &ROMol) -> HashMap<String, f64> {
-        let names = rdkit_sys::descriptors_ffi::get_property_names(&self.ptr);
-        let computed = rdkit_sys::descriptors_ffi::compute_properties(&self.ptr, &ro_mol.ptr);
+        let computed = self.try_compute_properties(ro_mol).unwrap();
 
-        assert!(names.len() != 0);
-        assert!(computed.len() == names.len());
+        assert!(!computed.is_empty());
 
-        names
+        computed
+    }
+
+    /// Like [`Properties::compute_properties`], but returns the error RDKit
+    /// raised for the molecule instead of panicking
+    pub fn try_compute_properties(
+        &self,
+        ro_mol: &ROMol,
+    ) -> Result<HashMap<String, f64>, cxx::Exception> {
+        let names = rdkit_sys::descriptors_ffi::get_property_names(&self.ptr);
+        let computed = rdkit_sys::descriptors_ffi::compute_properties(&self.ptr, &ro_mol.ptr)?;
+
+        Ok(names
             .into_iter()
             .zip(computed.as_slice())
             .map(|(k, v)| (k.to_string(), *v))
-            .collect()
+            .collect())
+    }
+}
+
+/// Per-atom contributions to the Wildman-Crippen logP and molar refractivity,
+/// together with the Crippen atom type assigned to each atom
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrippenContribs {
+    pub logp: Vec<f64>,
+    pub mr: Vec<f64>,
+    pub atom_types: Vec<String>,
+}
+
+impl CrippenContribs {
+    pub fn from_molecule(ro_mol: &ROMol) -> Self {
+        let contribs = rdkit_sys::descriptors_ffi::get_crippen_atom_contribs(&ro_mol.ptr);
+
+        CrippenContribs {
+            logp: contribs.logp,
+            mr: contribs.mr,
+            atom_types: contribs.atom_type_labels,
+        }
+    }
+}
+
+/// Per-atom contributions to the Labute approximate surface area. Implicit
+/// hydrogens are not attributed to their heavy atoms but summed up in
+/// `hydrogens`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LabuteAsaContribs {
+    pub atoms: Vec<f64>,
+    pub hydrogens: f64,
+}
+
+impl LabuteAsaContribs {
+    pub fn from_molecule(ro_mol: &ROMol, include_hs: bool) -> Self {
+        let contribs =
+            rdkit_sys::descriptors_ffi::get_labute_atom_contribs(&ro_mol.ptr, include_hs);
+
+        LabuteAsaContribs {
+            atoms: contribs.atoms,
+            hydrogens: contribs.hydrogens,
+        }
     }
 }
+
+/// Per-atom contributions to the topological polar surface area. Sulfur and
+/// phosphorus only contribute with `include_s_and_p`.
+pub fn tpsa_contribs(ro_mol: &ROMol, include_s_and_p: bool) -> Vec<f64> {
+    rdkit_sys::descriptors_ffi::get_tpsa_atom_contribs(&ro_mol.ptr, include_s_and_p)
+        .as_slice()
+        .to_vec()
+}
+
+/// Computes Gasteiger partial charges, one per atom. RDKit also stores them
+/// on each atom as the `_GasteigerCharge` property. Atoms without parameters
+/// get a charge of zero, unless `throw_on_param_failure` is set in which case
+/// an error is returned.
+pub fn gasteiger_charges(
+    ro_mol: &ROMol,
+    n_iter: i32,
+    throw_on_param_failure: bool,
+) -> Result<Vec<f64>, cxx::Exception> {
+    let charges = rdkit_sys::descriptors_ffi::compute_gasteiger_charges(
+        &ro_mol.ptr,
+        n_iter,
+        throw_on_param_failure,
+    )?;
+    Ok(charges.as_slice().to_vec())
+}
@@ -0,0 +1,81 @@
+use crate::ROMol;
+
+/// Shape descriptors of a single conformer. Moments of inertia and the
+/// ratios derived from them are weighted by atomic mass unless
+/// `use_atomic_masses` is unset, in which case every atom weighs the same.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Descriptors3D {
+    pub pmi1: f64,
+    pub pmi2: f64,
+    pub pmi3: f64,
+    pub npr1: f64,
+    pub npr2: f64,
+    pub radius_of_gyration: f64,
+    pub inertial_shape_factor: f64,
+    pub eccentricity: f64,
+    pub asphericity: f64,
+    pub spherocity_index: f64,
+    pub pbf: f64,
+}
+
+impl Descriptors3D {
+    /// A `conf_id` of -1 selects the default conformer. Fails if the molecule
+    /// has no conformer with that id.
+    pub fn from_molecule(
+        ro_mol: &ROMol,
+        conf_id: i32,
+        use_atomic_masses: bool,
+    ) -> Result<Self, cxx::Exception> {
+        use rdkit_sys::descriptors_ffi::*;
+
+        let ptr = &ro_mol.ptr;
+        Ok(Descriptors3D {
+            pmi1: calc_pmi1(ptr, conf_id, use_atomic_masses)?,
+            pmi2: calc_pmi2(ptr, conf_id, use_atomic_masses)?,
+            pmi3: calc_pmi3(ptr, conf_id, use_atomic_masses)?,
+            npr1: calc_npr1(ptr, conf_id, use_atomic_masses)?,
+            npr2: calc_npr2(ptr, conf_id, use_atomic_masses)?,
+            radius_of_gyration: calc_radius_of_gyration(ptr, conf_id, use_atomic_masses)?,
+            inertial_shape_factor: calc_inertial_shape_factor(ptr, conf_id, use_atomic_masses)?,
+            eccentricity: calc_eccentricity(ptr, conf_id, use_atomic_masses)?,
+            asphericity: calc_asphericity(ptr, conf_id, use_atomic_masses)?,
+            spherocity_index: calc_spherocity_index(ptr, conf_id)?,
+            pbf: calc_pbf(ptr, conf_id)?,
+        })
+    }
+}
+
+/// 3D autocorrelation descriptors, 80 values
+pub fn autocorr_3d(ro_mol: &ROMol, conf_id: i32) -> Result<Vec<f64>, cxx::Exception> {
+    let values = rdkit_sys::descriptors_ffi::calc_autocorr_3d(&ro_mol.ptr, conf_id)?;
+    Ok(values.as_slice().to_vec())
+}
+
+/// Radial distribution function descriptors, 210 values
+pub fn rdf(ro_mol: &ROMol, conf_id: i32) -> Result<Vec<f64>, cxx::Exception> {
+    let values = rdkit_sys::descriptors_ffi::calc_rdf(&ro_mol.ptr, conf_id)?;
+    Ok(values.as_slice().to_vec())
+}
+
+/// Molecule Representation of Structures based on Electron diffraction
+/// descriptors, 224 values
+pub fn morse(ro_mol: &ROMol, conf_id: i32) -> Result<Vec<f64>, cxx::Exception> {
+    let values = rdkit_sys::descriptors_ffi::calc_morse(&ro_mol.ptr, conf_id)?;
+    Ok(values.as_slice().to_vec())
+}
+
+/// Weighted Holistic Invariant Molecular descriptors, 114 values. RDKit's
+/// default `threshold` is 0.001.
+pub fn whim(ro_mol: &ROMol, conf_id: i32, threshold: f64) -> Result<Vec<f64>, cxx::Exception> {
+    let values = rdkit_sys::descriptors_ffi::calc_whim(&ro_mol.ptr, conf_id, threshold)?;
+    Ok(values.as_slice().to_vec())
+}
+
+/// GEometry, Topology, and Atom-Weights AssemblY descriptors, 273 values.
+/// `precision` is the number of decimals the leverage matrix is rounded to,
+/// RDKit's default is 2.
+pub fn getaway(ro_mol: &ROMol, conf_id: i32, precision: u32) -> Result<Vec<f64>, cxx::Exception> {
+    let values = rdkit_sys::descriptors_ffi::calc_getaway(&ro_mol.ptr, conf_id, precision)?;
+    Ok(values.as_slice().to_vec())
+}
@@ -0,0 +1,477 @@
+use crate::{
+    remove_hs, substruct_match, MoleculeProperties, PropertiesError, ROMol, RemoveHsParameters,
+    SubstructMatchParameters,
+};
+
+/// Hydrogen bond acceptor patterns used by QED, these differ from the
+/// Lipinski definitions in [`MoleculeProperties`]
+const QED_ACCEPTOR_SMARTS: [&str; 11] = [
+    "[oH0;X2]",
+    "[OH1;X2;v2]",
+    "[OH0;X2;v2]",
+    "[OH0;X1;v2]",
+    "[O-;X1]",
+    "[SH0;X2;v2]",
+    "[SH0;X1;v2]",
+    "[S-;X1]",
+    "[nH0;X2]",
+    "[NH0;X1;v3]",
+    "[$([N;+0;X3;v3]);!$(N[C,S]=O)]",
+];
+
+/// Structural alerts from Bickerton et al., Nature Chemistry 4, 90–98 (2012)
+const QED_ALERT_SMARTS: [&str; 116] = [
+    "*1[O,S,N]*1",
+    "[S,C](=[O,S])[F,Br,Cl,I]",
+    "[CX4][Cl,Br,I]",
+    "[#6]S(=O)(=O)O[#6]",
+    "[$([CH]),$(CC)]#CC(=O)[#6]",
+    "[$([CH]),$(CC)]#CC(=O)O[#6]",
+    "n[OH]",
+    "[$([CH]),$(CC)]#CS(=O)(=O)[#6]",
+    "C=C(C=O)C=O",
+    "n1c([F,Cl,Br,I])cccc1",
+    "[CH1](=O)",
+    "[#8][#8]",
+    "[C;!R]=[N;!R]",
+    "[N!R]=[N!R]",
+    "[#6](=O)[#6](=O)",
+    "[#16][#16]",
+    "[#7][NH2]",
+    "C(=O)N[NH2]",
+    "[#6]=S",
+    "[$([CH2]),$([CH][CX4]),$(C([CX4])[CX4])]=[$([CH2]),$([CH][CX4]),$(C([CX4])[CX4])]",
+    "C1(=[O,N])C=CC(=[O,N])C=C1",
+    "C1(=[O,N])C(=[O,N])C=CC=C1",
+    "a21aa3a(aa1aaaa2)aaaa3",
+    "a31a(a2a(aa1)aaaa2)aaaa3",
+    "a1aa2a3a(a1)A=AA=A3=AA=A2",
+    "c1cc([NH2])ccc1",
+    "[Hg,Fe,As,Sb,Zn,Se,se,Te,B,Si,Na,Ca,Ge,Ag,Mg,K,Ba,Sr,Be,Ti,Mo,Mn,Ru,Pd,Ni,Cu,Au,Cd,Al,Ga,Sn,Rh,Tl,Bi,Nb,Li,Pb,Hf,Ho]",
+    "I",
+    "OS(=O)(=O)[O-]",
+    "[N+](=O)[O-]",
+    "C(=O)N[OH]",
+    "C1NC(=O)NC(=O)1",
+    "[SH]",
+    "[S-]",
+    "c1ccc([Cl,Br,I,F])c([Cl,Br,I,F])c1[Cl,Br,I,F]",
+    "c1cc([Cl,Br,I,F])cc([Cl,Br,I,F])c1[Cl,Br,I,F]",
+    "[CR1]1[CR1][CR1][CR1][CR1][CR1][CR1]1",
+    "[CR1]1[CR1][CR1]cc[CR1][CR1]1",
+    "[CR2]1[CR2][CR2][CR2][CR2][CR2][CR2][CR2]1",
+    "[CR2]1[CR2][CR2]cc[CR2][CR2][CR2]1",
+    "[CH2R2]1N[CH2R2][CH2R2][CH2R2][CH2R2][CH2R2]1",
+    "[CH2R2]1N[CH2R2][CH2R2][CH2R2][CH2R2][CH2R2][CH2R2]1",
+    "C#C",
+    "[OR2,NR2]@[CR2]@[CR2]@[OR2,NR2]@[CR2]@[CR2]@[OR2,NR2]",
+    "[$([N+R]),$([n+R]),$([N+]=C)][O-]",
+    "[#6]=N[OH]",
+    "[#6]=NOC=O",
+    "[#6](=O)[CX4,CR0X3,O][#6](=O)",
+    "c1ccc2c(c1)ccc(=O)o2",
+    "[O+,o+,S+,s+]",
+    "N=C=O",
+    "[NX3,NX4][F,Cl,Br,I]",
+    "c1ccccc1OC(=O)[#6]",
+    "[CR0]=[CR0][CR0]=[CR0]",
+    "[C+,c+,C-,c-]",
+    "N=[N+]=[N-]",
+    "C12C(NC(N1)=O)CSC2",
+    "c1c([OH])c([OH,NH2,NH])ccc1",
+    "P",
+    "[N,O,S]C#N",
+    "C=C=O",
+    "[Si][F,Cl,Br,I]",
+    "[SX2]O",
+    "[SiR0,CR0](c1ccccc1)(c2ccccc2)(c3ccccc3)",
+    "O1CCCCC1OC2CCC3CCCCC3C2",
+    "N=[CR0][N,n,O,S]",
+    "[cR2]1[cR2][cR2]([Nv3X3,Nv4X4])[cR2][cR2][cR2]1[cR2]2[cR2][cR2][cR2]([Nv3X3,Nv4X4])[cR2][cR2]2",
+    "C=[C!r]C#N",
+    "[cR2]1[cR2]c([N+0X3R0,nX3R0])c([N+0X3R0,nX3R0])[cR2][cR2]1",
+    "[cR2]1[cR2]c([N+0X3R0,nX3R0])[cR2]c([N+0X3R0,nX3R0])[cR2]1",
+    "[cR2]1[cR2]c([N+0X3R0,nX3R0])[cR2][cR2]c1([N+0X3R0,nX3R0])",
+    "[OH]c1ccc([OH,NH2,NH])cc1",
+    "c1ccccc1OC(=O)O",
+    "[SX2H0][N]",
+    "c12ccccc1(SC(S)=N2)",
+    "c12ccccc1(SC(=S)N2)",
+    "c1nnnn1C=O",
+    "s1c(S)nnc1NC=O",
+    "S1C=CSC1=S",
+    "C(=O)Onnn",
+    "OS(=O)(=O)C(F)(F)F",
+    "N#CC[OH]",
+    "N#CC(=O)",
+    "S(=O)(=O)C#N",
+    "N[CH2]C#N",
+    "C1(=O)NCC1",
+    "S(=O)(=O)[O-,OH]",
+    "NC[F,Cl,Br,I]",
+    "C=[C!r]O",
+    "[NX2+0]=[O+0]",
+    "[OR0,NR0][OR0,NR0]",
+    "C(=O)O[C,H1].C(=O)O[C,H1].C(=O)O[C,H1]",
+    "[CX2R0][NX3R0]",
+    "c1ccccc1[C;!R]=[C;!R]c2ccccc2",
+    "[NX3R0,NX4R0,OR0,SX2R0][CX4][NX3R0,NX4R0,OR0,SX2R0]",
+    "[s,S,c,C,n,N,o,O]~[n+,N+](~[s,S,c,C,n,N,o,O])(~[s,S,c,C,n,N,o,O])~[s,S,c,C,n,N,o,O]",
+    "[s,S,c,C,n,N,o,O]~[nX3+,NX3+](~[s,S,c,C,n,N])~[s,S,c,C,n,N]",
+    "[*]=[N+]=[*]",
+    "[SX3](=O)[O-,OH]",
+    "N#N",
+    "F.F.F.F",
+    "[R0;D2][R0;D2][R0;D2][R0;D2]",
+    "[cR,CR]~C(=O)NC(=O)~[cR,CR]",
+    "C=!@CC=[O,S]",
+    "[#6,#8,#16][#6](=O)O[#6]",
+    "c[C;R0](=[O,S])[#6]",
+    "c[SX2][C;!R]",
+    "C=C=C",
+    "c1nc([F,Cl,Br,I,S])ncc1",
+    "c1ncnc([F,Cl,Br,I,S])c1",
+    "c1nc(c2c(n1)nc(n2)[F,Cl,Br,I])",
+    "[#6]S(=O)(=O)c1ccc(cc1)F",
+    "[15N]",
+    "[13C]",
+    "[18O]",
+    "[34S]",
+];
+
+thread_local! {
+    static QED_ACCEPTORS: Vec<ROMol> = parse_smarts(&QED_ACCEPTOR_SMARTS);
+    static QED_ALERTS: Vec<ROMol> = parse_smarts(&QED_ALERT_SMARTS);
+}
+
+fn parse_smarts(smarts: &[&str]) -> Vec<ROMol> {
+    smarts
+        .iter()
+        .map(|s| ROMol::from_smarts(s).unwrap_or_else(|| panic!("invalid QED smarts `{s}`")))
+        .collect()
+}
+
+/// The eight properties QED is built from. The same shape holds the raw
+/// values, their desirabilities and the weights used to combine them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QedProperties {
+    pub mw: f64,
+    pub alogp: f64,
+    pub hba: f64,
+    pub hbd: f64,
+    pub psa: f64,
+    pub rotb: f64,
+    /// RDKit's aromatic ring count. RDKit's QED instead counts the SSSR
+    /// after deleting aliphatic ring atoms, which differs for some fused
+    /// ring systems.
+    pub arom: f64,
+    pub alerts: f64,
+}
+
+struct AdsParameter {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    e: f64,
+    f: f64,
+    dmax: f64,
+}
+
+impl AdsParameter {
+    /// Asymmetric double sigmoid, normalised to a maximum of 1
+    fn desirability(&self, x: f64) -> f64 {
+        let exp1 = 1.0 + (-(x - self.c + self.d / 2.0) / self.e).exp();
+        let exp2 = 1.0 + (-(x - self.c - self.d / 2.0) / self.f).exp();
+        (self.a + self.b / exp1 * (1.0 - 1.0 / exp2)) / self.dmax
+    }
+}
+
+#[rustfmt::skip]
+const ADS_PARAMETERS: [AdsParameter; 8] = [
+    AdsParameter { a: 2.817065973, b: 392.5754953, c: 290.7489764, d: 2.419764353, e: 49.22325677, f: 65.37051707, dmax: 104.9805561 },
+    AdsParameter { a: 3.172690585, b: 137.8624751, c: 2.534937431, d: 4.581497897, e: 0.822739154, f: 0.576295591, dmax: 131.3186604 },
+    AdsParameter { a: 2.948620388, b: 160.4605972, c: 3.615294657, d: 4.435986202, e: 0.290141953, f: 1.300669958, dmax: 148.7763046 },
+    AdsParameter { a: 1.618662227, b: 1010.051101, c: 0.985094388, d: 0.000000001, e: 0.713820843, f: 0.920922555, dmax: 258.1632616 },
+    AdsParameter { a: 1.876861559, b: 125.2232657, c: 62.90773554, d: 87.83366614, e: 12.01999824, f: 28.51324732, dmax: 104.5686167 },
+    AdsParameter { a: 0.010000000, b: 272.4121427, c: 2.558379970, d: 1.566868287, e: 1.638418589, f: 2.768916273, dmax: 105.4420403 },
+    AdsParameter { a: 3.217788970, b: 957.7374108, c: 2.274627939, d: 0.000000001, e: 1.317690384, f: 2.767990299, dmax: 312.3372610 },
+    AdsParameter { a: 0.010000000, b: 1199.094025, c: -0.09002883, d: 0.000000001, e: 0.185904477, f: 0.875193782, dmax: 417.7253140 },
+];
+
+impl QedProperties {
+    /// Weights from the original publication, `qed` uses these by default
+    pub const WEIGHT_MEAN: QedProperties = QedProperties {
+        mw: 0.66,
+        alogp: 0.46,
+        hba: 0.05,
+        hbd: 0.61,
+        psa: 0.06,
+        rotb: 0.65,
+        arom: 0.48,
+        alerts: 0.95,
+    };
+
+    pub const WEIGHT_MAX: QedProperties = QedProperties {
+        mw: 0.50,
+        alogp: 0.25,
+        hba: 0.00,
+        hbd: 0.50,
+        psa: 0.00,
+        rotb: 0.50,
+        arom: 0.25,
+        alerts: 1.00,
+    };
+
+    pub const WEIGHT_NONE: QedProperties = QedProperties {
+        mw: 1.0,
+        alogp: 1.0,
+        hba: 1.0,
+        hbd: 1.0,
+        psa: 1.0,
+        rotb: 1.0,
+        arom: 1.0,
+        alerts: 1.0,
+    };
+
+    /// Computes the QED inputs on a copy of the molecule with hydrogens
+    /// removed. See [`QedProperties::arom`] for how aromatic rings are
+    /// counted.
+    pub fn from_molecule(ro_mol: &ROMol) -> Result<Self, PropertiesError> {
+        let mol = remove_hs(ro_mol, &RemoveHsParameters::default(), true);
+        let props = MoleculeProperties::from_molecule(&mol)?;
+
+        let all_matches = SubstructMatchParameters::new();
+        let hba = QED_ACCEPTORS.with(|acceptors| {
+            acceptors
+                .iter()
+                .map(|pattern| substruct_match(&mol, pattern, &all_matches).len())
+                .sum::<usize>()
+        });
+
+        let first_match = SubstructMatchParameters::new().max_matches(1);
+        let alerts = QED_ALERTS.with(|alerts| {
+            alerts
+                .iter()
+                .filter(|alert| !substruct_match(&mol, alert, &first_match).is_empty())
+                .count()
+        });
+
+        Ok(QedProperties {
+            mw: props.amw,
+            alogp: props.crippen_clog_p,
+            hba: hba as f64,
+            hbd: props.num_hbd as f64,
+            psa: props.tpsa,
+            rotb: props.num_rotatable_bonds as f64,
+            arom: props.num_aromatic_rings as f64,
+            alerts: alerts as f64,
+        })
+    }
+
+    /// Maps each property onto its desirability in `0..=1`
+    pub fn desirabilities(&self) -> QedProperties {
+        let [mw, alogp, hba, hbd, psa, rotb, arom, alerts] = self.to_array();
+        QedProperties {
+            mw: ADS_PARAMETERS[0].desirability(mw),
+            alogp: ADS_PARAMETERS[1].desirability(alogp),
+            hba: ADS_PARAMETERS[2].desirability(hba),
+            hbd: ADS_PARAMETERS[3].desirability(hbd),
+            psa: ADS_PARAMETERS[4].desirability(psa),
+            rotb: ADS_PARAMETERS[5].desirability(rotb),
+            arom: ADS_PARAMETERS[6].desirability(arom),
+            alerts: ADS_PARAMETERS[7].desirability(alerts),
+        }
+    }
+
+    /// Weighted geometric mean of the desirabilities
+    pub fn qed(&self, weights: &QedProperties) -> f64 {
+        let desirabilities = self.desirabilities().to_array();
+        let weights = weights.to_array();
+
+        let total = desirabilities
+            .iter()
+            .zip(weights.iter())
+            .map(|(d, w)| w * d.ln())
+            .sum::<f64>();
+        (total / weights.iter().sum::<f64>()).exp()
+    }
+
+    pub fn to_array(&self) -> [f64; 8] {
+        [
+            self.mw,
+            self.alogp,
+            self.hba,
+            self.hbd,
+            self.psa,
+            self.rotb,
+            self.arom,
+            self.alerts,
+        ]
+    }
+}
+
+/// Quantitative estimate of drug-likeness using the mean weights. Results
+/// can differ slightly from RDKit's Python `QED.qed` for fused ring systems
+/// where the aromatic ring counts disagree, see [`QedProperties::arom`].
+pub fn qed(ro_mol: &ROMol) -> Result<f64, PropertiesError> {
+    qed_with_weights(ro_mol, &QedProperties::WEIGHT_MEAN)
+}
+
+/// Quantitative estimate of drug-likeness with every property weighted
+/// equally
+pub fn qed_unweighted(ro_mol: &ROMol) -> Result<f64, PropertiesError> {
+    qed_with_weights(ro_mol, &QedProperties::WEIGHT_NONE)
+}
+
+pub fn qed_with_weights(ro_mol: &ROMol, weights: &QedProperties) -> Result<f64, PropertiesError> {
+    Ok(QedProperties::from_molecule(ro_mol)?.qed(weights))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DrugLikenessRule {
+    /// Lipinski's rule of five, one violation is tolerated
+    Lipinski,
+    Veber,
+    Ghose,
+    Egan,
+    Muegge,
+}
+
+impl DrugLikenessRule {
+    pub const ALL: [DrugLikenessRule; 5] = [
+        DrugLikenessRule::Lipinski,
+        DrugLikenessRule::Veber,
+        DrugLikenessRule::Ghose,
+        DrugLikenessRule::Egan,
+        DrugLikenessRule::Muegge,
+    ];
+
+    /// Number of violations a molecule may have and still pass
+    pub fn allowed_violations(&self) -> usize {
+        match self {
+            DrugLikenessRule::Lipinski => 1,
+            _ => 0,
+        }
+    }
+
+    /// Lists the `(property, value, min, max)` bounds this rule checks
+    fn bounds(
+        &self,
+        props: &MoleculeProperties,
+    ) -> Vec<(&'static str, f64, Option<f64>, Option<f64>)> {
+        let clog_p = props.crippen_clog_p;
+        let rotatable_bonds = props.num_rotatable_bonds as f64;
+        let heteroatoms = props.num_heteroatoms as f64;
+        // dummy atoms count as heteroatoms but not as heavy atoms
+        let carbons = props.num_heavy_atoms.saturating_sub(props.num_heteroatoms) as f64;
+
+        match self {
+            DrugLikenessRule::Lipinski => vec![
+                ("exact_mw", props.exact_mw, None, Some(500.0)),
+                ("crippen_clog_p", clog_p, None, Some(5.0)),
+                ("lipinski_hbd", props.lipinski_hbd as f64, None, Some(5.0)),
+                ("lipinski_hba", props.lipinski_hba as f64, None, Some(10.0)),
+            ],
+            DrugLikenessRule::Veber => vec![
+                ("num_rotatable_bonds", rotatable_bonds, None, Some(10.0)),
+                ("tpsa", props.tpsa, None, Some(140.0)),
+            ],
+            DrugLikenessRule::Ghose => vec![
+                ("amw", props.amw, Some(160.0), Some(480.0)),
+                ("crippen_clog_p", clog_p, Some(-0.4), Some(5.6)),
+                ("crippen_mr", props.crippen_mr, Some(40.0), Some(130.0)),
+                ("num_atoms", props.num_atoms as f64, Some(20.0), Some(70.0)),
+            ],
+            DrugLikenessRule::Egan => vec![
+                ("crippen_clog_p", clog_p, None, Some(5.88)),
+                ("tpsa", props.tpsa, None, Some(131.6)),
+            ],
+            DrugLikenessRule::Muegge => vec![
+                ("exact_mw", props.exact_mw, Some(200.0), Some(600.0)),
+                ("crippen_clog_p", clog_p, Some(-2.0), Some(5.0)),
+                ("tpsa", props.tpsa, None, Some(150.0)),
+                ("num_rings", props.num_rings as f64, None, Some(7.0)),
+                ("num_carbons", carbons, Some(5.0), None),
+                ("num_heteroatoms", heteroatoms, Some(2.0), None),
+                ("num_rotatable_bonds", rotatable_bonds, None, Some(15.0)),
+                ("num_hba", props.num_hba as f64, None, Some(10.0)),
+                ("num_hbd", props.num_hbd as f64, None, Some(5.0)),
+            ],
+        }
+    }
+
+    pub fn evaluate(&self, props: &MoleculeProperties) -> RuleEvaluation {
+        let violations = self
+            .bounds(props)
+            .into_iter()
+            .filter(|(_, value, min, max)| {
+                min.is_some_and(|min| *value < min) || max.is_some_and(|max| *value > max)
+            })
+            .map(|(property, value, min, max)| RuleViolation {
+                property,
+                value,
+                min,
+                max,
+            })
+            .collect::<Vec<_>>();
+
+        RuleEvaluation {
+            rule: *self,
+            passed: violations.len() <= self.allowed_violations(),
+            violations,
+        }
+    }
+}
+
+/// A property outside the inclusive `min..=max` range of a rule
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleViolation {
+    pub property: &'static str,
+    pub value: f64,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleEvaluation {
+    pub rule: DrugLikenessRule,
+    pub passed: bool,
+    pub violations: Vec<RuleViolation>,
+}
+
+pub struct DrugLikenessRules {
+    rules: Vec<DrugLikenessRule>,
+}
+
+impl Default for DrugLikenessRules {
+    fn default() -> Self {
+        DrugLikenessRules::new()
+    }
+}
+
+impl DrugLikenessRules {
+    /// Evaluates every rule in [`DrugLikenessRule::ALL`]
+    pub fn new() -> Self {
+        DrugLikenessRules {
+            rules: DrugLikenessRule::ALL.to_vec(),
+        }
+    }
+
+    pub fn with_rules(rules: &[DrugLikenessRule]) -> Self {
+        DrugLikenessRules {
+            rules: rules.to_vec(),
+        }
+    }
+
+    pub fn evaluate(&self, props: &MoleculeProperties) -> Vec<RuleEvaluation> {
+        self.rules.iter().map(|rule| rule.evaluate(props)).collect()
+    }
+
+    pub fn evaluate_molecule(
+        &self,
+        ro_mol: &ROMol,
+    ) -> Result<Vec<RuleEvaluation>, PropertiesError> {
+        Ok(self.evaluate(&MoleculeProperties::from_molecule(ro_mol)?))
+    }
+}
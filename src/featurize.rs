@@ -0,0 +1,160 @@
+use ndarray::Array2;
+
+use crate::{DescriptorCalculator, Fingerprint, Properties, ROMol};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum FeaturizeError {
+    #[error("fingerprint has {actual} bits, expected {expected}")]
+    FingerprintLength { expected: usize, actual: usize },
+    #[error("descriptor calculation failed: {0}")]
+    Descriptor(String),
+    #[error("descriptor `{0}` is not finite")]
+    NonFinite(String),
+}
+
+/// A row of a [`FeatureMatrix`] that could not be computed
+#[derive(Debug, PartialEq)]
+pub struct RowError {
+    pub row: usize,
+    pub error: FeaturizeError,
+}
+
+/// One row of features per input molecule. Rows listed in `errors` are left
+/// zeroed for fingerprints and filled with NaN for descriptors.
+#[derive(Debug)]
+pub struct FeatureMatrix<T> {
+    pub features: Array2<T>,
+    pub columns: Vec<String>,
+    pub errors: Vec<RowError>,
+}
+
+impl<T> FeatureMatrix<T> {
+    pub fn failed_rows(&self) -> Vec<usize> {
+        self.errors.iter().map(|error| error.row).collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FingerprintKind {
+    Rdk,
+    Pattern,
+    Morgan,
+}
+
+impl FingerprintKind {
+    pub fn compute(&self, ro_mol: &ROMol) -> Fingerprint {
+        match self {
+            FingerprintKind::Rdk => ro_mol.rdk_fingerprint(),
+            FingerprintKind::Pattern => ro_mol.pattern_fingerprint(),
+            FingerprintKind::Morgan => ro_mol.morgan_fingerprint(),
+        }
+    }
+}
+
+/// Stacks the fingerprints of `mols` into an `(n_mols, n_bits)` matrix of
+/// zeros and ones. The number of bits is taken from the first molecule.
+pub fn fingerprint_matrix(mols: &[ROMol], kind: FingerprintKind) -> FeatureMatrix<u8> {
+    fingerprint_matrix_with(mols, kind, |bit| bit as u8)
+}
+
+/// Like [`fingerprint_matrix`] but with `f32` entries, ready to be fed to a
+/// model
+pub fn fingerprint_matrix_f32(mols: &[ROMol], kind: FingerprintKind) -> FeatureMatrix<f32> {
+    fingerprint_matrix_with(mols, kind, |bit| if bit { 1.0 } else { 0.0 })
+}
+
+fn fingerprint_matrix_with<T: Clone>(
+    mols: &[ROMol],
+    kind: FingerprintKind,
+    convert: impl Fn(bool) -> T,
+) -> FeatureMatrix<T> {
+    let fingerprints = mols.iter().map(|mol| kind.compute(mol)).collect::<Vec<_>>();
+    let n_bits = fingerprints
+        .first()
+        .map(|fingerprint| fingerprint.0.len())
+        .unwrap_or(0);
+
+    let mut features = Array2::from_elem((mols.len(), n_bits), convert(false));
+    let mut errors = vec![];
+    for (row, fingerprint) in fingerprints.iter().enumerate() {
+        if fingerprint.0.len() != n_bits {
+            errors.push(RowError {
+                row,
+                error: FeaturizeError::FingerprintLength {
+                    expected: n_bits,
+                    actual: fingerprint.0.len(),
+                },
+            });
+            continue;
+        }
+        for (value, bit) in features.row_mut(row).iter_mut().zip(fingerprint.0.iter()) {
+            *value = convert(*bit);
+        }
+    }
+
+    FeatureMatrix {
+        features,
+        columns: (0..n_bits).map(|bit| bit.to_string()).collect(),
+        errors,
+    }
+}
+
+/// Computes the descriptors selected in `properties` for each molecule, one
+/// column per name in [`Properties::names`]. Rows RDKit fails on or that have
+/// non-finite values are reported as failed.
+pub fn descriptor_matrix(mols: &[ROMol], properties: &Properties) -> FeatureMatrix<f64> {
+    let columns = properties.names();
+    descriptor_matrix_with(mols, columns.clone(), |mol| {
+        let computed = properties
+            .try_compute_properties(mol)
+            .map_err(|e| FeaturizeError::Descriptor(e.what().to_string()))?;
+        columns
+            .iter()
+            .map(|name| match computed.get(name) {
+                Some(value) if value.is_finite() => Ok(*value),
+                Some(_) => Err(FeaturizeError::NonFinite(name.clone())),
+                None => Err(FeaturizeError::Descriptor(format!(
+                    "`{name}` was not computed"
+                ))),
+            })
+            .collect()
+    })
+}
+
+/// Computes the descriptor blocks of `calculator` for each molecule, one
+/// column per name in [`DescriptorCalculator::names`]
+pub fn descriptor_block_matrix(
+    mols: &[ROMol],
+    calculator: &DescriptorCalculator,
+) -> FeatureMatrix<f64> {
+    descriptor_matrix_with(mols, calculator.names(), |mol| {
+        calculator
+            .compute_values(mol)
+            .map_err(|e| FeaturizeError::Descriptor(e.what().to_string()))
+    })
+}
+
+fn descriptor_matrix_with(
+    mols: &[ROMol],
+    columns: Vec<String>,
+    compute_row: impl Fn(&ROMol) -> Result<Vec<f64>, FeaturizeError>,
+) -> FeatureMatrix<f64> {
+    let mut features = Array2::from_elem((mols.len(), columns.len()), f64::NAN);
+    let mut errors = vec![];
+    for (row, mol) in mols.iter().enumerate() {
+        match compute_row(mol) {
+            Ok(values) => features
+                .row_mut(row)
+                .iter_mut()
+                .zip(values)
+                .for_each(|(feature, value)| *feature = value),
+            Err(error) => errors.push(RowError { row, error }),
+        }
+    }
+
+    FeatureMatrix {
+        features,
+        columns,
+        errors,
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::OnceLock,
+};
+
+use crate::PeriodicTable;
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum FormulaError {
+    #[error("could not parse formula `{0}`")]
+    Parse(String),
+    #[error("unknown element `{0}` in formula")]
+    UnknownElement(String),
+}
+
+/// One element entry of a molecular formula. `isotope` is `None` for atoms
+/// with natural isotopic abundance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormulaElement {
+    pub symbol: String,
+    pub atomic_number: u32,
+    pub isotope: Option<u32>,
+    pub count: u32,
+}
+
+/// A parsed molecular formula as written by RDKit's `CalcMolFormula`
+#[derive(Clone, Debug, PartialEq)]
+pub struct MolecularFormula {
+    pub elements: Vec<FormulaElement>,
+    pub charge: i32,
+}
+
+impl MolecularFormula {
+    /// Parses formulas like `C9H8O4`, `C[13C]H5DO` or `C2H7N+`. `D` and `T`
+    /// are read as hydrogen isotopes.
+    pub fn parse(formula: &str) -> Result<Self, FormulaError> {
+        let parse_error = || FormulaError::Parse(formula.to_string());
+        let chars = formula.chars().collect::<Vec<_>>();
+        let mut elements = vec![];
+        let mut charge = 0;
+        let mut pos = 0;
+
+        let read_number = |pos: &mut usize| {
+            let start = *pos;
+            while *pos < chars.len() && chars[*pos].is_ascii_digit() {
+                *pos += 1;
+            }
+            chars[start..*pos].iter().collect::<String>()
+        };
+
+        while pos < chars.len() {
+            let mut isotope = None;
+            let symbol = match chars[pos] {
+                '[' => {
+                    pos += 1;
+                    let mass_number = read_number(&mut pos);
+                    isotope = Some(mass_number.parse::<u32>().map_err(|_| parse_error())?);
+                    let end = chars[pos..]
+                        .iter()
+                        .position(|c| *c == ']')
+                        .ok_or_else(parse_error)?;
+                    let symbol = chars[pos..pos + end].iter().collect::<String>();
+                    pos += end + 1;
+                    symbol
+                }
+                '+' | '-' => {
+                    let sign = if chars[pos] == '+' { 1 } else { -1 };
+                    pos += 1;
+                    let magnitude = read_number(&mut pos);
+                    if pos != chars.len() {
+                        return Err(parse_error());
+                    }
+                    charge = sign * magnitude.parse::<i32>().unwrap_or(1);
+                    break;
+                }
+                c if c.is_ascii_uppercase() => {
+                    let start = pos;
+                    pos += 1;
+                    if pos < chars.len() && chars[pos].is_ascii_lowercase() {
+                        pos += 1;
+                    }
+                    chars[start..pos].iter().collect::<String>()
+                }
+                _ => return Err(parse_error()),
+            };
+
+            let count = read_number(&mut pos);
+            let count = if count.is_empty() {
+                1
+            } else {
+                count.parse::<u32>().map_err(|_| parse_error())?
+            };
+
+            let (symbol, isotope) = match symbol.as_str() {
+                "D" => ("H".to_string(), Some(2)),
+                "T" => ("H".to_string(), Some(3)),
+                _ => (symbol, isotope),
+            };
+
+            let atomic_number = match atomic_numbers().get(&symbol) {
+                Some(atomic_number) => *atomic_number,
+                None => return Err(FormulaError::UnknownElement(symbol)),
+            };
+
+            elements.push(FormulaElement {
+                symbol,
+                atomic_number,
+                isotope,
+                count,
+            });
+        }
+
+        Ok(MolecularFormula { elements, charge })
+    }
+
+    /// Sum of the mass numbers of the most common isotope of each atom, or of
+    /// the given isotope for labelled atoms
+    pub fn nominal_mass(&self) -> u32 {
+        self.elements
+            .iter()
+            .map(|element| {
+                let mass_number = element.isotope.unwrap_or_else(|| {
+                    PeriodicTable::get_most_common_isotope(element.atomic_number) as u32
+                });
+                mass_number * element.count
+            })
+            .sum()
+    }
+
+    /// Computes the isotopic distribution of the formula. Peaks are binned by
+    /// nominal mass and reported at their abundance weighted mean mass; peaks
+    /// below `min_relative_abundance` of the most intense peak are dropped.
+    pub fn isotope_pattern(&self, min_relative_abundance: f64) -> Vec<IsotopePeak> {
+        // nominal mass -> (abundance, abundance weighted mass)
+        let mut distribution = BTreeMap::from([(0u32, (1.0f64, 0.0f64))]);
+
+        for element in &self.elements {
+            let isotopes = match element.isotope {
+                Some(isotope) => vec![(
+                    isotope,
+                    PeriodicTable::get_mass_for_isotope(element.atomic_number, isotope),
+                    1.0,
+                )],
+                None => natural_isotopes(element.atomic_number),
+            };
+
+            for _ in 0..element.count {
+                let mut next = BTreeMap::new();
+                for (nominal, (abundance, weighted_mass)) in &distribution {
+                    for (mass_number, mass, fraction) in &isotopes {
+                        let entry = next.entry(nominal + mass_number).or_insert((0.0, 0.0));
+                        entry.0 += abundance * fraction;
+                        entry.1 += fraction * weighted_mass + abundance * fraction * mass;
+                    }
+                }
+                next.retain(|_, (abundance, _)| *abundance > 1e-12);
+                distribution = next;
+            }
+        }
+
+        let total = distribution
+            .values()
+            .map(|(abundance, _)| abundance)
+            .sum::<f64>();
+        let max = distribution
+            .values()
+            .map(|(abundance, _)| *abundance)
+            .fold(0.0, f64::max);
+
+        distribution
+            .into_values()
+            .filter(|(abundance, _)| *abundance >= max * min_relative_abundance)
+            .map(|(abundance, weighted_mass)| IsotopePeak {
+                mass: weighted_mass / abundance,
+                abundance: abundance / total,
+            })
+            .collect()
+    }
+}
+
+/// RDKit asserts on unknown symbols, so look them up in a table built from
+/// the periodic table instead
+fn atomic_numbers() -> &'static HashMap<String, u32> {
+    static ATOMIC_NUMBERS: OnceLock<HashMap<String, u32>> = OnceLock::new();
+    ATOMIC_NUMBERS.get_or_init(|| {
+        (1..=PeriodicTable::get_max_atomic_number())
+            .map(|atomic_number| {
+                (
+                    PeriodicTable::get_element_symbol(atomic_number),
+                    atomic_number,
+                )
+            })
+            .collect()
+    })
+}
+
+/// Returns `(mass number, mass, fraction)` for the naturally occurring
+/// isotopes of an element
+fn natural_isotopes(atomic_number: u32) -> Vec<(u32, f64, f64)> {
+    let most_common = PeriodicTable::get_most_common_isotope(atomic_number).max(1) as u32;
+    let isotopes = (most_common.saturating_sub(12).max(1)..=most_common + 12)
+        .map(|isotope| {
+            (
+                isotope,
+                PeriodicTable::get_mass_for_isotope(atomic_number, isotope),
+                PeriodicTable::get_abundance_for_isotope(atomic_number, isotope),
+            )
+        })
+        .filter(|(_, _, abundance)| *abundance > 0.0)
+        .collect::<Vec<_>>();
+
+    // abundances are tabulated in percent and don't always add up to 100
+    let total = isotopes
+        .iter()
+        .map(|(_, _, abundance)| abundance)
+        .sum::<f64>();
+    if total == 0.0 {
+        return vec![(
+            most_common,
+            PeriodicTable::get_mass_for_isotope(atomic_number, most_common),
+            1.0,
+        )];
+    }
+
+    isotopes
+        .into_iter()
+        .map(|(isotope, mass, abundance)| (isotope, mass, abundance / total))
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IsotopePeak {
+    pub mass: f64,
+    /// Fraction of the whole distribution, before any peaks were dropped
+    pub abundance: f64,
+}
@@ -0,0 +1,276 @@
+use crate::{BondStereo, BondType, ChiralType, HybridizationType, ROMol};
+
+/// A per-atom feature. Features with a vocabulary are one-hot encoded with
+/// an extra trailing slot for values outside of it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AtomFeature {
+    AtomicNum(Vec<i32>),
+    Degree(Vec<u32>),
+    FormalCharge(Vec<i32>),
+    Hybridization(Vec<HybridizationType>),
+    Aromatic,
+    TotalNumHs(Vec<u32>),
+    Chirality(Vec<ChiralType>),
+    InRing,
+}
+
+impl AtomFeature {
+    /// Number of columns the feature takes up
+    pub fn num_columns(&self) -> usize {
+        match self {
+            AtomFeature::AtomicNum(vocabulary) => vocabulary.len() + 1,
+            AtomFeature::Degree(vocabulary) => vocabulary.len() + 1,
+            AtomFeature::FormalCharge(vocabulary) => vocabulary.len() + 1,
+            AtomFeature::Hybridization(vocabulary) => vocabulary.len() + 1,
+            AtomFeature::TotalNumHs(vocabulary) => vocabulary.len() + 1,
+            AtomFeature::Chirality(vocabulary) => vocabulary.len() + 1,
+            AtomFeature::Aromatic | AtomFeature::InRing => 1,
+        }
+    }
+}
+
+/// A per-bond feature, encoded like [`AtomFeature`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum BondFeature {
+    BondType(Vec<BondType>),
+    Conjugated,
+    InRing,
+    Stereo(Vec<BondStereo>),
+}
+
+impl BondFeature {
+    /// Number of columns the feature takes up
+    pub fn num_columns(&self) -> usize {
+        match self {
+            BondFeature::BondType(vocabulary) => vocabulary.len() + 1,
+            BondFeature::Stereo(vocabulary) => vocabulary.len() + 1,
+            BondFeature::Conjugated | BondFeature::InRing => 1,
+        }
+    }
+}
+
+/// A molecule as a graph in the layout PyTorch Geometric expects: one row of
+/// features per atom, an edge index of shape `(2, num_edges)` listing every
+/// bond in both directions, and one row of features per edge.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MolGraph {
+    pub atom_features: Vec<Vec<f32>>,
+    pub edge_index: [Vec<i64>; 2],
+    pub bond_features: Vec<Vec<f32>>,
+    pub atom_feature_len: usize,
+    pub bond_feature_len: usize,
+}
+
+impl MolGraph {
+    pub fn num_atoms(&self) -> usize {
+        self.atom_features.len()
+    }
+
+    pub fn num_edges(&self) -> usize {
+        self.edge_index[0].len()
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl MolGraph {
+    /// `(num_atoms, atom_feature_len)`, PyG's `x`
+    pub fn atom_feature_array(&self) -> ndarray::Array2<f32> {
+        rows_to_array(&self.atom_features, self.atom_feature_len)
+    }
+
+    /// `(2, num_edges)`, PyG's `edge_index`
+    pub fn edge_index_array(&self) -> ndarray::Array2<i64> {
+        let mut edge_index = ndarray::Array2::zeros((2, self.num_edges()));
+        for (row, indices) in self.edge_index.iter().enumerate() {
+            for (column, idx) in indices.iter().enumerate() {
+                edge_index[[row, column]] = *idx;
+            }
+        }
+        edge_index
+    }
+
+    /// `(num_edges, bond_feature_len)`, PyG's `edge_attr`
+    pub fn bond_feature_array(&self) -> ndarray::Array2<f32> {
+        rows_to_array(&self.bond_features, self.bond_feature_len)
+    }
+}
+
+#[cfg(feature = "ndarray")]
+fn rows_to_array(rows: &[Vec<f32>], num_columns: usize) -> ndarray::Array2<f32> {
+    let mut array = ndarray::Array2::zeros((rows.len(), num_columns));
+    for (mut array_row, row) in array.rows_mut().into_iter().zip(rows) {
+        for (value, feature) in array_row.iter_mut().zip(row) {
+            *value = *feature;
+        }
+    }
+    array
+}
+
+/// Turns molecules into [`MolGraph`]s with a configurable set of atom and
+/// bond features. The defaults follow the features commonly used by message
+/// passing networks.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphFeaturizer {
+    atom_features: Vec<AtomFeature>,
+    bond_features: Vec<BondFeature>,
+}
+
+impl Default for GraphFeaturizer {
+    fn default() -> Self {
+        let atom_features = vec![
+            AtomFeature::AtomicNum(vec![1, 5, 6, 7, 8, 9, 14, 15, 16, 17, 35, 53]),
+            AtomFeature::Degree((0..=5).collect()),
+            AtomFeature::FormalCharge((-2..=2).collect()),
+            AtomFeature::Hybridization(vec![
+                HybridizationType::SP,
+                HybridizationType::SP2,
+                HybridizationType::SP3,
+                HybridizationType::SP3D,
+                HybridizationType::SP3D2,
+            ]),
+            AtomFeature::Aromatic,
+            AtomFeature::TotalNumHs((0..=4).collect()),
+            AtomFeature::Chirality(vec![
+                ChiralType::CHI_UNSPECIFIED,
+                ChiralType::CHI_TETRAHEDRAL_CW,
+                ChiralType::CHI_TETRAHEDRAL_CCW,
+            ]),
+            AtomFeature::InRing,
+        ];
+        let bond_features = vec![
+            BondFeature::BondType(vec![
+                BondType::SINGLE,
+                BondType::DOUBLE,
+                BondType::TRIPLE,
+                BondType::AROMATIC,
+            ]),
+            BondFeature::Conjugated,
+            BondFeature::InRing,
+            BondFeature::Stereo(vec![
+                BondStereo::STEREONONE,
+                BondStereo::STEREOANY,
+                BondStereo::STEREOZ,
+                BondStereo::STEREOE,
+            ]),
+        ];
+        GraphFeaturizer::new(atom_features, bond_features)
+    }
+}
+
+impl GraphFeaturizer {
+    pub fn new(atom_features: Vec<AtomFeature>, bond_features: Vec<BondFeature>) -> Self {
+        GraphFeaturizer {
+            atom_features,
+            bond_features,
+        }
+    }
+
+    pub fn atom_features(&self) -> &[AtomFeature] {
+        &self.atom_features
+    }
+
+    pub fn bond_features(&self) -> &[BondFeature] {
+        &self.bond_features
+    }
+
+    pub fn atom_feature_len(&self) -> usize {
+        self.atom_features
+            .iter()
+            .map(AtomFeature::num_columns)
+            .sum()
+    }
+
+    pub fn bond_feature_len(&self) -> usize {
+        self.bond_features
+            .iter()
+            .map(BondFeature::num_columns)
+            .sum()
+    }
+
+    /// Fails for molecules without ring information when ring membership is
+    /// one of the features, see [`crate::Atom::is_in_ring`]
+    pub fn featurize(&self, ro_mol: &ROMol) -> Result<MolGraph, cxx::Exception> {
+        let mut mol = ro_mol.clone();
+
+        let mut atom_features = vec![];
+        for idx in 0..mol.num_atoms(true) {
+            let atom = mol.atom_with_idx(idx);
+            let mut row = Vec::with_capacity(self.atom_feature_len());
+            for feature in &self.atom_features {
+                match feature {
+                    AtomFeature::AtomicNum(vocabulary) => {
+                        one_hot(&mut row, vocabulary, &atom.get_atomic_num())
+                    }
+                    AtomFeature::Degree(vocabulary) => {
+                        one_hot(&mut row, vocabulary, &atom.get_degree())
+                    }
+                    AtomFeature::FormalCharge(vocabulary) => {
+                        one_hot(&mut row, vocabulary, &atom.get_formal_charge())
+                    }
+                    AtomFeature::Hybridization(vocabulary) => {
+                        one_hot(&mut row, vocabulary, &atom.get_hybridization_type())
+                    }
+                    AtomFeature::Aromatic => row.push(flag(atom.get_is_aromatic())),
+                    AtomFeature::TotalNumHs(vocabulary) => {
+                        one_hot(&mut row, vocabulary, &atom.get_total_num_hs())
+                    }
+                    AtomFeature::Chirality(vocabulary) => {
+                        one_hot(&mut row, vocabulary, &atom.get_chiral_tag())
+                    }
+                    AtomFeature::InRing => row.push(flag(atom.is_in_ring()?)),
+                }
+            }
+            atom_features.push(row);
+        }
+
+        let mut edge_index = [vec![], vec![]];
+        let mut bond_features = vec![];
+        for idx in 0..mol.num_bonds() {
+            let bond = mol.bond_with_idx(idx);
+            let mut row = Vec::with_capacity(self.bond_feature_len());
+            for feature in &self.bond_features {
+                match feature {
+                    BondFeature::BondType(vocabulary) => {
+                        one_hot(&mut row, vocabulary, &bond.get_bond_type())
+                    }
+                    BondFeature::Conjugated => row.push(flag(bond.get_is_conjugated())),
+                    BondFeature::InRing => row.push(flag(bond.is_in_ring()?)),
+                    BondFeature::Stereo(vocabulary) => {
+                        one_hot(&mut row, vocabulary, &bond.get_stereo())
+                    }
+                }
+            }
+
+            let begin = bond.get_begin_atom_idx() as i64;
+            let end = bond.get_end_atom_idx() as i64;
+            edge_index[0].extend([begin, end]);
+            edge_index[1].extend([end, begin]);
+            bond_features.push(row.clone());
+            bond_features.push(row);
+        }
+
+        Ok(MolGraph {
+            atom_features,
+            edge_index,
+            bond_features,
+            atom_feature_len: self.atom_feature_len(),
+            bond_feature_len: self.bond_feature_len(),
+        })
+    }
+}
+
+fn one_hot<T: PartialEq>(row: &mut Vec<f32>, vocabulary: &[T], value: &T) {
+    let position = vocabulary
+        .iter()
+        .position(|item| item == value)
+        .unwrap_or(vocabulary.len());
+    row.extend((0..=vocabulary.len()).map(|idx| flag(idx == position)));
+}
+
+fn flag(value: bool) -> f32 {
+    if value {
+        1.0
+    } else {
+        0.0
+    }
+}
@@ -5,7 +5,7 @@ use rdkit_sys::ro_mol_ffi;
 pub struct Atom<'a> {
     ptr: Pin<&'a mut ro_mol_ffi::Atom>,
 }
-pub use rdkit_sys::ro_mol_ffi::HybridizationType;
+pub use rdkit_sys::ro_mol_ffi::{ChiralType, HybridizationType};
 
 impl<'a> std::fmt::Display for Atom<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -115,6 +115,37 @@ impl<'a> Atom<'a> {
     pub fn get_degree(&self) -> u32 {
         ro_mol_ffi::get_degree(self.ptr.as_ref())
     }
+
+    pub fn get_chiral_tag(&self) -> ChiralType {
+        ro_mol_ffi::atom_get_chiral_tag(self.ptr.as_ref())
+    }
+
+    /// Fails if ring information hasn't been computed for the molecule, which
+    /// sanitization does.
+    pub fn is_in_ring(&self) -> Result<bool, cxx::Exception> {
+        ro_mol_ffi::atom_is_in_ring(self.ptr.as_ref())
+    }
+
+    /// The mass number of the atom, 0 for natural abundance
+    pub fn get_isotope(&self) -> u32 {
+        ro_mol_ffi::atom_get_isotope(self.ptr.as_ref())
+    }
+
+    /// True for atoms parsed from SMARTS or built from an [`crate::AtomQuery`]
+    pub fn has_query(&self) -> bool {
+        ro_mol_ffi::atom_has_query(self.ptr.as_ref())
+    }
+
+    /// The atom's SMARTS, or its SMILES if it has no query
+    pub fn as_smarts(&self) -> String {
+        ro_mol_ffi::atom_get_smarts(self.ptr.as_ref())
+    }
+
+    /// RDKit's description of the query tree, one node per line, empty if
+    /// the atom has no query
+    pub fn describe_query(&self) -> String {
+        ro_mol_ffi::atom_describe_query(self.ptr.as_ref())
+    }
 }
 
 pub trait SetPropValue {
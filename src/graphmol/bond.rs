@@ -0,0 +1,73 @@
+use std::pin::Pin;
+
+use rdkit_sys::ro_mol_ffi;
+pub use rdkit_sys::ro_mol_ffi::{BondStereo, BondType};
+
+pub struct Bond<'a> {
+    ptr: Pin<&'a mut ro_mol_ffi::Bond>,
+}
+
+impl<'a> std::fmt::Debug for Bond<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Bond")
+            .field("begin_atom_idx", &self.get_begin_atom_idx())
+            .field("end_atom_idx", &self.get_end_atom_idx())
+            .field("bond_type", &self.get_bond_type_as_double())
+            .finish()
+    }
+}
+
+impl<'a> Bond<'a> {
+    pub fn from_ptr(ptr: Pin<&'a mut ro_mol_ffi::Bond>) -> Self {
+        Self { ptr }
+    }
+
+    pub fn get_begin_atom_idx(&self) -> u32 {
+        ro_mol_ffi::get_begin_atom_idx(self.ptr.as_ref())
+    }
+
+    pub fn get_end_atom_idx(&self) -> u32 {
+        ro_mol_ffi::get_end_atom_idx(self.ptr.as_ref())
+    }
+
+    /// Returns the bond order, 1.5 for aromatic bonds
+    pub fn get_bond_type_as_double(&self) -> f64 {
+        ro_mol_ffi::get_bond_type_as_double(self.ptr.as_ref())
+    }
+
+    pub fn get_is_aromatic(&self) -> bool {
+        ro_mol_ffi::get_bond_is_aromatic(self.ptr.as_ref())
+    }
+
+    pub fn get_bond_type(&self) -> BondType {
+        ro_mol_ffi::get_bond_type(self.ptr.as_ref())
+    }
+
+    pub fn get_is_conjugated(&self) -> bool {
+        ro_mol_ffi::get_bond_is_conjugated(self.ptr.as_ref())
+    }
+
+    pub fn get_stereo(&self) -> BondStereo {
+        ro_mol_ffi::get_bond_stereo(self.ptr.as_ref())
+    }
+
+    /// Fails if ring information hasn't been computed for the molecule, which
+    /// sanitization does.
+    pub fn is_in_ring(&self) -> Result<bool, cxx::Exception> {
+        ro_mol_ffi::bond_is_in_ring(self.ptr.as_ref())
+    }
+
+    pub fn has_query(&self) -> bool {
+        ro_mol_ffi::bond_has_query(self.ptr.as_ref())
+    }
+
+    /// The bond's SMARTS, or its SMILES if it has no query
+    pub fn as_smarts(&self) -> String {
+        ro_mol_ffi::bond_get_smarts(self.ptr.as_ref())
+    }
+
+    /// RDKit's description of the query tree, empty if the bond has no query
+    pub fn describe_query(&self) -> String {
+        ro_mol_ffi::bond_describe_query(self.ptr.as_ref())
+    }
+}
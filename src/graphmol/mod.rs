@@ -1,6 +1,9 @@
 mod atom;
 pub use atom::*;
 
+mod bond;
+pub use bond::*;
+
 mod mol_ops;
 pub use mol_ops::*;
 
@@ -3,7 +3,7 @@ use std::fmt::{Debug, Formatter};
 use cxx::let_cxx_string;
 use rdkit_sys::*;
 
-use crate::{Atom, Fingerprint, RWMol, MoleculeProperties, substruct_match, SubstructMatchParameters, SubstructMatchItem};
+use crate::{Atom, Bond, CrippenContribs, Descriptors3D, Fingerprint, FormulaError, IsotopePeak, LabuteAsaContribs, MolecularFormula, RWMol, MoleculeProperties, PropertiesError, SetPropValue, substruct_match, substruct_match_first, count_substruct_matches, has_substruct_match, SubstructMatchParameters, SubstructMatchItem};
 
 pub struct ROMol {
     pub(crate) ptr: cxx::SharedPtr<ro_mol_ffi::ROMol>,
@@ -61,6 +61,11 @@ impl ROMol {
         ro_mol_ffi::mol_to_smiles(&self.ptr)
     }
 
+    /// Writes the molecule, or query molecule, as isomeric SMARTS
+    pub fn as_smarts(&self) -> String {
+        ro_mol_ffi::mol_to_smarts(&self.ptr, true)
+    }
+
     pub fn to_molblock(&self) -> String {
         ro_mol_ffi::mol_to_molblock(&self.ptr)
     }
@@ -89,19 +94,123 @@ impl ROMol {
         ro_mol_ffi::get_num_atoms(&self.ptr, only_explicit)
     }
 
+    pub fn num_conformers(&self) -> u32 {
+        ro_mol_ffi::get_num_conformers(&self.ptr)
+    }
+
     pub fn atom_with_idx(&mut self, idx: u32) -> Atom {
         let ptr = ro_mol_ffi::get_atom_with_idx(&mut self.ptr, idx);
         Atom::from_ptr(ptr)
     }
 
+    /// Read-only access to an atom through a shared reference, without
+    /// copying the molecule
+    pub fn with_atom<R>(&self, idx: u32, f: impl FnOnce(&Atom) -> R) -> R {
+        let mut ptr = self.ptr.clone();
+        f(&Atom::from_ptr(ro_mol_ffi::get_atom_with_idx(&mut ptr, idx)))
+    }
+
+    pub fn num_bonds(&self) -> u32 {
+        ro_mol_ffi::get_num_bonds(&self.ptr)
+    }
+
+    pub fn bond_with_idx(&mut self, idx: u32) -> Bond<'_> {
+        let ptr = ro_mol_ffi::get_bond_with_idx(&mut self.ptr, idx);
+        Bond::from_ptr(ptr)
+    }
+
+    /// Returns the topological distance matrix in row-major order. With
+    /// `use_bond_order` each bond counts as the inverse of its bond order.
+    /// Atoms in disconnected fragments are 1e8 apart.
+    pub fn distance_matrix(&self, use_bond_order: bool) -> Vec<f64> {
+        mol_ops_ffi::get_distance_matrix(&self.ptr, use_bond_order)
+            .as_slice()
+            .to_vec()
+    }
+
     pub fn update_property_cache(&mut self, strict: bool) {
         ro_mol_ffi::ro_mol_update_property_cache(&mut self.ptr, strict)
     }
 
-    pub fn properties(&self) -> MoleculeProperties {
+    /// Sets `key` on every atom, `values` holding one value per atom in index
+    /// order, e.g. to store per-atom descriptor contributions.
+    ///
+    /// Panics if `values` doesn't hold exactly one value per atom.
+    pub fn set_atom_props<T>(&mut self, key: &str, values: &[T])
+    where
+        T: SetPropValue + Copy,
+    {
+        assert_eq!(values.len(), self.num_atoms(true) as usize);
+
+        for (idx, value) in values.iter().enumerate() {
+            self.atom_with_idx(idx as u32).set_prop(key, *value);
+        }
+    }
+
+    pub fn properties(&self) -> Result<MoleculeProperties, PropertiesError> {
         MoleculeProperties::from_molecule(self)
     }
 
+    /// Mass weighted shape descriptors of the conformer `conf_id`, see
+    /// [`Descriptors3D::from_molecule`]
+    pub fn descriptors_3d(&self, conf_id: i32) -> Result<Descriptors3D, cxx::Exception> {
+        Descriptors3D::from_molecule(self, conf_id, true)
+    }
+
+    pub fn crippen_contribs(&self) -> CrippenContribs {
+        CrippenContribs::from_molecule(self)
+    }
+
+    pub fn labute_asa_contribs(&self, include_hs: bool) -> LabuteAsaContribs {
+        LabuteAsaContribs::from_molecule(self, include_hs)
+    }
+
+    /// Returns the Hill-ordered molecular formula
+    pub fn formula(&self) -> String {
+        self.formula_with_options(false, true)
+    }
+
+    /// Returns the molecular formula. With `separate_isotopes` labelled atoms
+    /// are written separately (`C[13C]H6O`), and `abbreviate_h_isotopes`
+    /// then writes deuterium and tritium as `D` and `T`.
+    pub fn formula_with_options(
+        &self,
+        separate_isotopes: bool,
+        abbreviate_h_isotopes: bool,
+    ) -> String {
+        descriptors_ffi::calc_mol_formula(&self.ptr, separate_isotopes, abbreviate_h_isotopes)
+    }
+
+    /// Parses the formula of the molecule, keeping isotope labels
+    pub fn molecular_formula(&self) -> Result<MolecularFormula, FormulaError> {
+        MolecularFormula::parse(&self.formula_with_options(true, true))
+    }
+
+    /// Returns the sum of the formal charges of all atoms
+    pub fn formal_charge(&self) -> i32 {
+        mol_ops_ffi::mol_get_formal_charge(&self.ptr)
+    }
+
+    /// Returns the exact mass using the most common isotope of each unlabelled
+    /// atom
+    pub fn monoisotopic_mass(&self) -> f64 {
+        descriptors_ffi::calc_exact_mw(&self.ptr, false)
+    }
+
+    pub fn nominal_mass(&self) -> Result<u32, FormulaError> {
+        Ok(self.molecular_formula()?.nominal_mass())
+    }
+
+    /// See [`MolecularFormula::isotope_pattern`]
+    pub fn isotope_pattern(
+        &self,
+        min_relative_abundance: f64,
+    ) -> Result<Vec<IsotopePeak>, FormulaError> {
+        Ok(self
+            .molecular_formula()?
+            .isotope_pattern(min_relative_abundance))
+    }
+
     pub fn from_smarts(smarts: &str) -> Option<ROMol> {
         RWMol::from_smarts(smarts).ok().map(|mol| mol.to_ro_mol())
     }
@@ -113,6 +222,18 @@ impl ROMol {
     pub fn substruct_match(&self, query: &ROMol, params: &SubstructMatchParameters) -> Vec<Vec<SubstructMatchItem>> {
         substruct_match(self, query, params)
     }
+
+    pub fn has_substruct_match(&self, query: &ROMol, params: &SubstructMatchParameters) -> bool {
+        has_substruct_match(self, query, params)
+    }
+
+    pub fn count_substruct_matches(&self, query: &ROMol, params: &SubstructMatchParameters) -> u32 {
+        count_substruct_matches(self, query, params)
+    }
+
+    pub fn substruct_match_first(&self, query: &ROMol, params: &SubstructMatchParameters) -> Option<Vec<(u32, u32)>> {
+        substruct_match_first(self, query, params)
+    }
 }
 
 impl Debug for ROMol {
@@ -122,6 +243,22 @@ impl Debug for ROMol {
     }
 }
 
+/// Shares the molecules with a C++ list, for RDKit functions that take
+/// several molecules
+pub(crate) fn ro_mol_list<'a>(mols: impl IntoIterator<Item = &'a ROMol>) -> cxx::UniquePtr<ro_mol_ffi::ROMolList> {
+    let mut list = ro_mol_ffi::new_ro_mol_list();
+    for mol in mols {
+        ro_mol_ffi::ro_mol_list_push(list.pin_mut(), &mol.ptr);
+    }
+    list
+}
+
+pub(crate) fn ro_mols_from_list(list: &cxx::UniquePtr<ro_mol_ffi::ROMolList>) -> Vec<ROMol> {
+    (0..ro_mol_ffi::ro_mol_list_len(list))
+        .map(|idx| ROMol { ptr: ro_mol_ffi::ro_mol_list_get(list, idx) })
+        .collect()
+}
+
 impl Clone for ROMol {
     fn clone(&self) -> Self {
         ROMol {
@@ -1,21 +1,68 @@
+mod chem_transforms;
+pub use chem_transforms::*;
+
+mod descriptor_blocks;
+pub use descriptor_blocks::*;
+
 mod descriptors;
 pub use descriptors::*;
 
+mod descriptors_3d;
+pub use descriptors_3d::*;
+
+mod drug_likeness;
+pub use drug_likeness::*;
+
+#[cfg(feature = "ndarray")]
+mod featurize;
+#[cfg(feature = "ndarray")]
+pub use featurize::*;
+
 mod file_parsers;
 pub use file_parsers::*;
 
 mod fingerprint;
 pub use fingerprint::*;
 
+mod formula;
+pub use formula::*;
+
+mod graph_featurizer;
+pub use graph_featurizer::*;
+
 mod graphmol;
 pub use graphmol::*;
 
+mod mcs;
+pub use mcs::*;
+
 mod mol_standardize;
 pub use mol_standardize::*;
 
+mod mol_validation;
+pub use mol_validation::*;
+
 mod periodic_table;
 pub use periodic_table::*;
 
+mod query;
+pub use query::*;
+
+mod resonance;
+pub use resonance::*;
+
+mod rgroup_decomposition;
+pub use rgroup_decomposition::*;
+
+mod scaffold;
+pub use scaffold::*;
+
+mod scaffold_network;
+pub use scaffold_network::*;
+
+mod substruct_library;
+pub use substruct_library::*;
+
 mod substruct_match;
 pub use substruct_match::*;
 
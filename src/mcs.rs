@@ -0,0 +1,122 @@
+use cxx::SharedPtr;
+use rdkit_sys::fmcs_ffi;
+pub use rdkit_sys::fmcs_ffi::{AtomComparator, BondComparator};
+
+use crate::{ro_mol_list, ROMol};
+
+/// Options for [`find_mcs`]. By default atoms are compared by element and
+/// bonds by order, and the search runs for at most an hour.
+pub struct McsParameters {
+    pub(crate) ptr: SharedPtr<fmcs_ffi::MCSParameters>,
+}
+
+impl Default for McsParameters {
+    fn default() -> Self {
+        McsParameters::new()
+    }
+}
+
+impl McsParameters {
+    pub fn new() -> Self {
+        McsParameters {
+            ptr: fmcs_ffi::new_mcs_parameters(),
+        }
+    }
+
+    pub fn get_ring_matches_ring_only(&self) -> bool {
+        fmcs_ffi::mcs_parameters_get_ring_matches_ring_only(&self.ptr)
+    }
+
+    pub fn get_complete_rings_only(&self) -> bool {
+        fmcs_ffi::mcs_parameters_get_complete_rings_only(&self.ptr)
+    }
+
+    pub fn get_match_valences(&self) -> bool {
+        fmcs_ffi::mcs_parameters_get_match_valences(&self.ptr)
+    }
+
+    pub fn get_match_chiral_tag(&self) -> bool {
+        fmcs_ffi::mcs_parameters_get_match_chiral_tag(&self.ptr)
+    }
+
+    /// In seconds
+    pub fn get_timeout(&self) -> u32 {
+        fmcs_ffi::mcs_parameters_get_timeout(&self.ptr)
+    }
+
+    pub fn get_threshold(&self) -> f64 {
+        fmcs_ffi::mcs_parameters_get_threshold(&self.ptr)
+    }
+
+    pub fn atom_comparator(mut self, comparator: AtomComparator) -> McsParameters {
+        fmcs_ffi::mcs_parameters_set_atom_comparator(&mut self.ptr, comparator);
+        self
+    }
+
+    pub fn bond_comparator(mut self, comparator: BondComparator) -> McsParameters {
+        fmcs_ffi::mcs_parameters_set_bond_comparator(&mut self.ptr, comparator);
+        self
+    }
+
+    /// Ring atoms and bonds only match other ring atoms and bonds
+    pub fn ring_matches_ring_only(mut self, what: bool) -> McsParameters {
+        fmcs_ffi::mcs_parameters_set_ring_matches_ring_only(&mut self.ptr, what);
+        self
+    }
+
+    /// Leave out partially matched rings
+    pub fn complete_rings_only(mut self, what: bool) -> McsParameters {
+        fmcs_ffi::mcs_parameters_set_complete_rings_only(&mut self.ptr, what);
+        self
+    }
+
+    pub fn match_valences(mut self, what: bool) -> McsParameters {
+        fmcs_ffi::mcs_parameters_set_match_valences(&mut self.ptr, what);
+        self
+    }
+
+    pub fn match_chiral_tag(mut self, what: bool) -> McsParameters {
+        fmcs_ffi::mcs_parameters_set_match_chiral_tag(&mut self.ptr, what);
+        self
+    }
+
+    /// Stop searching after this many seconds and return the best
+    /// substructure found so far
+    pub fn timeout(mut self, seconds: u32) -> McsParameters {
+        fmcs_ffi::mcs_parameters_set_timeout(&mut self.ptr, seconds);
+        self
+    }
+
+    /// Fraction of the molecules the substructure has to be found in, 1.0
+    /// requires all of them
+    pub fn threshold(mut self, threshold: f64) -> McsParameters {
+        fmcs_ffi::mcs_parameters_set_threshold(&mut self.ptr, threshold);
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct McsResult {
+    pub smarts: String,
+    pub num_atoms: u32,
+    pub num_bonds: u32,
+    /// The timeout was hit, the substructure may not be the largest one
+    pub timed_out: bool,
+    /// The substructure as a query molecule, `None` if nothing is shared
+    pub query_mol: Option<ROMol>,
+}
+
+/// Finds the largest substructure shared by the molecules
+pub fn find_mcs(mols: &[ROMol], params: &McsParameters) -> Result<McsResult, cxx::Exception> {
+    let list = ro_mol_list(mols);
+    let result = fmcs_ffi::find_mcs(&list, &params.ptr)?;
+    let query_mol = fmcs_ffi::mcs_result_query_mol(&result);
+
+    Ok(McsResult {
+        smarts: fmcs_ffi::mcs_result_smarts(&result),
+        num_atoms: fmcs_ffi::mcs_result_num_atoms(&result),
+        num_bonds: fmcs_ffi::mcs_result_num_bonds(&result),
+        timed_out: fmcs_ffi::mcs_result_canceled(&result),
+        query_mol: (!query_mol.is_null()).then_some(ROMol { ptr: query_mol }),
+    })
+}
@@ -1,7 +1,25 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
 use cxx::SharedPtr;
+pub use rdkit_sys::mol_standardize_ffi::TautomerEnumeratorStatus;
+use rdkit_sys::TautomerCallback;
 
 use crate::{ROMol, RWMol};
 
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum CleanupParametersError {
+    #[error("line {line} should have {expected_columns} tab separated columns: `{content}`")]
+    InvalidLine {
+        line: usize,
+        expected_columns: usize,
+        content: String,
+    },
+}
+
 pub struct CleanupParameters {
     pub(crate) ptr: SharedPtr<rdkit_sys::mol_standardize_ffi::CleanupParameters>,
 }
@@ -14,8 +32,288 @@ impl Default for CleanupParameters {
     }
 }
 
+impl CleanupParameters {
+    /// RDKit data directory the default data files are read from
+    pub fn get_rdbase(&self) -> String {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_rdbase(&self.ptr)
+    }
+
+    pub fn set_rdbase(&mut self, what: &str) {
+        cxx::let_cxx_string!(what = what);
+        rdkit_sys::mol_standardize_ffi::set_cleanup_rdbase(&mut self.ptr, &what)
+    }
+
+    /// Path of a normalization transforms file, empty for the built in transforms
+    pub fn get_normalizations(&self) -> String {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_normalizations(&self.ptr)
+    }
+
+    pub fn set_normalizations(&mut self, what: &str) {
+        cxx::let_cxx_string!(what = what);
+        rdkit_sys::mol_standardize_ffi::set_cleanup_normalizations(&mut self.ptr, &what)
+    }
+
+    /// Path of an acid/base pairs file, empty for the built in pairs
+    pub fn get_acidbase_file(&self) -> String {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_acidbase_file(&self.ptr)
+    }
+
+    pub fn set_acidbase_file(&mut self, what: &str) {
+        cxx::let_cxx_string!(what = what);
+        rdkit_sys::mol_standardize_ffi::set_cleanup_acidbase_file(&mut self.ptr, &what)
+    }
+
+    /// Path of a fragments file, empty for the built in fragments
+    pub fn get_fragment_file(&self) -> String {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_fragment_file(&self.ptr)
+    }
+
+    pub fn set_fragment_file(&mut self, what: &str) {
+        cxx::let_cxx_string!(what = what);
+        rdkit_sys::mol_standardize_ffi::set_cleanup_fragment_file(&mut self.ptr, &what)
+    }
+
+    /// Path of a tautomer transforms file, empty for the built in transforms
+    pub fn get_tautomer_transforms(&self) -> String {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_tautomer_transforms(&self.ptr)
+    }
+
+    pub fn set_tautomer_transforms(&mut self, what: &str) {
+        cxx::let_cxx_string!(what = what);
+        rdkit_sys::mol_standardize_ffi::set_cleanup_tautomer_transforms(&mut self.ptr, &what)
+    }
+
+    pub fn get_max_restarts(&self) -> i32 {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_max_restarts(&self.ptr)
+    }
+
+    pub fn set_max_restarts(&mut self, what: i32) {
+        rdkit_sys::mol_standardize_ffi::set_cleanup_max_restarts(&mut self.ptr, what)
+    }
+
+    pub fn get_max_tautomers(&self) -> i32 {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_max_tautomers(&self.ptr)
+    }
+
+    pub fn set_max_tautomers(&mut self, what: i32) {
+        rdkit_sys::mol_standardize_ffi::set_cleanup_max_tautomers(&mut self.ptr, what)
+    }
+
+    pub fn get_max_transforms(&self) -> i32 {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_max_transforms(&self.ptr)
+    }
+
+    pub fn set_max_transforms(&mut self, what: i32) {
+        rdkit_sys::mol_standardize_ffi::set_cleanup_max_transforms(&mut self.ptr, what)
+    }
+
+    pub fn get_prefer_organic(&self) -> bool {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_prefer_organic(&self.ptr)
+    }
+
+    pub fn set_prefer_organic(&mut self, what: bool) {
+        rdkit_sys::mol_standardize_ffi::set_cleanup_prefer_organic(&mut self.ptr, what)
+    }
+
+    pub fn get_do_canonical(&self) -> bool {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_do_canonical(&self.ptr)
+    }
+
+    pub fn set_do_canonical(&mut self, what: bool) {
+        rdkit_sys::mol_standardize_ffi::set_cleanup_do_canonical(&mut self.ptr, what)
+    }
+
+    pub fn get_tautomer_remove_sp3_stereo(&self) -> bool {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_tautomer_remove_sp3_stereo(&self.ptr)
+    }
+
+    pub fn set_tautomer_remove_sp3_stereo(&mut self, what: bool) {
+        rdkit_sys::mol_standardize_ffi::set_cleanup_tautomer_remove_sp3_stereo(&mut self.ptr, what)
+    }
+
+    pub fn get_tautomer_remove_bond_stereo(&self) -> bool {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_tautomer_remove_bond_stereo(&self.ptr)
+    }
+
+    pub fn set_tautomer_remove_bond_stereo(&mut self, what: bool) {
+        rdkit_sys::mol_standardize_ffi::set_cleanup_tautomer_remove_bond_stereo(&mut self.ptr, what)
+    }
+
+    pub fn get_tautomer_remove_isotopic_hs(&self) -> bool {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_tautomer_remove_isotopic_hs(&self.ptr)
+    }
+
+    pub fn set_tautomer_remove_isotopic_hs(&mut self, what: bool) {
+        rdkit_sys::mol_standardize_ffi::set_cleanup_tautomer_remove_isotopic_hs(&mut self.ptr, what)
+    }
+
+    pub fn get_tautomer_reassign_stereo(&self) -> bool {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_tautomer_reassign_stereo(&self.ptr)
+    }
+
+    pub fn set_tautomer_reassign_stereo(&mut self, what: bool) {
+        rdkit_sys::mol_standardize_ffi::set_cleanup_tautomer_reassign_stereo(&mut self.ptr, what)
+    }
+
+    pub fn get_largest_fragment_chooser_use_atom_count(&self) -> bool {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_largest_fragment_chooser_use_atom_count(
+            &self.ptr,
+        )
+    }
+
+    pub fn set_largest_fragment_chooser_use_atom_count(&mut self, what: bool) {
+        rdkit_sys::mol_standardize_ffi::set_cleanup_largest_fragment_chooser_use_atom_count(
+            &mut self.ptr,
+            what,
+        )
+    }
+
+    pub fn get_largest_fragment_chooser_count_heavy_atoms_only(&self) -> bool {
+        rdkit_sys::mol_standardize_ffi::get_cleanup_largest_fragment_chooser_count_heavy_atoms_only(
+            &self.ptr,
+        )
+    }
+
+    pub fn set_largest_fragment_chooser_count_heavy_atoms_only(&mut self, what: bool) {
+        rdkit_sys::mol_standardize_ffi::set_cleanup_largest_fragment_chooser_count_heavy_atoms_only(
+            &mut self.ptr,
+            what,
+        )
+    }
+
+    /// The `(name, SMIRKS)` normalization transforms, which take precedence
+    /// over [`CleanupParameters::get_normalizations`] when not empty
+    pub fn normalization_data(&self) -> Vec<(String, String)> {
+        let names = rdkit_sys::mol_standardize_ffi::get_cleanup_normalization_data_names(&self.ptr);
+        let smirks =
+            rdkit_sys::mol_standardize_ffi::get_cleanup_normalization_data_smirks(&self.ptr);
+        names
+            .iter()
+            .zip(smirks.iter())
+            .map(|(name, smirks)| {
+                (
+                    name.to_string_lossy().into_owned(),
+                    smirks.to_string_lossy().into_owned(),
+                )
+            })
+            .collect()
+    }
+
+    pub fn set_normalization_data(&mut self, transforms: &[(&str, &str)]) {
+        let names = transforms
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect();
+        let smirks = transforms
+            .iter()
+            .map(|(_, smirks)| smirks.to_string())
+            .collect();
+        rdkit_sys::mol_standardize_ffi::set_cleanup_normalization_data(
+            &mut self.ptr,
+            &names,
+            &smirks,
+        )
+    }
+
+    /// Reads normalization transforms in the format of RDKit's
+    /// `normalizations.txt`: one tab separated `name` and `SMIRKS` per line,
+    /// lines starting with `//` are comments. The SMIRKS are only checked
+    /// once the parameters are used.
+    pub fn set_normalizations_from_str(
+        &mut self,
+        data: &str,
+    ) -> Result<(), CleanupParametersError> {
+        let transforms = parse_data_lines(data, 2)?;
+        let transforms = transforms
+            .iter()
+            .map(|columns| (columns[0], columns[1]))
+            .collect::<Vec<_>>();
+        self.set_normalization_data(&transforms);
+        Ok(())
+    }
+
+    /// The `(name, acid SMARTS, base SMARTS)` pairs, which take precedence
+    /// over [`CleanupParameters::get_acidbase_file`] when not empty
+    pub fn acid_base_data(&self) -> Vec<(String, String, String)> {
+        let column = |idx| {
+            rdkit_sys::mol_standardize_ffi::get_cleanup_acidbase_data_column(&self.ptr, idx)
+                .iter()
+                .map(|value| value.to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+        };
+        let (names, acids, bases) = (column(0), column(1), column(2));
+        names
+            .into_iter()
+            .zip(acids)
+            .zip(bases)
+            .map(|((name, acid), base)| (name, acid, base))
+            .collect()
+    }
+
+    pub fn set_acid_base_data(&mut self, pairs: &[(&str, &str, &str)]) {
+        let names = pairs.iter().map(|(name, _, _)| name.to_string()).collect();
+        let acids = pairs.iter().map(|(_, acid, _)| acid.to_string()).collect();
+        let bases = pairs.iter().map(|(_, _, base)| base.to_string()).collect();
+        rdkit_sys::mol_standardize_ffi::set_cleanup_acidbase_data(
+            &mut self.ptr,
+            &names,
+            &acids,
+            &bases,
+        )
+    }
+
+    /// Reads acid/base pairs in the format of RDKit's `acid_base_pairs.txt`:
+    /// one tab separated `name`, acid and base SMARTS per line, strongest
+    /// acid first
+    pub fn set_acid_base_pairs_from_str(
+        &mut self,
+        data: &str,
+    ) -> Result<(), CleanupParametersError> {
+        let pairs = parse_data_lines(data, 3)?;
+        let pairs = pairs
+            .iter()
+            .map(|columns| (columns[0], columns[1], columns[2]))
+            .collect::<Vec<_>>();
+        self.set_acid_base_data(&pairs);
+        Ok(())
+    }
+}
+
+/// Splits the non-comment lines of an RDKit standardization data file into
+/// their tab separated columns
+fn parse_data_lines(
+    data: &str,
+    num_columns: usize,
+) -> Result<Vec<Vec<&str>>, CleanupParametersError> {
+    data.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with("//"))
+        .map(|(idx, line)| {
+            let columns = line
+                .split('\t')
+                .map(str::trim)
+                .filter(|column| !column.is_empty())
+                .collect::<Vec<_>>();
+            if columns.len() == num_columns {
+                Ok(columns)
+            } else {
+                Err(CleanupParametersError::InvalidLine {
+                    line: idx + 1,
+                    expected_columns: num_columns,
+                    content: line.to_string(),
+                })
+            }
+        })
+        .collect()
+}
+
+type TautomerCallbackFn = Arc<Mutex<Box<dyn FnMut(u32) -> bool + Send>>>;
+
 pub struct TautomerEnumerator {
     pub(crate) ptr: SharedPtr<rdkit_sys::mol_standardize_ffi::TautomerEnumerator>,
+    callback: Option<TautomerCallbackFn>,
+    timeout: Option<Duration>,
+    deadline: Arc<Mutex<Option<Instant>>>,
 }
 
 impl Default for TautomerEnumerator {
@@ -28,10 +326,144 @@ impl TautomerEnumerator {
     pub fn new() -> Self {
         let ptr = rdkit_sys::mol_standardize_ffi::tautomer_enumerator();
 
-        TautomerEnumerator { ptr }
+        TautomerEnumerator::from_ptr(ptr)
+    }
+
+    /// Takes the tautomer transforms file and the `max_tautomers`,
+    /// `max_transforms` and stereo options from `cleanup_params`. Fails if the
+    /// transforms file can't be read.
+    pub fn from_cleanup_parameters(
+        cleanup_params: &CleanupParameters,
+    ) -> Result<Self, cxx::Exception> {
+        let ptr = rdkit_sys::mol_standardize_ffi::new_tautomer_enumerator_from_params(
+            &cleanup_params.ptr,
+        )?;
+        Ok(TautomerEnumerator::from_ptr(ptr))
+    }
+
+    fn from_ptr(ptr: SharedPtr<rdkit_sys::mol_standardize_ffi::TautomerEnumerator>) -> Self {
+        TautomerEnumerator {
+            ptr,
+            callback: None,
+            timeout: None,
+            deadline: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn get_max_tautomers(&self) -> u32 {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_get_max_tautomers(&self.ptr)
+    }
+
+    pub fn set_max_tautomers(&mut self, what: u32) {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_set_max_tautomers(&mut self.ptr, what)
+    }
+
+    pub fn get_max_transforms(&self) -> u32 {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_get_max_transforms(&self.ptr)
+    }
+
+    pub fn set_max_transforms(&mut self, what: u32) {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_set_max_transforms(&mut self.ptr, what)
+    }
+
+    pub fn get_remove_sp3_stereo(&self) -> bool {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_get_remove_sp3_stereo(&self.ptr)
+    }
+
+    pub fn set_remove_sp3_stereo(&mut self, what: bool) {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_set_remove_sp3_stereo(
+            &mut self.ptr,
+            what,
+        )
+    }
+
+    pub fn get_remove_bond_stereo(&self) -> bool {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_get_remove_bond_stereo(&self.ptr)
+    }
+
+    pub fn set_remove_bond_stereo(&mut self, what: bool) {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_set_remove_bond_stereo(
+            &mut self.ptr,
+            what,
+        )
+    }
+
+    pub fn get_remove_isotopic_hs(&self) -> bool {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_get_remove_isotopic_hs(&self.ptr)
+    }
+
+    pub fn set_remove_isotopic_hs(&mut self, what: bool) {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_set_remove_isotopic_hs(
+            &mut self.ptr,
+            what,
+        )
+    }
+
+    pub fn get_reassign_stereo(&self) -> bool {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_get_reassign_stereo(&self.ptr)
+    }
+
+    pub fn set_reassign_stereo(&mut self, what: bool) {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_set_reassign_stereo(&mut self.ptr, what)
+    }
+
+    /// `callback` is called after each enumeration step with the number of
+    /// tautomers found so far. Returning `false` cancels the enumeration, the
+    /// result's status is then [`TautomerEnumeratorStatus::Canceled`]. A
+    /// panicking callback cancels the enumeration too.
+    ///
+    /// The callback is stored on the underlying RDKit enumerator, so it
+    /// replaces the callback seen by every handle sharing that enumerator.
+    pub fn set_callback(&mut self, callback: impl FnMut(u32) -> bool + Send + 'static) {
+        self.callback = Some(Arc::new(Mutex::new(Box::new(callback))));
+        self.install_callback();
+    }
+
+    /// Cancels enumerations, and the enumeration done by
+    /// [`TautomerEnumerator::canonicalize`], that run longer than `timeout`
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+        self.install_callback();
+    }
+
+    /// The score canonicalization picks the highest scoring tautomer by
+    pub fn score_tautomer(ro_mol: &ROMol) -> i32 {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_score_tautomer(&ro_mol.ptr)
+    }
+
+    fn install_callback(&mut self) {
+        let deadline = self.deadline.clone();
+        let callback = self.callback.clone();
+        let callback = move |num_tautomers| {
+            let deadline = *deadline.lock().unwrap_or_else(PoisonError::into_inner);
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return false;
+            }
+            match &callback {
+                // a callback that panicked before is not called again
+                Some(callback) => match callback.lock() {
+                    Ok(mut callback) => callback(num_tautomers),
+                    Err(_) => false,
+                },
+                None => true,
+            }
+        };
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_set_callback(
+            &mut self.ptr,
+            Box::new(TautomerCallback(Box::new(callback))),
+        );
+    }
+
+    /// The timeout is measured from the start of each enumeration
+    fn start_timeout(&self) {
+        if let Some(timeout) = self.timeout {
+            *self.deadline.lock().unwrap_or_else(PoisonError::into_inner) =
+                Some(Instant::now() + timeout);
+        }
     }
 
     pub fn enumerate(&self, ro_mol: &crate::ROMol) -> TautomerEnumeratorResult {
+        self.start_timeout();
         let t_enumerator_result =
             rdkit_sys::mol_standardize_ffi::tautomer_enumerate(&self.ptr, &ro_mol.ptr);
         let size = rdkit_sys::mol_standardize_ffi::tautomer_enumerator_result_tautomers_size(
@@ -46,6 +478,7 @@ impl TautomerEnumerator {
     }
 
     pub fn canonicalize(&self, ro_mol: &crate::ROMol) -> Result<crate::ROMol, cxx::Exception> {
+        self.start_timeout();
         let canonical_mol_ptr = rdkit_sys::mol_standardize_ffi::tautomer_enumerator_canonicalize(
             &self.ptr,
             &ro_mol.ptr,
@@ -64,6 +497,56 @@ pub struct TautomerEnumeratorResult {
     size: usize,
 }
 
+impl TautomerEnumeratorResult {
+    /// Whether the enumeration completed or stopped early at a limit or
+    /// through the callback
+    pub fn status(&self) -> TautomerEnumeratorStatus {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_result_status(&self.t_enumerator_result)
+    }
+
+    /// Canonical SMILES of the tautomers, in iteration order
+    pub fn smiles(&self) -> Vec<String> {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_result_smiles(&self.t_enumerator_result)
+            .iter()
+            .map(|smiles| smiles.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    /// The tautomers keyed by their canonical SMILES, RDKit's
+    /// `smilesTautomerMap`
+    pub fn smiles_tautomer_map(&self) -> BTreeMap<String, ROMol> {
+        self.smiles()
+            .into_iter()
+            .enumerate()
+            .map(|(idx, smiles)| {
+                let ptr = rdkit_sys::mol_standardize_ffi::tautomer_enumerator_result_tautomers_at(
+                    &self.t_enumerator_result,
+                    idx,
+                );
+                (smiles, ROMol { ptr })
+            })
+            .collect()
+    }
+
+    /// Indices of the atoms that differ between the tautomers
+    pub fn modified_atoms(&self) -> Vec<u32> {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_result_modified_atoms(
+            &self.t_enumerator_result,
+        )
+        .as_slice()
+        .to_vec()
+    }
+
+    /// Indices of the bonds that differ between the tautomers
+    pub fn modified_bonds(&self) -> Vec<u32> {
+        rdkit_sys::mol_standardize_ffi::tautomer_enumerator_result_modified_bonds(
+            &self.t_enumerator_result,
+        )
+        .as_slice()
+        .to_vec()
+    }
+}
+
 impl Iterator for TautomerEnumeratorResult {
     type Item = crate::ROMol;
 
@@ -109,6 +592,80 @@ pub fn fragment_parent(
     RWMol { ptr }
 }
 
+/// The uncharged form of the [`fragment_parent`]. As with the other parent
+/// functions, `skip_standardize` skips the initial [`standardize`] step for
+/// molecules that were already cleaned up.
+pub fn charge_parent(
+    rw_mol: &RWMol,
+    cleanup_params: &CleanupParameters,
+    skip_standardize: bool,
+) -> Result<RWMol, cxx::Exception> {
+    let ptr = rdkit_sys::mol_standardize_ffi::charge_parent(
+        &rw_mol.ptr,
+        &cleanup_params.ptr,
+        skip_standardize,
+    )?;
+    Ok(RWMol { ptr })
+}
+
+/// The canonical tautomer of the molecule, standardized again afterwards
+pub fn tautomer_parent(
+    rw_mol: &RWMol,
+    cleanup_params: &CleanupParameters,
+    skip_standardize: bool,
+) -> Result<RWMol, cxx::Exception> {
+    let ptr = rdkit_sys::mol_standardize_ffi::tautomer_parent(
+        &rw_mol.ptr,
+        &cleanup_params.ptr,
+        skip_standardize,
+    )?;
+    Ok(RWMol { ptr })
+}
+
+/// The molecule with its atom and bond stereochemistry removed
+pub fn stereo_parent(
+    rw_mol: &RWMol,
+    cleanup_params: &CleanupParameters,
+    skip_standardize: bool,
+) -> Result<RWMol, cxx::Exception> {
+    let ptr = rdkit_sys::mol_standardize_ffi::stereo_parent(
+        &rw_mol.ptr,
+        &cleanup_params.ptr,
+        skip_standardize,
+    )?;
+    Ok(RWMol { ptr })
+}
+
+/// The molecule with all isotope labels removed
+pub fn isotope_parent(
+    rw_mol: &RWMol,
+    cleanup_params: &CleanupParameters,
+    skip_standardize: bool,
+) -> Result<RWMol, cxx::Exception> {
+    let ptr = rdkit_sys::mol_standardize_ffi::isotope_parent(
+        &rw_mol.ptr,
+        &cleanup_params.ptr,
+        skip_standardize,
+    )?;
+    Ok(RWMol { ptr })
+}
+
+/// Combines the charge, tautomer, stereo and isotope parents: the largest
+/// fragment, uncharged, in its canonical tautomer and without stereo or
+/// isotope information
+pub fn super_parent(
+    rw_mol: &RWMol,
+    cleanup_params: &CleanupParameters,
+    skip_standardize: bool,
+) -> Result<RWMol, cxx::Exception> {
+    let ptr = rdkit_sys::mol_standardize_ffi::super_parent(
+        &rw_mol.ptr,
+        &cleanup_params.ptr,
+        skip_standardize,
+    )?;
+    Ok(RWMol { ptr })
+}
+
 pub struct Uncharger {
     pub(crate) ptr: SharedPtr<rdkit_sys::mol_standardize_ffi::Uncharger>,
 }
@@ -126,3 +683,235 @@ impl Uncharger {
         }
     }
 }
+
+/// Applies the normalization transforms of `cleanup_params`
+pub fn normalize(
+    rw_mol: &RWMol,
+    cleanup_params: &CleanupParameters,
+) -> Result<RWMol, cxx::Exception> {
+    let ptr = rdkit_sys::mol_standardize_ffi::normalize(&rw_mol.ptr, &cleanup_params.ptr)?;
+    Ok(RWMol { ptr })
+}
+
+/// Moves charges so that the strongest acids ionize first
+pub fn reionize(
+    rw_mol: &RWMol,
+    cleanup_params: &CleanupParameters,
+) -> Result<RWMol, cxx::Exception> {
+    let ptr = rdkit_sys::mol_standardize_ffi::reionize(&rw_mol.ptr, &cleanup_params.ptr)?;
+    Ok(RWMol { ptr })
+}
+
+/// RDKit's standard cleanup in one call: removes hydrogens, disconnects
+/// metals, normalizes and reionizes the molecule.
+pub fn standardize(
+    rw_mol: &RWMol,
+    cleanup_params: &CleanupParameters,
+) -> Result<RWMol, cxx::Exception> {
+    let ptr = rdkit_sys::mol_standardize_ffi::standardize(&rw_mol.ptr, &cleanup_params.ptr)?;
+    Ok(RWMol { ptr })
+}
+
+pub struct Normalizer {
+    pub(crate) ptr: SharedPtr<rdkit_sys::mol_standardize_ffi::Normalizer>,
+}
+
+impl Default for Normalizer {
+    fn default() -> Self {
+        Normalizer::new()
+    }
+}
+
+impl Normalizer {
+    /// Uses RDKit's built in normalization transforms
+    pub fn new() -> Self {
+        Normalizer {
+            ptr: rdkit_sys::mol_standardize_ffi::new_normalizer(),
+        }
+    }
+
+    /// Builds a normalizer from `(name, SMIRKS)` transforms. Transforms are
+    /// applied repeatedly, at most `max_restarts` times.
+    pub fn from_transforms(
+        transforms: &[(&str, &str)],
+        max_restarts: u32,
+    ) -> Result<Self, cxx::Exception> {
+        let names = transforms
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect();
+        let smirks = transforms
+            .iter()
+            .map(|(_, smirks)| smirks.to_string())
+            .collect();
+        let ptr = rdkit_sys::mol_standardize_ffi::new_normalizer_from_transforms(
+            &names,
+            &smirks,
+            max_restarts,
+        )?;
+        Ok(Normalizer { ptr })
+    }
+
+    pub fn from_cleanup_parameters(
+        cleanup_params: &CleanupParameters,
+    ) -> Result<Self, cxx::Exception> {
+        let ptr = rdkit_sys::mol_standardize_ffi::new_normalizer_from_params(&cleanup_params.ptr)?;
+        Ok(Normalizer { ptr })
+    }
+
+    pub fn normalize(&self, mol: &ROMol) -> Result<ROMol, cxx::Exception> {
+        let ptr = rdkit_sys::mol_standardize_ffi::normalizer_normalize(&self.ptr, &mol.ptr)?;
+        Ok(ROMol { ptr })
+    }
+}
+
+pub struct Reionizer {
+    pub(crate) ptr: SharedPtr<rdkit_sys::mol_standardize_ffi::Reionizer>,
+}
+
+impl Default for Reionizer {
+    fn default() -> Self {
+        Reionizer::new()
+    }
+}
+
+impl Reionizer {
+    /// Uses RDKit's built in acid/base pairs
+    pub fn new() -> Self {
+        Reionizer {
+            ptr: rdkit_sys::mol_standardize_ffi::new_reionizer(),
+        }
+    }
+
+    /// Builds a reionizer from `(name, acid SMARTS, base SMARTS)` pairs,
+    /// ordered from the strongest to the weakest acid
+    pub fn from_acid_base_pairs(pairs: &[(&str, &str, &str)]) -> Result<Self, cxx::Exception> {
+        let names = pairs.iter().map(|(name, _, _)| name.to_string()).collect();
+        let acids = pairs.iter().map(|(_, acid, _)| acid.to_string()).collect();
+        let bases = pairs.iter().map(|(_, _, base)| base.to_string()).collect();
+        let ptr = rdkit_sys::mol_standardize_ffi::new_reionizer_from_pairs(&names, &acids, &bases)?;
+        Ok(Reionizer { ptr })
+    }
+
+    pub fn from_cleanup_parameters(
+        cleanup_params: &CleanupParameters,
+    ) -> Result<Self, cxx::Exception> {
+        let ptr = rdkit_sys::mol_standardize_ffi::new_reionizer_from_params(&cleanup_params.ptr)?;
+        Ok(Reionizer { ptr })
+    }
+
+    pub fn reionize(&self, mol: &ROMol) -> Result<ROMol, cxx::Exception> {
+        let ptr = rdkit_sys::mol_standardize_ffi::reionizer_reionize(&self.ptr, &mol.ptr)?;
+        Ok(ROMol { ptr })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetalDisconnectorOptions {
+    /// Also break carbon-magnesium bonds of Grignard reagents
+    pub split_grignards: bool,
+    /// Also break bonds between metals and aromatic carbons
+    pub split_aromatic_c: bool,
+    /// Adjust the charges of the former neighbours of the metal
+    pub adjust_charges: bool,
+    /// Remove the dummy atoms of haptic bonds
+    pub remove_haptic_dummies: bool,
+}
+
+impl Default for MetalDisconnectorOptions {
+    fn default() -> Self {
+        MetalDisconnectorOptions {
+            split_grignards: false,
+            split_aromatic_c: false,
+            adjust_charges: true,
+            remove_haptic_dummies: false,
+        }
+    }
+}
+
+pub struct MetalDisconnector {
+    pub(crate) ptr: SharedPtr<rdkit_sys::mol_standardize_ffi::MetalDisconnector>,
+}
+
+impl Default for MetalDisconnector {
+    fn default() -> Self {
+        MetalDisconnector::new(&MetalDisconnectorOptions::default())
+    }
+}
+
+impl MetalDisconnector {
+    pub fn new(options: &MetalDisconnectorOptions) -> Self {
+        MetalDisconnector {
+            ptr: rdkit_sys::mol_standardize_ffi::new_metal_disconnector(
+                options.split_grignards,
+                options.split_aromatic_c,
+                options.adjust_charges,
+                options.remove_haptic_dummies,
+            ),
+        }
+    }
+
+    pub fn disconnect(&self, mol: &ROMol) -> Result<ROMol, cxx::Exception> {
+        let ptr =
+            rdkit_sys::mol_standardize_ffi::metal_disconnector_disconnect(&self.ptr, &mol.ptr)?;
+        Ok(ROMol { ptr })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LargestFragmentChooserOptions {
+    /// Prefer fragments containing carbon over larger inorganic ones
+    pub prefer_organic: bool,
+    /// Compare fragments by atom count rather than molecular weight
+    pub use_atom_count: bool,
+    /// Ignore hydrogens when counting atoms
+    pub count_heavy_atoms_only: bool,
+}
+
+impl Default for LargestFragmentChooserOptions {
+    fn default() -> Self {
+        LargestFragmentChooserOptions {
+            prefer_organic: false,
+            use_atom_count: true,
+            count_heavy_atoms_only: false,
+        }
+    }
+}
+
+pub struct LargestFragmentChooser {
+    pub(crate) ptr: SharedPtr<rdkit_sys::mol_standardize_ffi::LargestFragmentChooser>,
+}
+
+impl Default for LargestFragmentChooser {
+    fn default() -> Self {
+        LargestFragmentChooser::new(&LargestFragmentChooserOptions::default())
+    }
+}
+
+impl LargestFragmentChooser {
+    pub fn new(options: &LargestFragmentChooserOptions) -> Self {
+        LargestFragmentChooser {
+            ptr: rdkit_sys::mol_standardize_ffi::new_largest_fragment_chooser(
+                options.prefer_organic,
+                options.use_atom_count,
+                options.count_heavy_atoms_only,
+            ),
+        }
+    }
+
+    pub fn from_cleanup_parameters(cleanup_params: &CleanupParameters) -> Self {
+        LargestFragmentChooser {
+            ptr: rdkit_sys::mol_standardize_ffi::new_largest_fragment_chooser_from_params(
+                &cleanup_params.ptr,
+            ),
+        }
+    }
+
+    pub fn choose(&self, mol: &ROMol) -> ROMol {
+        ROMol {
+            ptr: rdkit_sys::mol_standardize_ffi::largest_fragment_chooser_choose(
+                &self.ptr, &mol.ptr,
+            ),
+        }
+    }
+}
@@ -0,0 +1,339 @@
+use cxx::SharedPtr;
+use rdkit_sys::mol_standardize_ffi;
+
+use crate::{Atom, ROMol};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidationLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+/// A problem reported by a [`Validator`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationError {
+    pub level: ValidationLevel,
+    /// Name of the check that failed, e.g. `IsotopeValidation`
+    pub validation: String,
+    pub message: String,
+    /// Atoms the problem was found on, empty for problems with the molecule
+    /// as a whole
+    pub atom_indices: Vec<u32>,
+}
+
+impl ValidationError {
+    /// Splits messages of the form `ERROR: [ValenceValidation] message`
+    fn parse(error: &str) -> Self {
+        let (level, rest) = match error.split_once(": ") {
+            Some(("INFO", rest)) => (ValidationLevel::Info, rest),
+            Some(("WARNING", rest)) => (ValidationLevel::Warning, rest),
+            Some(("ERROR", rest)) => (ValidationLevel::Error, rest),
+            _ => (ValidationLevel::Error, error),
+        };
+        let (validation, message) = rest
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once("] "))
+            .unwrap_or(("", rest));
+
+        ValidationError {
+            level,
+            validation: validation.to_string(),
+            message: message.to_string(),
+            atom_indices: vec![],
+        }
+    }
+}
+
+/// A structure check from MolStandardize's validation layer
+pub trait Validator {
+    /// With `report_all_failures` unset validators stop at the first problem
+    /// they find
+    fn validate(
+        &self,
+        mol: &ROMol,
+        report_all_failures: bool,
+    ) -> Result<Vec<ValidationError>, cxx::Exception>;
+}
+
+fn run_validation(
+    ptr: &SharedPtr<mol_standardize_ffi::ValidationMethod>,
+    mol: &ROMol,
+    report_all_failures: bool,
+) -> Result<Vec<ValidationError>, cxx::Exception> {
+    let errors =
+        mol_standardize_ffi::validation_method_validate(ptr, &mol.ptr, report_all_failures)?;
+    Ok(errors
+        .iter()
+        .map(|error| {
+            let mut error = ValidationError::parse(&error.to_string_lossy());
+            error.atom_indices = atom_indices_in_message(&error.message);
+            error
+        })
+        .collect())
+}
+
+/// RDKit mentions atoms as `atom # 3` or `atom 3` in its messages
+fn atom_indices_in_message(message: &str) -> Vec<u32> {
+    let words = message.split_whitespace().collect::<Vec<_>>();
+    let mut indices = vec![];
+    for (pos, word) in words.iter().enumerate() {
+        if !word.eq_ignore_ascii_case("atom") {
+            continue;
+        }
+        let index = match words.get(pos + 1) {
+            Some(&"#") => words.get(pos + 2),
+            next => next,
+        };
+        let index = index.map(|index| index.trim_end_matches(|c: char| !c.is_ascii_digit()));
+        if let Some(Ok(index)) = index.map(str::parse::<u32>) {
+            indices.push(index);
+        }
+    }
+    indices
+}
+
+/// Indices of the atoms `predicate` holds for
+fn atoms_matching(mol: &ROMol, predicate: impl Fn(&Atom) -> bool) -> Vec<u32> {
+    (0..mol.num_atoms(true))
+        .filter(|idx| mol.with_atom(*idx, &predicate))
+        .collect()
+}
+
+macro_rules! validation_method {
+    ($(#[$meta:meta])* $name:ident, $constructor:ident) => {
+        $(#[$meta])*
+        pub struct $name {
+            pub(crate) ptr: SharedPtr<mol_standardize_ffi::ValidationMethod>,
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name::new()
+            }
+        }
+
+        impl $name {
+            pub fn new() -> Self {
+                $name {
+                    ptr: mol_standardize_ffi::$constructor(),
+                }
+            }
+        }
+
+        impl Validator for $name {
+            fn validate(
+                &self,
+                mol: &ROMol,
+                report_all_failures: bool,
+            ) -> Result<Vec<ValidationError>, cxx::Exception> {
+                run_validation(&self.ptr, mol, report_all_failures)
+            }
+        }
+    };
+}
+
+validation_method!(
+    /// Reports the problems sanitization would run into, such as atoms with
+    /// too high a valence
+    RDKitValidation,
+    new_rdkit_validation
+);
+validation_method!(
+    /// Flags molecules without any atoms
+    NoAtomValidation,
+    new_no_atom_validation
+);
+validation_method!(
+    /// Flags known solvents, salts and counterions present as separate
+    /// fragments
+    FragmentValidation,
+    new_fragment_validation
+);
+validation_method!(
+    /// Flags molecules with a net charge
+    NeutralValidation,
+    new_neutral_validation
+);
+validation_method!(
+    /// Runs the checks of the original MolVS validator: no atoms, fragments,
+    /// neutrality and isotopes
+    MolVsValidation,
+    new_molvs_validation
+);
+validation_method!(
+    /// Flags atoms with radical electrons, except for a few common stable
+    /// radicals like nitric oxide
+    DisallowedRadicalValidation,
+    new_disallowed_radical_validation
+);
+validation_method!(
+    /// Flags ambiguous or conflicting wedging around stereocenters
+    StereoValidation,
+    new_stereo_validation
+);
+
+/// Flags atoms with an isotope label. In `strict` mode only isotopes without
+/// a known mass are reported. RDKit reports each distinct isotope once, as in
+/// `13C`, so the error lists every atom carrying it.
+pub struct IsotopeValidation {
+    pub(crate) ptr: SharedPtr<mol_standardize_ffi::ValidationMethod>,
+}
+
+impl Default for IsotopeValidation {
+    fn default() -> Self {
+        IsotopeValidation::new(false)
+    }
+}
+
+impl IsotopeValidation {
+    pub fn new(strict: bool) -> Self {
+        IsotopeValidation {
+            ptr: mol_standardize_ffi::new_isotope_validation(strict),
+        }
+    }
+}
+
+impl Validator for IsotopeValidation {
+    fn validate(
+        &self,
+        mol: &ROMol,
+        report_all_failures: bool,
+    ) -> Result<Vec<ValidationError>, cxx::Exception> {
+        let mut errors = run_validation(&self.ptr, mol, report_all_failures)?;
+        for error in &mut errors {
+            let label = error.message.split_whitespace().last().unwrap_or_default();
+            let symbol_start = label
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(label.len());
+            let (isotope, symbol) = label.split_at(symbol_start);
+            let Ok(isotope) = isotope.parse::<u32>() else {
+                continue;
+            };
+            error.atom_indices = atoms_matching(mol, |atom| {
+                atom.get_isotope() == isotope && atom.symbol() == symbol
+            });
+        }
+        Ok(errors)
+    }
+}
+
+/// Flags every atom whose element isn't in the list
+pub struct AllowedAtomsValidation {
+    pub(crate) ptr: SharedPtr<mol_standardize_ffi::ValidationMethod>,
+    atomic_nums: Vec<u32>,
+}
+
+impl AllowedAtomsValidation {
+    pub fn new(atomic_nums: &[u32]) -> Self {
+        let atomic_nums = atomic_nums.to_vec();
+        AllowedAtomsValidation {
+            ptr: mol_standardize_ffi::new_allowed_atoms_validation(&atomic_nums),
+            atomic_nums,
+        }
+    }
+}
+
+impl Validator for AllowedAtomsValidation {
+    fn validate(
+        &self,
+        mol: &ROMol,
+        report_all_failures: bool,
+    ) -> Result<Vec<ValidationError>, cxx::Exception> {
+        let errors = run_validation(&self.ptr, mol, report_all_failures)?;
+        // RDKit reports one error per offending atom, in atom order
+        let offending = atoms_matching(mol, |atom| {
+            !self.atomic_nums.contains(&(atom.get_atomic_num() as u32))
+        });
+        Ok(with_atom_per_error(errors, offending))
+    }
+}
+
+/// Flags every atom whose element is in the list
+pub struct DisallowedAtomsValidation {
+    pub(crate) ptr: SharedPtr<mol_standardize_ffi::ValidationMethod>,
+    atomic_nums: Vec<u32>,
+}
+
+impl DisallowedAtomsValidation {
+    pub fn new(atomic_nums: &[u32]) -> Self {
+        let atomic_nums = atomic_nums.to_vec();
+        DisallowedAtomsValidation {
+            ptr: mol_standardize_ffi::new_disallowed_atoms_validation(&atomic_nums),
+            atomic_nums,
+        }
+    }
+}
+
+impl Validator for DisallowedAtomsValidation {
+    fn validate(
+        &self,
+        mol: &ROMol,
+        report_all_failures: bool,
+    ) -> Result<Vec<ValidationError>, cxx::Exception> {
+        let errors = run_validation(&self.ptr, mol, report_all_failures)?;
+        let offending = atoms_matching(mol, |atom| {
+            self.atomic_nums.contains(&(atom.get_atomic_num() as u32))
+        });
+        Ok(with_atom_per_error(errors, offending))
+    }
+}
+
+/// Errors without a matching atom keep the atoms mentioned in their message
+fn with_atom_per_error(mut errors: Vec<ValidationError>, atoms: Vec<u32>) -> Vec<ValidationError> {
+    for (error, atom_idx) in errors.iter_mut().zip(atoms) {
+        error.atom_indices = vec![atom_idx];
+    }
+    errors
+}
+
+/// A set of validators run one after the other, e.g. the rules of a
+/// compound registration system
+#[derive(Default)]
+pub struct ValidationRuleSet {
+    validators: Vec<Box<dyn Validator>>,
+}
+
+impl ValidationRuleSet {
+    pub fn new() -> Self {
+        ValidationRuleSet::default()
+    }
+
+    pub fn with<V: Validator + 'static>(mut self, validator: V) -> Self {
+        self.add(validator);
+        self
+    }
+
+    pub fn add<V: Validator + 'static>(&mut self, validator: V) {
+        self.validators.push(Box::new(validator));
+    }
+
+    pub fn len(&self) -> usize {
+        self.validators.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+
+    /// True if no validator reports anything. Every message counts as a
+    /// failure whatever its level, since RDKit reports e.g. disallowed atoms
+    /// and charged molecules at the `INFO` level.
+    pub fn is_valid(&self, mol: &ROMol) -> Result<bool, cxx::Exception> {
+        Ok(self.validate(mol, false)?.is_empty())
+    }
+}
+
+impl Validator for ValidationRuleSet {
+    fn validate(
+        &self,
+        mol: &ROMol,
+        report_all_failures: bool,
+    ) -> Result<Vec<ValidationError>, cxx::Exception> {
+        let mut errors = vec![];
+        for validator in &self.validators {
+            errors.extend(validator.validate(mol, report_all_failures)?);
+        }
+        Ok(errors)
+    }
+}
@@ -0,0 +1,256 @@
+use std::ops::Not;
+
+use crate::{ROMol, RWMol};
+
+/// An atom query that renders to a SMARTS atom expression, e.g. a halogen
+/// with a single neighbor:
+///
+/// ```
+/// use rdkit::AtomQuery;
+///
+/// let query = AtomQuery::halogen().and(AtomQuery::Degree(1));
+/// assert_eq!(query.to_smarts(), "[#9,#17,#35,#53;D1]");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum AtomQuery {
+    /// `*`
+    Any,
+    /// `#n`
+    AtomicNum(u32),
+    /// `a`
+    Aromatic,
+    /// `A`
+    Aliphatic,
+    /// `Dn`, the number of explicit neighbors
+    Degree(u32),
+    /// `Hn`, implicit and explicit hydrogens
+    TotalHCount(u32),
+    /// `Xn`, neighbors including implicit hydrogens
+    TotalConnectivity(u32),
+    /// `vn`
+    TotalValence(u32),
+    /// `+n` or `-n`
+    FormalCharge(i32),
+    /// The mass number, `13` in `[13#6]`
+    Isotope(u32),
+    /// `R`
+    InRing,
+    /// `Rn`, the number of SSSR rings the atom is in
+    RingCount(u32),
+    /// `rn`, the size of the smallest ring the atom is in
+    RingSize(u32),
+    /// `$(...)`, atoms the given SMARTS matches starting from its first atom
+    Recursive(String),
+    And(Box<AtomQuery>, Box<AtomQuery>),
+    Or(Box<AtomQuery>, Box<AtomQuery>),
+    Not(Box<AtomQuery>),
+}
+
+impl AtomQuery {
+    /// Fluorine, chlorine, bromine or iodine
+    pub fn halogen() -> Self {
+        AtomQuery::AtomicNum(9)
+            .or(AtomQuery::AtomicNum(17))
+            .or(AtomQuery::AtomicNum(35))
+            .or(AtomQuery::AtomicNum(53))
+    }
+
+    /// Matches atoms in the environment described by `query`, which is
+    /// anchored at its first atom
+    pub fn recursive(query: &ROMol) -> Self {
+        AtomQuery::Recursive(query.as_smarts())
+    }
+
+    pub fn and(self, other: AtomQuery) -> Self {
+        AtomQuery::And(Box::new(self), Box::new(other))
+    }
+
+    pub fn or(self, other: AtomQuery) -> Self {
+        AtomQuery::Or(Box::new(self), Box::new(other))
+    }
+
+    /// The query as a bracketed SMARTS atom
+    pub fn to_smarts(&self) -> String {
+        format!("[{}]", render(&self.expr()))
+    }
+
+    /// A single atom query molecule, whose [`ROMol::as_smarts`] shows how
+    /// RDKit parsed the query
+    pub fn to_mol(&self) -> Result<ROMol, Box<dyn std::error::Error>> {
+        Ok(RWMol::from_smarts(&self.to_smarts())?.to_ro_mol())
+    }
+
+    fn expr(&self) -> Expr {
+        let primitive = match self {
+            AtomQuery::Any => "*".to_string(),
+            AtomQuery::AtomicNum(num) => format!("#{num}"),
+            AtomQuery::Aromatic => "a".to_string(),
+            AtomQuery::Aliphatic => "A".to_string(),
+            AtomQuery::Degree(degree) => format!("D{degree}"),
+            AtomQuery::TotalHCount(count) => format!("H{count}"),
+            AtomQuery::TotalConnectivity(count) => format!("X{count}"),
+            AtomQuery::TotalValence(valence) => format!("v{valence}"),
+            AtomQuery::FormalCharge(charge) if *charge < 0 => format!("{charge}"),
+            AtomQuery::FormalCharge(charge) => format!("+{charge}"),
+            AtomQuery::Isotope(isotope) => format!("{isotope}"),
+            AtomQuery::InRing => "R".to_string(),
+            AtomQuery::RingCount(count) => format!("R{count}"),
+            AtomQuery::RingSize(size) => format!("r{size}"),
+            AtomQuery::Recursive(smarts) => format!("$({smarts})"),
+            AtomQuery::And(a, b) => return Expr::And(Box::new(a.expr()), Box::new(b.expr())),
+            AtomQuery::Or(a, b) => return Expr::Or(Box::new(a.expr()), Box::new(b.expr())),
+            AtomQuery::Not(query) => return Expr::Not(Box::new(query.expr())),
+        };
+        Expr::Primitive(primitive)
+    }
+}
+
+impl Not for AtomQuery {
+    type Output = AtomQuery;
+
+    fn not(self) -> Self::Output {
+        AtomQuery::Not(Box::new(self))
+    }
+}
+
+/// A bond query that renders to a SMARTS bond expression
+#[derive(Clone, Debug, PartialEq)]
+pub enum BondQuery {
+    /// `~`
+    Any,
+    /// `-`
+    Single,
+    /// `=`
+    Double,
+    /// `#`
+    Triple,
+    /// `:`
+    Aromatic,
+    /// `@`
+    InRing,
+    And(Box<BondQuery>, Box<BondQuery>),
+    Or(Box<BondQuery>, Box<BondQuery>),
+    Not(Box<BondQuery>),
+}
+
+impl BondQuery {
+    pub fn and(self, other: BondQuery) -> Self {
+        BondQuery::And(Box::new(self), Box::new(other))
+    }
+
+    pub fn or(self, other: BondQuery) -> Self {
+        BondQuery::Or(Box::new(self), Box::new(other))
+    }
+
+    pub fn to_smarts(&self) -> String {
+        render(&self.expr())
+    }
+
+    /// The bond between two wildcard atoms, `*~*` for [`BondQuery::Any`]
+    pub fn to_mol(&self) -> Result<ROMol, Box<dyn std::error::Error>> {
+        Ok(RWMol::from_smarts(&format!("*{}*", self.to_smarts()))?.to_ro_mol())
+    }
+
+    fn expr(&self) -> Expr {
+        let primitive = match self {
+            BondQuery::Any => "~",
+            BondQuery::Single => "-",
+            BondQuery::Double => "=",
+            BondQuery::Triple => "#",
+            BondQuery::Aromatic => ":",
+            BondQuery::InRing => "@",
+            BondQuery::And(a, b) => return Expr::And(Box::new(a.expr()), Box::new(b.expr())),
+            BondQuery::Or(a, b) => return Expr::Or(Box::new(a.expr()), Box::new(b.expr())),
+            BondQuery::Not(query) => return Expr::Not(Box::new(query.expr())),
+        };
+        Expr::Primitive(primitive.to_string())
+    }
+}
+
+impl Not for BondQuery {
+    type Output = BondQuery;
+
+    fn not(self) -> Self::Output {
+        BondQuery::Not(Box::new(self))
+    }
+}
+
+enum Expr {
+    Primitive(String),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+}
+
+// SMARTS operators from tightest to loosest binding: `!`, `&`, `,`, `;`
+const PRIMITIVE: u8 = 3;
+const HIGH_AND: u8 = 2;
+const OR: u8 = 1;
+const LOW_AND: u8 = 0;
+
+fn render(expr: &Expr) -> String {
+    match render_with_precedence(expr) {
+        Some((smarts, _)) => smarts,
+        // SMARTS has no parentheses, so e.g. a negated OR has to be
+        // rewritten as an AND of ORs
+        None => {
+            let clauses = conjunctive_normal_form(expr, false);
+            let separator = if clauses.iter().all(|clause| clause.len() == 1) {
+                "&"
+            } else {
+                ";"
+            };
+            clauses
+                .iter()
+                .map(|clause| clause.join(","))
+                .collect::<Vec<_>>()
+                .join(separator)
+        }
+    }
+}
+
+/// `None` if the expression can't be written as is
+fn render_with_precedence(expr: &Expr) -> Option<(String, u8)> {
+    match expr {
+        Expr::Primitive(primitive) => Some((primitive.clone(), PRIMITIVE)),
+        Expr::Not(inner) => {
+            let (inner, precedence) = render_with_precedence(inner)?;
+            (precedence == PRIMITIVE).then(|| (format!("!{inner}"), PRIMITIVE))
+        }
+        Expr::Or(a, b) => {
+            let (a, a_precedence) = render_with_precedence(a)?;
+            let (b, b_precedence) = render_with_precedence(b)?;
+            (a_precedence >= OR && b_precedence >= OR).then(|| (format!("{a},{b}"), OR))
+        }
+        Expr::And(a, b) => {
+            let (a, a_precedence) = render_with_precedence(a)?;
+            let (b, b_precedence) = render_with_precedence(b)?;
+            if a_precedence >= HIGH_AND && b_precedence >= HIGH_AND {
+                Some((format!("{a}&{b}"), HIGH_AND))
+            } else {
+                Some((format!("{a};{b}"), LOW_AND))
+            }
+        }
+    }
+}
+
+/// Clauses of possibly negated primitives, ORed within and ANDed across
+fn conjunctive_normal_form(expr: &Expr, negated: bool) -> Vec<Vec<String>> {
+    match (expr, negated) {
+        (Expr::Primitive(primitive), false) => vec![vec![primitive.clone()]],
+        (Expr::Primitive(primitive), true) => vec![vec![format!("!{primitive}")]],
+        (Expr::Not(inner), _) => conjunctive_normal_form(inner, !negated),
+        (Expr::And(a, b), false) | (Expr::Or(a, b), true) => {
+            let mut clauses = conjunctive_normal_form(a, negated);
+            clauses.extend(conjunctive_normal_form(b, negated));
+            clauses
+        }
+        (Expr::Or(a, b), false) | (Expr::And(a, b), true) => {
+            let a = conjunctive_normal_form(a, negated);
+            let b = conjunctive_normal_form(b, negated);
+            a.iter()
+                .flat_map(|a| b.iter().map(move |b| [a.as_slice(), b.as_slice()].concat()))
+                .collect()
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use cxx::SharedPtr;
+use rdkit_sys::{resonance_ffi, substruct_match_ffi};
+
+use crate::{
+    substruct_match::{first_match, match_items},
+    ROMol, SubstructMatchItem, SubstructMatchParameters,
+};
+
+/// Which resonance structures [`ResonanceMolSupplier`] enumerates. The
+/// defaults only keep the structures with the most complete octets and the
+/// least charge separation.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResonanceFlags {
+    /// Also include structures with less complete octets than the best one
+    pub allow_incomplete_octets: bool,
+    /// Also include charge separated structures when uncharged ones exist
+    pub allow_charge_separation: bool,
+    /// Enumerate all degenerate Kekulé structures instead of just one
+    pub kekule_all: bool,
+    /// Accept cations with incomplete octets next to nitrogen even if the
+    /// conjugated group isn't positively charged overall
+    pub unconstrained_cations: bool,
+    /// Accept anions even if the conjugated group isn't negatively charged
+    /// overall
+    pub unconstrained_anions: bool,
+}
+
+impl ResonanceFlags {
+    fn bits(&self) -> u32 {
+        let mut bits = 0;
+        for (flag, bit) in [
+            (self.allow_incomplete_octets, 1 << 0),
+            (self.allow_charge_separation, 1 << 1),
+            (self.kekule_all, 1 << 2),
+            (self.unconstrained_cations, 1 << 3),
+            (self.unconstrained_anions, 1 << 4),
+        ] {
+            if flag {
+                bits |= bit;
+            }
+        }
+        bits
+    }
+}
+
+/// Enumerates the resonance structures of a molecule, iterating yields each
+/// of them as a [`ROMol`]
+pub struct ResonanceMolSupplier {
+    pub(crate) ptr: SharedPtr<resonance_ffi::ResonanceMolSupplier>,
+    pos: u32,
+}
+
+impl ResonanceMolSupplier {
+    /// Enumeration stops after `max_structs` structures, RDKit's default is
+    /// 1000
+    pub fn new(ro_mol: &ROMol, flags: &ResonanceFlags, max_structs: u32) -> Self {
+        ResonanceMolSupplier {
+            ptr: resonance_ffi::new_resonance_mol_supplier(&ro_mol.ptr, flags.bits(), max_structs),
+            pos: 0,
+        }
+    }
+
+    /// Number of resonance structures, enumerating them if that hasn't
+    /// happened yet
+    pub fn len(&self) -> u32 {
+        resonance_ffi::resonance_mol_supplier_length(&self.ptr)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Fails if `idx` is out of range
+    pub fn resonance_structure(&self, idx: u32) -> Result<ROMol, cxx::Exception> {
+        let ptr = resonance_ffi::resonance_mol_supplier_at(&self.ptr, idx)?;
+        Ok(ROMol { ptr })
+    }
+
+    pub fn num_conj_groups(&self) -> u32 {
+        resonance_ffi::resonance_mol_supplier_num_conj_grps(&self.ptr)
+    }
+
+    /// The conjugated group the atom belongs to, `None` for atoms outside of
+    /// any. Fails if `atom_idx` is out of range.
+    pub fn atom_conj_group_idx(&self, atom_idx: u32) -> Result<Option<u32>, cxx::Exception> {
+        let idx = resonance_ffi::resonance_mol_supplier_atom_conj_grp_idx(&self.ptr, atom_idx)?;
+        Ok(u32::try_from(idx).ok())
+    }
+
+    /// The conjugated group the bond belongs to, `None` for bonds outside of
+    /// any. Fails if `bond_idx` is out of range.
+    pub fn bond_conj_group_idx(&self, bond_idx: u32) -> Result<Option<u32>, cxx::Exception> {
+        let idx = resonance_ffi::resonance_mol_supplier_bond_conj_grp_idx(&self.ptr, bond_idx)?;
+        Ok(u32::try_from(idx).ok())
+    }
+
+    /// True if enumeration stopped early
+    pub fn was_canceled(&self) -> bool {
+        resonance_ffi::resonance_mol_supplier_was_canceled(&self.ptr)
+    }
+
+    /// Like [`crate::substruct_match`], but matches `query` against every
+    /// resonance structure enumerated with this supplier's flags
+    pub fn substruct_match(
+        &self,
+        query: &ROMol,
+        params: &SubstructMatchParameters,
+    ) -> Vec<Vec<SubstructMatchItem>> {
+        match_items(substruct_match_ffi::substruct_match_resonance(
+            &self.ptr,
+            &query.ptr,
+            &params.ptr,
+        ))
+    }
+
+    /// Resonance aware [`crate::has_substruct_match`]
+    pub fn has_substruct_match(&self, query: &ROMol, params: &SubstructMatchParameters) -> bool {
+        substruct_match_ffi::has_substruct_match_resonance(&self.ptr, &query.ptr, &params.ptr)
+    }
+
+    /// Resonance aware [`crate::count_substruct_matches`], with the same
+    /// memory use growing with the number of matches
+    pub fn count_substruct_matches(&self, query: &ROMol, params: &SubstructMatchParameters) -> u32 {
+        substruct_match_ffi::count_substruct_matches_resonance(&self.ptr, &query.ptr, &params.ptr)
+    }
+
+    /// Resonance aware [`crate::substruct_match_first`]
+    pub fn substruct_match_first(
+        &self,
+        query: &ROMol,
+        params: &SubstructMatchParameters,
+    ) -> Option<Vec<(u32, u32)>> {
+        first_match(substruct_match_ffi::substruct_match_first_resonance(
+            &self.ptr,
+            &query.ptr,
+            &params.ptr,
+        ))
+    }
+}
+
+impl Iterator for ResonanceMolSupplier {
+    type Item = ROMol;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.len() {
+            return None;
+        }
+        let structure = self.resonance_structure(self.pos).ok();
+        self.pos += 1;
+        structure
+    }
+}
@@ -0,0 +1,242 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use cxx::{let_cxx_string, SharedPtr};
+use rdkit_sys::rgroup_decomposition_ffi;
+
+use crate::{ro_mol_list, ROMol};
+
+/// How the R-group attachment points of the cores are marked. These are bit
+/// flags, e.g. `[AtomMapLabels, RelabelDuplicateLabels]` in
+/// [`RGroupDecompositionParameters::labels`].
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RGroupLabels {
+    IsotopeLabels = 0x01,
+    AtomMapLabels = 0x02,
+    AtomIndexLabels = 0x04,
+    RelabelDuplicateLabels = 0x08,
+    MdlRGroupLabels = 0x10,
+    DummyAtomLabels = 0x20,
+    /// Use whichever of the labels above is found on the core
+    AutoDetect = 0xff,
+}
+
+/// How molecules with several possible core matches are assigned one. These
+/// are bit flags, so modifiers like `NoSymmetrization` are combined with a
+/// strategy, e.g. `[Greedy, NoSymmetrization]`.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RGroupMatching {
+    Greedy = 0x01,
+    GreedyChunks = 0x02,
+    Exhaustive = 0x04,
+    NoSymmetrization = 0x08,
+    FingerprintVariance = 0x10,
+    /// Genetic algorithm
+    Genetic = 0x20,
+}
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RGroupCoreAlignment {
+    NoAlignment = 0x00,
+    /// Align the cores to their maximum common substructure so equivalent
+    /// positions get the same R-group label
+    Mcs = 0x01,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum RGroupParametersError {
+    #[error("at least one label is required")]
+    NoLabels,
+    #[error("at least one matching strategy is required")]
+    NoMatchingStrategy,
+}
+
+/// Options for [`rgroup_decompose`]
+pub struct RGroupDecompositionParameters {
+    pub(crate) ptr: SharedPtr<rgroup_decomposition_ffi::RGroupDecompositionParameters>,
+}
+
+impl Default for RGroupDecompositionParameters {
+    fn default() -> Self {
+        RGroupDecompositionParameters::new()
+    }
+}
+
+impl RGroupDecompositionParameters {
+    pub fn new() -> Self {
+        RGroupDecompositionParameters {
+            ptr: rgroup_decomposition_ffi::new_rgroup_decomposition_parameters(),
+        }
+    }
+
+    pub fn get_remove_all_hydrogen_rgroups(&self) -> bool {
+        rgroup_decomposition_ffi::rgroup_decomposition_parameters_get_remove_all_hydrogen_rgroups(
+            &self.ptr,
+        )
+    }
+
+    pub fn get_only_match_at_rgroups(&self) -> bool {
+        rgroup_decomposition_ffi::rgroup_decomposition_parameters_get_only_match_at_rgroups(
+            &self.ptr,
+        )
+    }
+
+    /// In seconds, negative for no timeout
+    pub fn get_timeout(&self) -> f64 {
+        rgroup_decomposition_ffi::rgroup_decomposition_parameters_get_timeout(&self.ptr)
+    }
+
+    /// Fails if `labels` is empty
+    pub fn labels(mut self, labels: &[RGroupLabels]) -> Result<Self, RGroupParametersError> {
+        if labels.is_empty() {
+            return Err(RGroupParametersError::NoLabels);
+        }
+        let labels = labels.iter().fold(0, |flags, label| flags | *label as u32);
+        rgroup_decomposition_ffi::rgroup_decomposition_parameters_set_labels(&mut self.ptr, labels);
+        Ok(self)
+    }
+
+    /// Fails if `strategy` is empty
+    pub fn matching_strategy(
+        mut self,
+        strategy: &[RGroupMatching],
+    ) -> Result<Self, RGroupParametersError> {
+        if strategy.is_empty() {
+            return Err(RGroupParametersError::NoMatchingStrategy);
+        }
+        let strategy = strategy.iter().fold(0, |flags, flag| flags | *flag as u32);
+        rgroup_decomposition_ffi::rgroup_decomposition_parameters_set_matching_strategy(
+            &mut self.ptr,
+            strategy,
+        );
+        Ok(self)
+    }
+
+    pub fn alignment(mut self, alignment: RGroupCoreAlignment) -> Self {
+        rgroup_decomposition_ffi::rgroup_decomposition_parameters_set_alignment(
+            &mut self.ptr,
+            alignment as u32,
+        );
+        self
+    }
+
+    /// Drop R-groups that are only hydrogen in every molecule
+    pub fn remove_all_hydrogen_rgroups(mut self, what: bool) -> Self {
+        rgroup_decomposition_ffi::rgroup_decomposition_parameters_set_remove_all_hydrogen_rgroups(
+            &mut self.ptr,
+            what,
+        );
+        self
+    }
+
+    /// Only allow substituents at the labelled positions of the core,
+    /// molecules with other substituents are unmatched
+    pub fn only_match_at_rgroups(mut self, what: bool) -> Self {
+        rgroup_decomposition_ffi::rgroup_decomposition_parameters_set_only_match_at_rgroups(
+            &mut self.ptr,
+            what,
+        );
+        self
+    }
+
+    /// Fail the decomposition after this many seconds
+    pub fn timeout(mut self, seconds: f64) -> Self {
+        rgroup_decomposition_ffi::rgroup_decomposition_parameters_set_timeout(
+            &mut self.ptr,
+            seconds,
+        );
+        self
+    }
+}
+
+/// The decomposition of one molecule, keyed by `Core`, `R1`, `R2`, ...
+#[derive(Debug)]
+pub struct RGroupRow {
+    /// Index of the molecule in the input
+    pub mol_idx: u32,
+    pub groups: BTreeMap<String, ROMol>,
+}
+
+impl RGroupRow {
+    pub fn core(&self) -> Option<&ROMol> {
+        self.groups.get("Core")
+    }
+
+    /// `rgroup(1)` is `R1`
+    pub fn rgroup(&self, num: u32) -> Option<&ROMol> {
+        self.groups.get(&format!("R{num}"))
+    }
+
+    pub fn smiles(&self) -> BTreeMap<String, String> {
+        self.groups
+            .iter()
+            .map(|(label, mol)| (label.clone(), mol.as_smiles()))
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct RGroupDecomposition {
+    /// One row per matched molecule, in input order
+    pub rows: Vec<RGroupRow>,
+    /// Indices of the molecules no core matched
+    pub unmatched: Vec<u32>,
+}
+
+impl RGroupDecomposition {
+    /// The labels used across all rows, `Core` first and R-groups in
+    /// numeric order
+    pub fn labels(&self) -> Vec<String> {
+        let labels = self
+            .rows
+            .iter()
+            .flat_map(|row| row.groups.keys())
+            .collect::<BTreeSet<_>>();
+        let mut labels = labels.into_iter().cloned().collect::<Vec<_>>();
+        labels.sort_by_key(|label| match label.strip_prefix('R') {
+            Some(num) => (1, num.parse::<u32>().unwrap_or(u32::MAX), label.clone()),
+            None => (0, 0, label.clone()),
+        });
+        labels
+    }
+}
+
+/// Splits each molecule into the matching core and the substituents at its
+/// R-group positions
+pub fn rgroup_decompose(
+    cores: &[ROMol],
+    mols: &[ROMol],
+    params: &RGroupDecompositionParameters,
+) -> Result<RGroupDecomposition, cxx::Exception> {
+    let result = rgroup_decomposition_ffi::rgroup_decompose(
+        &ro_mol_list(cores),
+        &ro_mol_list(mols),
+        &params.ptr,
+    )?;
+    let unmatched = rgroup_decomposition_ffi::rgroup_decompose_result_unmatched(&result)
+        .iter()
+        .copied()
+        .collect::<Vec<_>>();
+    let matched = (0..mols.len() as u32).filter(|idx| !unmatched.contains(idx));
+
+    let mut rows = vec![];
+    for (row, mol_idx) in
+        (0..rgroup_decomposition_ffi::rgroup_decompose_result_num_rows(&result)).zip(matched)
+    {
+        let mut groups = BTreeMap::new();
+        let labels = rgroup_decomposition_ffi::rgroup_decompose_result_row_labels(&result, row);
+        for label in labels.iter() {
+            let label = label.to_string_lossy().into_owned();
+            let_cxx_string!(cxx_label = &label);
+            let ptr = rgroup_decomposition_ffi::rgroup_decompose_result_row_mol(
+                &result, row, &cxx_label,
+            )?;
+            groups.insert(label, ROMol { ptr });
+        }
+        rows.push(RGroupRow { mol_idx, groups });
+    }
+
+    Ok(RGroupDecomposition { rows, unmatched })
+}
@@ -0,0 +1,37 @@
+use std::collections::BTreeMap;
+
+use rdkit_sys::chem_transforms_ffi;
+
+use crate::ROMol;
+
+/// The Bemis–Murcko scaffold: ring systems and the linkers between them,
+/// with all side chains removed. Empty for acyclic molecules.
+pub fn murcko_scaffold(mol: &ROMol) -> Result<ROMol, cxx::Exception> {
+    let ptr = chem_transforms_ffi::murcko_decompose(&mol.ptr)?;
+    Ok(ROMol { ptr })
+}
+
+/// The scaffold's framework, with every heavy atom turned into an aliphatic
+/// carbon and every bond into a single bond
+pub fn generic_scaffold(scaffold: &ROMol) -> Result<ROMol, cxx::Exception> {
+    let ptr = chem_transforms_ffi::make_scaffold_generic(&scaffold.ptr)?;
+    Ok(ROMol { ptr })
+}
+
+/// Groups molecules by the SMILES of their Murcko scaffold, or of its generic
+/// framework if `generic` is set, returning the indices of the molecules in
+/// each group. Acyclic molecules share the empty scaffold `""`.
+pub fn group_by_scaffold<'a>(
+    mols: impl IntoIterator<Item = &'a ROMol>,
+    generic: bool,
+) -> Result<BTreeMap<String, Vec<usize>>, cxx::Exception> {
+    let mut groups = BTreeMap::<String, Vec<usize>>::new();
+    for (idx, mol) in mols.into_iter().enumerate() {
+        let mut scaffold = murcko_scaffold(mol)?;
+        if generic {
+            scaffold = generic_scaffold(&scaffold)?;
+        }
+        groups.entry(scaffold.as_smiles()).or_default().push(idx);
+    }
+    Ok(groups)
+}
@@ -0,0 +1,280 @@
+use std::fmt::Write;
+
+use cxx::SharedPtr;
+use rdkit_sys::scaffold_network_ffi;
+pub use rdkit_sys::scaffold_network_ffi::EdgeType;
+
+use crate::{ro_mol_list, ROMol};
+
+/// Options for building a [`ScaffoldNetwork`]
+pub struct ScaffoldNetworkParams {
+    pub(crate) ptr: SharedPtr<scaffold_network_ffi::ScaffoldNetworkParams>,
+}
+
+impl Default for ScaffoldNetworkParams {
+    fn default() -> Self {
+        ScaffoldNetworkParams::new()
+    }
+}
+
+impl ScaffoldNetworkParams {
+    /// Fragments at RDKit's default bond breaker, the bonds between rings
+    /// and the rest of the molecule
+    pub fn new() -> Self {
+        ScaffoldNetworkParams {
+            ptr: scaffold_network_ffi::default_scaffold_network_params(),
+        }
+    }
+
+    /// Fragments at the bonds matched by these reaction SMARTS instead.
+    /// Fails if one of them can't be parsed.
+    pub fn with_bond_breakers(bond_breakers_smarts: &[&str]) -> Result<Self, cxx::Exception> {
+        let smarts = bond_breakers_smarts
+            .iter()
+            .map(|smarts| smarts.to_string())
+            .collect();
+        Ok(ScaffoldNetworkParams {
+            ptr: scaffold_network_ffi::new_scaffold_network_params(&smarts)?,
+        })
+    }
+
+    /// Add scaffolds with all atoms replaced by dummies
+    pub fn include_generic_scaffolds(mut self, what: bool) -> Self {
+        scaffold_network_ffi::set_include_generic_scaffolds(&mut self.ptr, what);
+        self
+    }
+
+    /// Add scaffolds with all bonds replaced by single bonds
+    pub fn include_generic_bond_scaffolds(mut self, what: bool) -> Self {
+        scaffold_network_ffi::include_generic_bond_scaffolds(&mut self.ptr, what);
+        self
+    }
+
+    pub fn include_scaffolds_without_attachments(mut self, what: bool) -> Self {
+        scaffold_network_ffi::include_scaffolds_without_attachments(&mut self.ptr, what);
+        self
+    }
+
+    pub fn include_scaffolds_with_attachments(mut self, what: bool) -> Self {
+        scaffold_network_ffi::include_scaffolds_with_attachments(&mut self.ptr, what);
+        self
+    }
+
+    pub fn keep_only_first_fragment(mut self, what: bool) -> Self {
+        scaffold_network_ffi::keep_only_first_fragment(&mut self.ptr, what);
+        self
+    }
+
+    /// Reduce molecules to their Murcko scaffold before fragmenting them
+    pub fn prune_before_fragmenting(mut self, what: bool) -> Self {
+        scaffold_network_ffi::prune_before_fragmenting(&mut self.ptr, what);
+        self
+    }
+
+    pub fn flatten_isotopes(mut self, what: bool) -> Self {
+        scaffold_network_ffi::flatten_isotopes(&mut self.ptr, what);
+        self
+    }
+
+    pub fn flatten_chirality(mut self, what: bool) -> Self {
+        scaffold_network_ffi::flatten_chirality(&mut self.ptr, what);
+        self
+    }
+
+    /// Only keep the largest fragment of each molecule
+    pub fn flatten_keep_largest(mut self, what: bool) -> Self {
+        scaffold_network_ffi::flatten_keep_largest(&mut self.ptr, what);
+        self
+    }
+
+    /// Track how many molecules each node occurs in, see
+    /// [`ScaffoldNetwork::mol_counts`]. On by default.
+    pub fn collect_mol_counts(mut self, what: bool) -> Self {
+        scaffold_network_ffi::collect_mol_counts(&mut self.ptr, what);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScaffoldNetworkEdge {
+    /// Index of the node the scaffold was derived from
+    pub begin_idx: u32,
+    pub end_idx: u32,
+    pub edge_type: EdgeType,
+}
+
+/// A graph of scaffolds, connected by the operation deriving one from
+/// another. Nodes are identified by SMILES.
+pub struct ScaffoldNetwork {
+    pub(crate) ptr: SharedPtr<scaffold_network_ffi::ScaffoldNetworkClass>,
+}
+
+impl ScaffoldNetwork {
+    pub fn new<'a>(
+        mols: impl IntoIterator<Item = &'a ROMol>,
+        params: &ScaffoldNetworkParams,
+    ) -> Result<Self, cxx::Exception> {
+        let mut network = ScaffoldNetwork {
+            ptr: scaffold_network_ffi::default_scaffold_network(),
+        };
+        network.update_network(mols, params)?;
+        Ok(network)
+    }
+
+    /// Adds more molecules to the network
+    pub fn update_network<'a>(
+        &mut self,
+        mols: impl IntoIterator<Item = &'a ROMol>,
+        params: &ScaffoldNetworkParams,
+    ) -> Result<(), cxx::Exception> {
+        let list = ro_mol_list(mols);
+        scaffold_network_ffi::update_scaffold_network(&mut self.ptr, &list, &params.ptr)
+    }
+
+    /// SMILES of the scaffolds
+    pub fn nodes(&self) -> Vec<String> {
+        scaffold_network_ffi::scaffold_network_nodes(&self.ptr)
+            .iter()
+            .map(|node| node.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    /// How often each node was reached while building the network
+    pub fn counts(&self) -> Vec<u32> {
+        scaffold_network_ffi::scaffold_network_counts(&self.ptr)
+            .iter()
+            .copied()
+            .collect()
+    }
+
+    /// How many molecules contain each node. Collected by default, empty if
+    /// the network was built with
+    /// [`ScaffoldNetworkParams::collect_mol_counts`] turned off.
+    pub fn mol_counts(&self) -> Vec<u32> {
+        scaffold_network_ffi::scaffold_network_mol_counts(&self.ptr)
+            .iter()
+            .copied()
+            .collect()
+    }
+
+    pub fn edges(&self) -> Vec<ScaffoldNetworkEdge> {
+        scaffold_network_ffi::scaffold_network_edges(&self.ptr)
+            .iter()
+            .map(|edge| ScaffoldNetworkEdge {
+                begin_idx: scaffold_network_ffi::network_edge_begin_idx(edge),
+                end_idx: scaffold_network_ffi::network_edge_end_idx(edge),
+                edge_type: scaffold_network_ffi::network_edge_type(edge),
+            })
+            .collect()
+    }
+
+    /// Directed GraphML with `smiles`, `count` and, if collected,
+    /// `mol_count` node attributes and a `type` edge attribute
+    pub fn to_graphml(&self) -> String {
+        let mol_counts = self.mol_counts();
+        let mut graphml = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+            "  <key id=\"smiles\" for=\"node\" attr.name=\"smiles\" attr.type=\"string\"/>\n",
+            "  <key id=\"count\" for=\"node\" attr.name=\"count\" attr.type=\"int\"/>\n",
+            "  <key id=\"mol_count\" for=\"node\" attr.name=\"mol_count\" attr.type=\"int\"/>\n",
+            "  <key id=\"type\" for=\"edge\" attr.name=\"type\" attr.type=\"string\"/>\n",
+            "  <graph id=\"scaffold_network\" edgedefault=\"directed\">\n",
+        ));
+        for (idx, (smiles, count)) in self.nodes().iter().zip(self.counts()).enumerate() {
+            let _ = write!(
+                graphml,
+                "    <node id=\"n{idx}\"><data key=\"smiles\">{}</data><data key=\"count\">{count}</data>",
+                escape_xml(smiles)
+            );
+            if let Some(mol_count) = mol_counts.get(idx) {
+                let _ = write!(graphml, "<data key=\"mol_count\">{mol_count}</data>");
+            }
+            graphml.push_str("</node>\n");
+        }
+        for edge in self.edges() {
+            let _ = writeln!(
+                graphml,
+                "    <edge source=\"n{}\" target=\"n{}\"><data key=\"type\">{}</data></edge>",
+                edge.begin_idx,
+                edge.end_idx,
+                edge_type_name(edge.edge_type)
+            );
+        }
+        graphml.push_str("  </graph>\n</graphml>\n");
+        graphml
+    }
+
+    /// `{"nodes": [{"id", "smiles", "count", "mol_count"}], "edges":
+    /// [{"source", "target", "type"}]}`, with `mol_count` only present if
+    /// collected
+    pub fn to_json(&self) -> String {
+        let mol_counts = self.mol_counts();
+        let nodes = self
+            .nodes()
+            .iter()
+            .zip(self.counts())
+            .enumerate()
+            .map(|(idx, (smiles, count))| {
+                let mol_count = mol_counts
+                    .get(idx)
+                    .map(|mol_count| format!(",\"mol_count\":{mol_count}"))
+                    .unwrap_or_default();
+                format!(
+                    "{{\"id\":{idx},\"smiles\":\"{}\",\"count\":{count}{mol_count}}}",
+                    escape_json(smiles)
+                )
+            })
+            .collect::<Vec<_>>();
+        let edges = self
+            .edges()
+            .iter()
+            .map(|edge| {
+                format!(
+                    "{{\"source\":{},\"target\":{},\"type\":\"{}\"}}",
+                    edge.begin_idx,
+                    edge.end_idx,
+                    edge_type_name(edge.edge_type)
+                )
+            })
+            .collect::<Vec<_>>();
+        format!(
+            "{{\"nodes\":[{}],\"edges\":[{}]}}",
+            nodes.join(","),
+            edges.join(",")
+        )
+    }
+}
+
+fn edge_type_name(edge_type: EdgeType) -> &'static str {
+    match edge_type {
+        EdgeType::Fragment => "Fragment",
+        EdgeType::Generic => "Generic",
+        EdgeType::GenericBond => "GenericBond",
+        EdgeType::RemoveAttachment => "RemoveAttachment",
+        EdgeType::Initialize => "Initialize",
+        _ => "Unknown",
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
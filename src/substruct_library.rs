@@ -0,0 +1,129 @@
+use std::path::Path;
+
+use cxx::SharedPtr;
+use rdkit_sys::substruct_library_ffi;
+
+use crate::{ROMol, SubstructMatchParameters};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SubstructLibraryError {
+    #[error("could not read or write the library file")]
+    Io(#[from] std::io::Error),
+    #[error("could not (de)serialize the library")]
+    Pickle(#[from] cxx::Exception),
+}
+
+/// A collection of molecules for repeated substructure searches. Molecules
+/// are stored as SMILES next to their pattern fingerprint, which screens out
+/// most non-matching molecules before a full match is attempted.
+pub struct SubstructLibrary {
+    pub(crate) ptr: SharedPtr<substruct_library_ffi::SubstructLibrary>,
+}
+
+impl Default for SubstructLibrary {
+    fn default() -> Self {
+        SubstructLibrary::new()
+    }
+}
+
+impl SubstructLibrary {
+    pub fn new() -> Self {
+        SubstructLibrary {
+            ptr: substruct_library_ffi::new_substruct_library(),
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SubstructLibraryError> {
+        let ptr = substruct_library_ffi::new_substruct_library_from_pickle(bytes)?;
+        Ok(SubstructLibrary { ptr })
+    }
+
+    /// Requires an RDKit built with boost serialization
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SubstructLibraryError> {
+        Ok(substruct_library_ffi::substruct_library_pickle(&self.ptr)?)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, SubstructLibraryError> {
+        let bytes = std::fs::read(path)?;
+        SubstructLibrary::from_bytes(&bytes)
+    }
+
+    pub fn to_file(&self, path: impl AsRef<Path>) -> Result<(), SubstructLibraryError> {
+        std::fs::write(path, self.to_bytes()?)?;
+        Ok(())
+    }
+
+    /// Returns the index of the molecule in the library
+    pub fn add_mol(&mut self, mol: &ROMol) -> Result<u32, cxx::Exception> {
+        substruct_library_ffi::substruct_library_add_mol(&mut self.ptr, &mol.ptr)
+    }
+
+    pub fn len(&self) -> u32 {
+        substruct_library_ffi::substruct_library_size(&self.ptr)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Fails if `idx` is out of range
+    pub fn get_mol(&self, idx: u32) -> Result<ROMol, cxx::Exception> {
+        let ptr = substruct_library_ffi::substruct_library_get_mol(&self.ptr, idx)?;
+        Ok(ROMol { ptr })
+    }
+
+    /// Indices of the molecules containing `query`. `max_results` of `None`
+    /// returns all of them, `num_threads` of `None` uses every core.
+    pub fn get_matches(
+        &self,
+        query: &ROMol,
+        params: &SubstructMatchParameters,
+        max_results: Option<u32>,
+        num_threads: Option<u32>,
+    ) -> Result<Vec<u32>, cxx::Exception> {
+        let max_results = max_results.map_or(-1, |max| i32::try_from(max).unwrap_or(i32::MAX));
+        let matches = substruct_library_ffi::substruct_library_get_matches(
+            &self.ptr,
+            &query.ptr,
+            &params.ptr,
+            threads(num_threads),
+            max_results,
+        )?;
+        Ok(matches.iter().copied().collect())
+    }
+
+    pub fn count_matches(
+        &self,
+        query: &ROMol,
+        params: &SubstructMatchParameters,
+        num_threads: Option<u32>,
+    ) -> Result<u32, cxx::Exception> {
+        substruct_library_ffi::substruct_library_count_matches(
+            &self.ptr,
+            &query.ptr,
+            &params.ptr,
+            threads(num_threads),
+        )
+    }
+
+    pub fn has_match(
+        &self,
+        query: &ROMol,
+        params: &SubstructMatchParameters,
+        num_threads: Option<u32>,
+    ) -> Result<bool, cxx::Exception> {
+        substruct_library_ffi::substruct_library_has_match(
+            &self.ptr,
+            &query.ptr,
+            &params.ptr,
+            threads(num_threads),
+        )
+    }
+}
+
+/// RDKit treats 0 as "all cores" and negative values as that many cores fewer
+fn threads(num_threads: Option<u32>) -> i32 {
+    num_threads.map_or(0, |num_threads| {
+        i32::try_from(num_threads).unwrap_or(i32::MAX)
+    })
+}
@@ -4,6 +4,9 @@ pub use substruct_match_item::*;
 mod substruct_match_parameters;
 pub use substruct_match_parameters::*;
 
+use cxx::{CxxVector, UniquePtr};
+use rdkit_sys::substruct_match_ffi::{self as ffi, MatchVectType};
+
 use crate::ROMol;
 
 pub fn substruct_match(
@@ -11,8 +14,46 @@ pub fn substruct_match(
     query: &ROMol,
     params: &SubstructMatchParameters,
 ) -> Vec<Vec<SubstructMatchItem>> {
-    let matches =
-        rdkit_sys::substruct_match_ffi::substruct_match(&mol.ptr, &query.ptr, &params.ptr);
+    match_items(rdkit_sys::substruct_match_ffi::substruct_match(
+        &mol.ptr,
+        &query.ptr,
+        &params.ptr,
+    ))
+}
+
+/// Stops at the first match instead of collecting them all
+pub fn has_substruct_match(mol: &ROMol, query: &ROMol, params: &SubstructMatchParameters) -> bool {
+    rdkit_sys::substruct_match_ffi::has_substruct_match(&mol.ptr, &query.ptr, &params.ptr)
+}
+
+/// Number of matches. Unlike [`substruct_match`] this isn't limited by the
+/// parameters' `max_matches`. The matches are still collected on the C++ side
+/// so they can be uniquified, memory use grows with the number of matches.
+pub fn count_substruct_matches(
+    mol: &ROMol,
+    query: &ROMol,
+    params: &SubstructMatchParameters,
+) -> u32 {
+    rdkit_sys::substruct_match_ffi::count_substruct_matches(&mol.ptr, &query.ptr, &params.ptr)
+}
+
+/// The first match as `(query_atom_idx, mol_atom_idx)` pairs, `None` if the
+/// query doesn't match
+pub fn substruct_match_first(
+    mol: &ROMol,
+    query: &ROMol,
+    params: &SubstructMatchParameters,
+) -> Option<Vec<(u32, u32)>> {
+    first_match(rdkit_sys::substruct_match_ffi::substruct_match_first(
+        &mol.ptr,
+        &query.ptr,
+        &params.ptr,
+    ))
+}
+
+pub(crate) fn match_items(
+    matches: UniquePtr<CxxVector<MatchVectType>>,
+) -> Vec<Vec<SubstructMatchItem>> {
     matches
         .into_iter()
         .map(|x| {
@@ -23,3 +64,18 @@ pub fn substruct_match(
         })
         .collect()
 }
+
+pub(crate) fn first_match(
+    items: UniquePtr<CxxVector<ffi::SubstructMatchItem>>,
+) -> Option<Vec<(u32, u32)>> {
+    if items.is_empty() {
+        return None;
+    }
+    Some(
+        items
+            .iter()
+            .map(SubstructMatchItem::from)
+            .map(|item| (item.query_atom_idx as u32, item.mol_atom_idx as u32))
+            .collect(),
+    )
+}
@@ -1,5 +1,7 @@
 use cxx::SharedPtr;
-use rdkit_sys::substruct_match_ffi::new_substruct_match_parameters;
+use rdkit_sys::{substruct_match_ffi::new_substruct_match_parameters, SubstructFinalCheck};
+
+use crate::ROMol;
 
 pub struct SubstructMatchParameters {
     pub ptr: SharedPtr<rdkit_sys::substruct_match_ffi::SubstructMatchParameters>,
@@ -47,37 +49,67 @@ impl SubstructMatchParameters {
         rdkit_sys::substruct_match_ffi::get_uniquify(&self.ptr)
     }
 
-    pub fn use_chirality(mut self, what: bool) -> SubstructMatchParameters{
+    pub fn get_max_matches(&self) -> u32 {
+        rdkit_sys::substruct_match_ffi::get_max_matches(&self.ptr)
+    }
+
+    pub fn get_atom_properties(&self) -> Vec<String> {
+        rdkit_sys::substruct_match_ffi::get_atom_properties(&self.ptr)
+            .iter()
+            .map(|prop| prop.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    pub fn get_bond_properties(&self) -> Vec<String> {
+        rdkit_sys::substruct_match_ffi::get_bond_properties(&self.ptr)
+            .iter()
+            .map(|prop| prop.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    pub fn get_specified_stereo_query_matches_unspecified(&self) -> bool {
+        rdkit_sys::substruct_match_ffi::get_specified_stereo_query_matches_unspecified(&self.ptr)
+    }
+
+    pub fn get_max_recursive_matches(&self) -> u32 {
+        rdkit_sys::substruct_match_ffi::get_max_recursive_matches(&self.ptr)
+    }
+
+    pub fn get_num_threads(&self) -> i32 {
+        rdkit_sys::substruct_match_ffi::get_num_threads(&self.ptr)
+    }
+
+    pub fn use_chirality(mut self, what: bool) -> SubstructMatchParameters {
         rdkit_sys::substruct_match_ffi::set_use_chirality(&mut self.ptr, what);
         self
     }
 
-    pub fn use_enhanced_stereo(mut self, what: bool) -> SubstructMatchParameters{
+    pub fn use_enhanced_stereo(mut self, what: bool) -> SubstructMatchParameters {
         rdkit_sys::substruct_match_ffi::set_use_enhanced_stereo(&mut self.ptr, what);
         self
     }
 
-    pub fn aromatic_matches_conjugated(mut self, what: bool) -> SubstructMatchParameters{
+    pub fn aromatic_matches_conjugated(mut self, what: bool) -> SubstructMatchParameters {
         rdkit_sys::substruct_match_ffi::set_aromatic_matches_conjugated(&mut self.ptr, what);
         self
     }
 
-    pub fn use_query_query_matches(mut self, what: bool) -> SubstructMatchParameters{
+    pub fn use_query_query_matches(mut self, what: bool) -> SubstructMatchParameters {
         rdkit_sys::substruct_match_ffi::set_use_query_query_matches(&mut self.ptr, what);
         self
     }
 
-    pub fn use_generic_matchers(mut self, what: bool) -> SubstructMatchParameters{
+    pub fn use_generic_matchers(mut self, what: bool) -> SubstructMatchParameters {
         rdkit_sys::substruct_match_ffi::set_use_generic_matchers(&mut self.ptr, what);
         self
     }
 
-    pub fn recursion_possible(mut self, what: bool) -> SubstructMatchParameters{
+    pub fn recursion_possible(mut self, what: bool) -> SubstructMatchParameters {
         rdkit_sys::substruct_match_ffi::set_recursion_possible(&mut self.ptr, what);
         self
     }
 
-    pub fn uniquify(mut self, what: bool) -> SubstructMatchParameters{
+    pub fn uniquify(mut self, what: bool) -> SubstructMatchParameters {
         rdkit_sys::substruct_match_ffi::set_uniquify(&mut self.ptr, what);
         self
     }
@@ -86,4 +118,64 @@ impl SubstructMatchParameters {
         rdkit_sys::substruct_match_ffi::set_max_matches(&mut self.ptr, value);
         self
     }
+
+    /// Atoms only match if they have the same value for each of these
+    /// properties as the query atom
+    pub fn atom_properties(mut self, props: &[&str]) -> SubstructMatchParameters {
+        let props = props.iter().map(|prop| prop.to_string()).collect();
+        rdkit_sys::substruct_match_ffi::set_atom_properties(&mut self.ptr, &props);
+        self
+    }
+
+    /// Bonds only match if they have the same value for each of these
+    /// properties as the query bond
+    pub fn bond_properties(mut self, props: &[&str]) -> SubstructMatchParameters {
+        let props = props.iter().map(|prop| prop.to_string()).collect();
+        rdkit_sys::substruct_match_ffi::set_bond_properties(&mut self.ptr, &props);
+        self
+    }
+
+    /// With chirality enabled, let query atoms and bonds with specified
+    /// stereo match unspecified stereo in the molecule
+    pub fn specified_stereo_query_matches_unspecified(
+        mut self,
+        what: bool,
+    ) -> SubstructMatchParameters {
+        rdkit_sys::substruct_match_ffi::set_specified_stereo_query_matches_unspecified(
+            &mut self.ptr,
+            what,
+        );
+        self
+    }
+
+    /// Limits the matches tried for each recursive SMARTS query
+    pub fn max_recursive_matches(mut self, value: u32) -> SubstructMatchParameters {
+        rdkit_sys::substruct_match_ffi::set_max_recursive_matches(&mut self.ptr, value);
+        self
+    }
+
+    /// Threads used when matching against a [`crate::ResonanceMolSupplier`].
+    /// 0 uses every core, negative values are subtracted from the number of
+    /// cores.
+    pub fn num_threads(mut self, value: i32) -> SubstructMatchParameters {
+        rdkit_sys::substruct_match_ffi::set_num_threads(&mut self.ptr, value);
+        self
+    }
+
+    /// Runs `check` on every match found, with the matched atom indices in
+    /// query atom order, and drops the match if it returns `false`. Useful
+    /// for geometric or property constraints SMARTS can't express. Use
+    /// [`ROMol::with_atom`] to inspect the matched atoms. A panic in `check`
+    /// rejects the match.
+    pub fn extra_final_check<F>(mut self, check: F) -> SubstructMatchParameters
+    where
+        F: Fn(&ROMol, &[u32]) -> bool + Send + Sync + 'static,
+    {
+        let check = SubstructFinalCheck(Box::new(move |mol, atom_indices| {
+            let mol = ROMol { ptr: mol.clone() };
+            check(&mol, atom_indices)
+        }));
+        rdkit_sys::substruct_match_ffi::set_extra_final_check(&mut self.ptr, Box::new(check));
+        self
+    }
 }
@@ -0,0 +1,61 @@
+use rdkit::{
+    delete_substructs, replace_core, replace_sidechains, replace_substructs, ROMol,
+    ReplaceCoreOptions, SubstructMatchParameters,
+};
+
+#[test]
+fn test_delete_substructs() {
+    let params = SubstructMatchParameters::new();
+    let mol = ROMol::from_smiles("CC(=O)[O-].[Na+]").unwrap();
+    let salt = ROMol::from_smarts("[Na+]").unwrap();
+    let stripped = delete_substructs(&mol, &salt, true, &params).unwrap();
+    assert_eq!(stripped.as_smiles(), "CC(=O)[O-]");
+
+    let mol = ROMol::from_smiles("CCOC(C)=O").unwrap();
+    let ethyl = ROMol::from_smarts("[CH3][CH2]").unwrap();
+    assert_eq!(
+        delete_substructs(&mol, &ethyl, true, &params)
+            .unwrap()
+            .as_smiles(),
+        "CCOC(C)=O"
+    );
+}
+
+#[test]
+fn test_replace_substructs() {
+    let params = SubstructMatchParameters::new();
+    let mol = ROMol::from_smiles("OCCO").unwrap();
+    let query = ROMol::from_smarts("[OH]").unwrap();
+    let replacement = ROMol::from_smiles("N").unwrap();
+
+    let products = replace_substructs(&mol, &query, &replacement, false, 0, &params).unwrap();
+    assert_eq!(products.len(), 2);
+    assert_eq!(products[0].as_smiles(), "NCCO");
+
+    let products = replace_substructs(&mol, &query, &replacement, true, 0, &params).unwrap();
+    assert_eq!(products.len(), 1);
+    assert_eq!(products[0].as_smiles(), "NCCN");
+}
+
+#[test]
+fn test_replace_sidechains_and_core() {
+    let params = SubstructMatchParameters::new();
+    let mol = ROMol::from_smiles("Oc1ccccc1C").unwrap();
+    let core = ROMol::from_smiles("c1ccccc1").unwrap();
+
+    let sidechains = replace_core(&mol, &core, &ReplaceCoreOptions::default(), &params)
+        .unwrap()
+        .unwrap();
+    assert_eq!(sidechains.as_smiles(), "[1*]O.[2*]C");
+
+    let scaffold = replace_sidechains(&mol, &core, &params).unwrap().unwrap();
+    assert_eq!(scaffold.as_smiles(), "[1*]c1ccccc1[2*]");
+
+    let core = ROMol::from_smiles("C1CCCCC1").unwrap();
+    assert!(
+        replace_core(&mol, &core, &ReplaceCoreOptions::default(), &params)
+            .unwrap()
+            .is_none()
+    );
+    assert!(replace_sidechains(&mol, &core, &params).unwrap().is_none());
+}
@@ -0,0 +1,70 @@
+use rdkit::{
+    bertz_ct, estate_indices, ipc, wiener_index, DescriptorBlock, DescriptorCalculator, ROMol,
+};
+
+#[test]
+fn test_block_names() {
+    for block in DescriptorBlock::ALL {
+        assert_eq!(block.value_names().len(), block.num_values());
+    }
+    assert_eq!(DescriptorBlock::Mqn.value_names()[0], "MQN1");
+    assert_eq!(DescriptorBlock::SlogPVsa.value_names()[11], "SlogP_VSA12");
+    assert_eq!(DescriptorBlock::BertzCt.value_names(), vec!["BertzCT"]);
+}
+
+#[test]
+fn test_descriptor_calculator() {
+    let mol = ROMol::from_smiles("CC(=O)Oc1ccccc1C(=O)O").unwrap();
+    let calculator = DescriptorCalculator::default();
+
+    let blocks = calculator.compute(&mol).unwrap();
+    assert_eq!(blocks.len(), DescriptorBlock::ALL.len());
+    for block in &blocks {
+        assert_eq!(block.values.len(), block.block.num_values());
+        assert_eq!(block.names, block.block.value_names());
+    }
+
+    let values = calculator.compute_values(&mol).unwrap();
+    assert_eq!(values.len(), calculator.names().len());
+
+    let mqn = DescriptorBlock::Mqn.compute(&mol).unwrap();
+    // MQN1 counts carbons, MQN10 acyclic oxygens
+    assert_eq!(mqn[0], 9.0);
+    assert_eq!(mqn[9], 4.0);
+}
+
+#[test]
+fn test_estate_vsa_sums_to_labute_asa() {
+    let mol = ROMol::from_smiles("CC(=O)Oc1ccccc1C(=O)O").unwrap();
+    let estate_vsa = DescriptorBlock::EStateVsa.compute(&mol).unwrap();
+    let labute = mol.labute_asa_contribs(true);
+    let atoms_asa = labute.atoms.iter().sum::<f64>();
+    assert!((estate_vsa.iter().sum::<f64>() - atoms_asa).abs() < 1e-6);
+}
+
+#[test]
+fn test_topological_indices() {
+    let butane = ROMol::from_smiles("CCCC").unwrap();
+    assert_eq!(wiener_index(&butane), 10.0);
+
+    let ethane = ROMol::from_smiles("CC").unwrap();
+    assert_eq!(ipc(&ethane), 2.0);
+    assert_eq!(bertz_ct(&ethane), 0.0);
+
+    let benzene = ROMol::from_smiles("c1ccccc1").unwrap();
+    assert!((bertz_ct(&benzene) - 71.96100505779535).abs() < 1e-6);
+
+    // two fragments don't contribute to each other
+    let methanes = ROMol::from_smiles("C.C").unwrap();
+    assert_eq!(wiener_index(&methanes), 0.0);
+}
+
+#[test]
+fn test_estate_indices() {
+    let mol = ROMol::from_smiles("CCO").unwrap();
+    let estates = estate_indices(&mol);
+    assert_eq!(estates.len(), 3);
+    // hydroxyl oxygen is the most electronegative atom
+    assert!(estates[2] > estates[0]);
+    assert!(estates[2] > estates[1]);
+}
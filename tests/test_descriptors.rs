@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
-use rdkit::{Properties, ROMol};
+use rdkit::{
+    gasteiger_charges, tpsa_contribs, Descriptor, MoleculeProperties, Properties, PropertiesError,
+    ROMol,
+};
 
 #[test]
 fn test_a_thing() {
@@ -83,7 +86,7 @@ fn test_computing_properties() {
 #[test]
 fn test_molecule_properties() {
     let mol = ROMol::from_smiles("CCOC(=O)C(C)(C)OC1=CC=C(C=C1)Cl.CO.C1=CC(=CC=C1C(=O)N[C@@H](CCC(=O)O)C(=O)O)NCC2=CN=C3C(=N2)C(=O)NC(=N3)N").unwrap();
-    let props = mol.properties();
+    let props = mol.properties().unwrap();
 
     assert_eq!(props.num_atoms, 88);
     assert_eq!(props.chi1n, 14.854760794353165);
@@ -128,4 +131,114 @@ fn test_molecule_properties() {
     assert_eq!(props.num_spiro_atoms, 0);
     assert_eq!(props.hall_kier_alpha, -5.8100000000000005);
     assert_eq!(props.labute_asa, 290.3869834026883);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_properties_with_names() {
+    let mol = ROMol::from_smiles("c1ccccc1C(=O)NC").unwrap();
+    let properties = Properties::with_names(&["tpsa", "NumAtoms"]).unwrap();
+    assert_eq!(properties.names(), vec!["tpsa", "NumAtoms"]);
+
+    let computed = properties.compute_properties(&mol);
+    assert_eq!(computed.len(), 2);
+    assert_eq!(*computed.get("NumAtoms").unwrap(), 19.0);
+    assert_eq!(format!("{:.1}", computed.get("tpsa").unwrap()), "29.1");
+
+    assert_eq!(
+        Properties::with_names(&["NotADescriptor"]).err(),
+        Some(PropertiesError::UnknownDescriptor(
+            "NotADescriptor".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_properties_with_descriptors() {
+    let available = Properties::available_names();
+    for descriptor in Descriptor::ALL {
+        assert!(available.contains(&descriptor.name().to_string()));
+        assert_eq!(descriptor.name().parse::<Descriptor>(), Ok(descriptor));
+    }
+
+    let properties =
+        Properties::with_descriptors(&[Descriptor::CrippenClogP, Descriptor::Kappa1]).unwrap();
+    assert_eq!(properties.names(), vec!["CrippenClogP", "kappa1"]);
+}
+
+#[test]
+fn test_molecule_properties_from_map() {
+    let mol = ROMol::from_smiles("c1ccccc1C(=O)NC").unwrap();
+    let properties = Properties::with_descriptors(&Descriptor::ALL).unwrap();
+    let mut computed = properties.compute_properties(&mol);
+
+    let props = MoleculeProperties::try_from(computed.clone()).unwrap();
+    assert_eq!(props, mol.properties().unwrap());
+    assert_eq!(props.num_atoms, 19);
+
+    computed.insert("NotADescriptor".to_string(), 1.0);
+    assert_eq!(
+        MoleculeProperties::try_from(computed.clone()).err(),
+        Some(PropertiesError::UnexpectedDescriptor(
+            "NotADescriptor".to_string()
+        ))
+    );
+
+    computed.remove("tpsa");
+    assert_eq!(
+        MoleculeProperties::try_from(computed).err(),
+        Some(PropertiesError::MissingDescriptor(Descriptor::Tpsa))
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_molecule_properties_serde() {
+    let mol = ROMol::from_smiles("c1ccccc1C(=O)NC").unwrap();
+    let props = mol.properties().unwrap();
+
+    let json = serde_json::to_string(&props).unwrap();
+    let deserialized: MoleculeProperties = serde_json::from_str(&json).unwrap();
+    assert_eq!(props, deserialized);
+}
+
+#[test]
+fn test_atom_contributions() {
+    let mut mol = ROMol::from_smiles("CC(=O)Oc1ccccc1C(=O)O").unwrap();
+    let props = mol.properties().unwrap();
+    let num_atoms = mol.num_atoms(true) as usize;
+
+    let crippen = mol.crippen_contribs();
+    assert_eq!(crippen.logp.len(), num_atoms);
+    assert_eq!(crippen.atom_types.len(), num_atoms);
+    assert!((crippen.logp.iter().sum::<f64>() - props.crippen_clog_p).abs() < 1e-6);
+    assert!((crippen.mr.iter().sum::<f64>() - props.crippen_mr).abs() < 1e-6);
+
+    let tpsa = tpsa_contribs(&mol, false);
+    assert_eq!(tpsa.len(), num_atoms);
+    assert_eq!(tpsa[0], 0.0);
+    assert!((tpsa.iter().sum::<f64>() - props.tpsa).abs() < 1e-6);
+
+    let labute = mol.labute_asa_contribs(true);
+    assert_eq!(labute.atoms.len(), num_atoms);
+    assert!((labute.atoms.iter().sum::<f64>() + labute.hydrogens - props.labute_asa).abs() < 1e-6);
+
+    let charges = gasteiger_charges(&mol, 12, false).unwrap();
+    assert_eq!(charges.len(), num_atoms);
+    assert!(charges.iter().sum::<f64>().abs() < 1e-6);
+    assert!(charges[2] < 0.0);
+
+    mol.set_atom_props("crippen_logp", &crippen.logp);
+    let atom = mol.atom_with_idx(0);
+    assert_eq!(
+        atom.get_float_prop("crippen_logp").unwrap(),
+        crippen.logp[0]
+    );
+    assert_eq!(atom.get_float_prop("_GasteigerCharge").unwrap(), charges[0]);
+}
+
+#[test]
+fn test_crippen_atom_types() {
+    let mol = ROMol::from_smiles("CCO").unwrap();
+    let crippen = mol.crippen_contribs();
+    assert_eq!(crippen.atom_types, vec!["C1", "C3", "O2"]);
+}
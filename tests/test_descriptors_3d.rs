@@ -0,0 +1,57 @@
+use rdkit::{autocorr_3d, getaway, morse, rdf, whim, Descriptors3D, RWMol};
+
+const PROPANOL_3D: &str = r#"
+     RDKit          3D
+
+  4  3  0  0  0  0  0  0  0  0999 V2000
+    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0
+    1.5200    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0
+    2.0300    1.4300    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0
+    3.4300    1.5000    0.8000 O   0  0  0  0  0  0  0  0  0  0  0  0
+  1  2  1  0
+  2  3  1  0
+  3  4  1  0
+M  END
+"#;
+
+#[test]
+fn test_descriptors_3d() {
+    let mol = RWMol::from_mol_block(PROPANOL_3D, true, true, false)
+        .unwrap()
+        .to_ro_mol();
+    assert_eq!(mol.num_conformers(), 1);
+
+    let descriptors = mol.descriptors_3d(-1).unwrap();
+    assert!(descriptors.pmi1 <= descriptors.pmi2);
+    assert!(descriptors.pmi2 <= descriptors.pmi3);
+    assert!((descriptors.npr1 - descriptors.pmi1 / descriptors.pmi3).abs() < 1e-6);
+    assert!((descriptors.npr2 - descriptors.pmi2 / descriptors.pmi3).abs() < 1e-6);
+    assert!(descriptors.npr1 + descriptors.npr2 >= 1.0 - 1e-6);
+    assert!(descriptors.radius_of_gyration > 0.0);
+    assert!(descriptors.pbf > 0.0);
+
+    let unweighted = Descriptors3D::from_molecule(&mol, -1, false).unwrap();
+    assert_ne!(unweighted.pmi1, descriptors.pmi1);
+}
+
+#[test]
+fn test_descriptors_3d_missing_conformer() {
+    let mol = RWMol::from_mol_block(PROPANOL_3D, true, true, false)
+        .unwrap()
+        .to_ro_mol();
+    assert!(mol.descriptors_3d(7).is_err());
+    assert!(rdf(&mol, 7).is_err());
+}
+
+#[test]
+fn test_vector_descriptors_3d() {
+    let mol = RWMol::from_mol_block(PROPANOL_3D, true, true, false)
+        .unwrap()
+        .to_ro_mol();
+
+    assert_eq!(autocorr_3d(&mol, -1).unwrap().len(), 80);
+    assert_eq!(rdf(&mol, -1).unwrap().len(), 210);
+    assert_eq!(morse(&mol, -1).unwrap().len(), 224);
+    assert_eq!(whim(&mol, -1, 0.001).unwrap().len(), 114);
+    assert_eq!(getaway(&mol, -1, 2).unwrap().len(), 273);
+}
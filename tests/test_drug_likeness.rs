@@ -0,0 +1,80 @@
+use rdkit::{qed, qed_unweighted, DrugLikenessRule, DrugLikenessRules, QedProperties, ROMol};
+
+#[test]
+fn test_qed_from_properties() {
+    let props = QedProperties {
+        mw: 180.159,
+        alogp: 1.3101,
+        hba: 4.0,
+        hbd: 1.0,
+        psa: 63.6,
+        rotb: 2.0,
+        arom: 1.0,
+        alerts: 2.0,
+    };
+
+    assert_eq!(
+        format!("{:.6}", props.qed(&QedProperties::WEIGHT_MEAN)),
+        "0.519089"
+    );
+    assert_eq!(
+        format!("{:.6}", props.qed(&QedProperties::WEIGHT_NONE)),
+        "0.635172"
+    );
+
+    let desirabilities = props.desirabilities().to_array();
+    assert!(desirabilities.iter().all(|d| *d > 0.0 && *d <= 1.0));
+}
+
+#[test]
+fn test_qed() {
+    let aspirin = ROMol::from_smiles("CC(=O)Oc1ccccc1C(=O)O").unwrap();
+    let props = QedProperties::from_molecule(&aspirin).unwrap();
+    assert_eq!(props.hbd, 1.0);
+    assert_eq!(props.arom, 1.0);
+    assert!(props.alerts >= 1.0);
+
+    let weighted = qed(&aspirin).unwrap();
+    assert_eq!(weighted, props.qed(&QedProperties::WEIGHT_MEAN));
+    assert!(weighted > 0.0 && weighted < 1.0);
+
+    let unweighted = qed_unweighted(&aspirin).unwrap();
+    assert_eq!(unweighted, props.qed(&QedProperties::WEIGHT_NONE));
+}
+
+#[test]
+fn test_drug_likeness_rules() {
+    let aspirin = ROMol::from_smiles("CC(=O)Oc1ccccc1C(=O)O").unwrap();
+    let evaluations = DrugLikenessRules::new()
+        .evaluate_molecule(&aspirin)
+        .unwrap();
+    assert_eq!(evaluations.len(), 5);
+
+    let lipinski = &evaluations[0];
+    assert_eq!(lipinski.rule, DrugLikenessRule::Lipinski);
+    assert!(lipinski.passed);
+    assert!(lipinski.violations.is_empty());
+
+    let muegge = &evaluations[4];
+    assert_eq!(muegge.rule, DrugLikenessRule::Muegge);
+    assert!(!muegge.passed);
+    assert_eq!(muegge.violations.len(), 1);
+    assert_eq!(muegge.violations[0].property, "exact_mw");
+    assert_eq!(muegge.violations[0].min, Some(200.0));
+}
+
+#[test]
+fn test_drug_likeness_rules_with_dummy_atoms() {
+    // RDKit counts the dummy atoms as heteroatoms but not as heavy atoms
+    let mol = ROMol::from_smiles("*C(*)*").unwrap();
+    let evaluation = DrugLikenessRules::with_rules(&[DrugLikenessRule::Muegge])
+        .evaluate_molecule(&mol)
+        .unwrap();
+
+    let carbons = evaluation[0]
+        .violations
+        .iter()
+        .find(|violation| violation.property == "num_carbons")
+        .unwrap();
+    assert_eq!(carbons.value, 0.0);
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "ndarray")]
+
+use rdkit::{
+    descriptor_block_matrix, descriptor_matrix, fingerprint_matrix, fingerprint_matrix_f32,
+    Descriptor, DescriptorBlock, DescriptorCalculator, FingerprintKind, Properties, ROMol,
+};
+
+fn molecules() -> Vec<ROMol> {
+    ["CCO", "c1ccccc1", "CC(=O)Oc1ccccc1C(=O)O"]
+        .iter()
+        .map(|smiles| ROMol::from_smiles(smiles).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_fingerprint_matrix() {
+    let mols = molecules();
+    let matrix = fingerprint_matrix(&mols, FingerprintKind::Morgan);
+    let n_bits = mols[0].morgan_fingerprint().0.len();
+
+    assert_eq!(matrix.features.shape(), &[3, n_bits]);
+    assert!(matrix.errors.is_empty());
+
+    let expected = mols[1].morgan_fingerprint().0.count_ones();
+    let ones = matrix
+        .features
+        .row(1)
+        .iter()
+        .filter(|bit| **bit == 1)
+        .count();
+    assert_eq!(ones, expected);
+
+    let matrix_f32 = fingerprint_matrix_f32(&mols, FingerprintKind::Morgan);
+    assert_eq!(
+        matrix_f32.features.sum() as usize,
+        matrix.features.iter().filter(|bit| **bit == 1).count()
+    );
+}
+
+#[test]
+fn test_descriptor_matrix() {
+    let mols = molecules();
+    let properties =
+        Properties::with_descriptors(&[Descriptor::NumAtoms, Descriptor::Tpsa]).unwrap();
+    let matrix = descriptor_matrix(&mols, &properties);
+
+    assert_eq!(matrix.features.shape(), &[3, 2]);
+    assert_eq!(matrix.columns, properties.names());
+    assert!(matrix.errors.is_empty());
+
+    let num_atoms = matrix.columns.iter().position(|c| c == "NumAtoms").unwrap();
+    assert_eq!(matrix.features[[0, num_atoms]], 9.0);
+}
+
+#[test]
+fn test_descriptor_block_matrix() {
+    let mols = molecules();
+    let calculator = DescriptorCalculator::new(&[DescriptorBlock::Mqn, DescriptorBlock::BertzCt]);
+    let matrix = descriptor_block_matrix(&mols, &calculator);
+
+    assert_eq!(matrix.features.shape(), &[3, 43]);
+    assert_eq!(matrix.columns[42], "BertzCT");
+    assert!(matrix.failed_rows().is_empty());
+    assert_eq!(matrix.features[[2, 0]], 9.0);
+}
@@ -0,0 +1,60 @@
+use rdkit::{FormulaElement, FormulaError, MolecularFormula, ROMol};
+
+#[test]
+fn test_formula() {
+    let aspirin = ROMol::from_smiles("CC(=O)Oc1ccccc1C(=O)O").unwrap();
+    assert_eq!(aspirin.formula(), "C9H8O4");
+    assert_eq!(aspirin.formal_charge(), 0);
+    assert_eq!(aspirin.nominal_mass().unwrap(), 180);
+    assert_eq!(format!("{:.4}", aspirin.monoisotopic_mass()), "180.0423");
+
+    let labelled = ROMol::from_smiles("[13CH3]C([2H])O").unwrap();
+    assert_eq!(labelled.formula_with_options(true, true), "C[13C]H5DO");
+    assert_eq!(labelled.formula_with_options(true, false), "C[13C]H5[2H]O");
+    assert_eq!(labelled.nominal_mass().unwrap(), 48);
+
+    let charged = ROMol::from_smiles("C[NH3+]").unwrap();
+    assert_eq!(charged.formal_charge(), 1);
+    assert_eq!(charged.molecular_formula().unwrap().charge, 1);
+}
+
+#[test]
+fn test_parse_formula() {
+    let formula = MolecularFormula::parse("C[13C]H5DO-2").unwrap();
+    assert_eq!(formula.charge, -2);
+    assert_eq!(
+        formula.elements[1],
+        FormulaElement {
+            symbol: "C".to_string(),
+            atomic_number: 6,
+            isotope: Some(13),
+            count: 1,
+        }
+    );
+    assert_eq!(formula.elements[3].isotope, Some(2));
+    assert_eq!(formula.elements[3].atomic_number, 1);
+
+    assert_eq!(
+        MolecularFormula::parse("c1ccccc1"),
+        Err(FormulaError::Parse("c1ccccc1".to_string()))
+    );
+    assert_eq!(
+        MolecularFormula::parse("C2Xx"),
+        Err(FormulaError::UnknownElement("Xx".to_string()))
+    );
+}
+
+#[test]
+fn test_isotope_pattern() {
+    let aspirin = ROMol::from_smiles("CC(=O)Oc1ccccc1C(=O)O").unwrap();
+    let pattern = aspirin.isotope_pattern(0.0).unwrap();
+
+    let total = pattern.iter().map(|peak| peak.abundance).sum::<f64>();
+    assert_eq!(format!("{:.6}", total), "1.000000");
+    assert_eq!(format!("{:.3}", pattern[0].mass), "180.042");
+    assert!(pattern[0].abundance > pattern[1].abundance);
+    assert_eq!(format!("{:.0}", pattern[1].mass), "181");
+
+    let filtered = aspirin.isotope_pattern(0.01).unwrap();
+    assert!(filtered.len() < pattern.len());
+}
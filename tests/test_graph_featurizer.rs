@@ -0,0 +1,67 @@
+use rdkit::{AtomFeature, BondFeature, BondType, GraphFeaturizer, ROMol};
+
+#[test]
+fn test_default_featurizer() {
+    let mol = ROMol::from_smiles("C[C@H](N)c1ccccc1").unwrap();
+    let featurizer = GraphFeaturizer::default();
+    let graph = featurizer.featurize(&mol).unwrap();
+
+    assert_eq!(graph.num_atoms(), 9);
+    assert_eq!(graph.num_edges(), 18);
+    assert_eq!(graph.bond_features.len(), 18);
+    for row in &graph.atom_features {
+        assert_eq!(row.len(), featurizer.atom_feature_len());
+        // all features are one-hot encoded or flags
+        assert!(row.iter().all(|v| *v == 0.0 || *v == 1.0));
+    }
+    for row in &graph.bond_features {
+        assert_eq!(row.len(), featurizer.bond_feature_len());
+    }
+
+    // edges come in both directions
+    assert_eq!(graph.edge_index[0][0], graph.edge_index[1][1]);
+    assert_eq!(graph.edge_index[1][0], graph.edge_index[0][1]);
+}
+
+#[test]
+fn test_custom_featurizer() {
+    let mol = ROMol::from_smiles("C1CC1Br").unwrap();
+    let featurizer = GraphFeaturizer::new(
+        vec![AtomFeature::AtomicNum(vec![6, 7]), AtomFeature::InRing],
+        vec![
+            BondFeature::BondType(vec![BondType::SINGLE]),
+            BondFeature::InRing,
+        ],
+    );
+    assert_eq!(featurizer.atom_feature_len(), 4);
+    assert_eq!(featurizer.bond_feature_len(), 3);
+
+    let graph = featurizer.featurize(&mol).unwrap();
+    assert_eq!(graph.atom_features[0], vec![1.0, 0.0, 0.0, 1.0]);
+    // bromine falls into the trailing "other" slot and is not in a ring
+    assert_eq!(graph.atom_features[3], vec![0.0, 0.0, 1.0, 0.0]);
+
+    // the C-Br bond is the last bond, listed twice
+    assert_eq!(graph.bond_features[0], vec![1.0, 0.0, 1.0]);
+    assert_eq!(graph.bond_features[7], vec![1.0, 0.0, 0.0]);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_graph_arrays() {
+    let mol = ROMol::from_smiles("CCO").unwrap();
+    let featurizer = GraphFeaturizer::default();
+    let graph = featurizer.featurize(&mol).unwrap();
+
+    assert_eq!(
+        graph.atom_feature_array().shape(),
+        &[3, featurizer.atom_feature_len()]
+    );
+    assert_eq!(graph.edge_index_array().shape(), &[2, 4]);
+    assert_eq!(
+        graph.bond_feature_array().shape(),
+        &[4, featurizer.bond_feature_len()]
+    );
+    assert_eq!(graph.edge_index_array()[[0, 0]], 0);
+    assert_eq!(graph.edge_index_array()[[1, 0]], 1);
+}
@@ -347,4 +347,21 @@ fn mol_has_substruct_match() {
     assert_eq!(mol.substruct_match(&query1, &SubstructMatchParameters::new()).len(), 1);
     assert_eq!(mol.substruct_match(&query2, &SubstructMatchParameters::new().use_chirality(true)).len(), 0);
     assert_eq!(mol.substruct_match(&query2, &SubstructMatchParameters::new().use_chirality(false)).len(), 1);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_bonds_and_distance_matrix() {
+    let mut mol = ROMol::from_smiles("C=CC").unwrap();
+    assert_eq!(mol.num_bonds(), 2);
+
+    let bond = mol.bond_with_idx(0);
+    assert_eq!(bond.get_begin_atom_idx(), 0);
+    assert_eq!(bond.get_end_atom_idx(), 1);
+    assert_eq!(bond.get_bond_type_as_double(), 2.0);
+    assert!(!bond.get_is_aromatic());
+
+    let distances = mol.distance_matrix(false);
+    assert_eq!(distances, vec![0.0, 1.0, 2.0, 1.0, 0.0, 1.0, 2.0, 1.0, 0.0]);
+    let distances = mol.distance_matrix(true);
+    assert_eq!(distances[1], 0.5);
+}
@@ -0,0 +1,53 @@
+use rdkit::{find_mcs, AtomComparator, McsParameters, ROMol, SubstructMatchParameters};
+
+fn from_smiles_list(smiles: &[&str]) -> Vec<ROMol> {
+    smiles
+        .iter()
+        .map(|smiles| ROMol::from_smiles(smiles).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_find_mcs() {
+    let mols = from_smiles_list(&["c1ccccc1CCO", "c1ccccc1CCN", "c1ccccc1CC(=O)O"]);
+    let result = find_mcs(&mols, &McsParameters::default()).unwrap();
+    assert_eq!(result.num_atoms, 8);
+    assert_eq!(result.num_bonds, 8);
+    assert!(!result.timed_out);
+    assert!(ROMol::from_smarts(&result.smarts).is_some());
+
+    let query = result.query_mol.unwrap();
+    let params = SubstructMatchParameters::new();
+    assert!(mols
+        .iter()
+        .all(|mol| mol.has_substruct_match(&query, &params)));
+}
+
+#[test]
+fn test_find_mcs_parameters() {
+    let mols = from_smiles_list(&["c1ccccc1CCO", "c1ccccc1CCN"]);
+    let params = McsParameters::new().atom_comparator(AtomComparator::AtomCompareAny);
+    let result = find_mcs(&mols, &params).unwrap();
+    assert_eq!(result.num_atoms, 9);
+
+    let params = McsParameters::new()
+        .ring_matches_ring_only(true)
+        .complete_rings_only(true)
+        .match_valences(true)
+        .match_chiral_tag(true)
+        .timeout(10)
+        .threshold(0.5);
+    assert!(params.get_ring_matches_ring_only());
+    assert!(params.get_complete_rings_only());
+    assert!(params.get_match_valences());
+    assert!(params.get_match_chiral_tag());
+    assert_eq!(params.get_timeout(), 10);
+    assert_eq!(params.get_threshold(), 0.5);
+
+    // ring atoms can't match the chain, leaving the ethyl group
+    let mols = from_smiles_list(&["C1CCCCC1CC", "CCCCCCCC"]);
+    let params = McsParameters::new().ring_matches_ring_only(true);
+    let result = find_mcs(&mols, &params).unwrap();
+    assert_eq!(result.num_atoms, 2);
+    assert_eq!(result.num_bonds, 1);
+}
@@ -0,0 +1,302 @@
+use std::time::Duration;
+
+use rdkit::{
+    charge_parent, isotope_parent, normalize, reionize, standardize, stereo_parent, super_parent,
+    tautomer_parent, CleanupParameters, CleanupParametersError, LargestFragmentChooser,
+    LargestFragmentChooserOptions, MetalDisconnector, MetalDisconnectorOptions, Normalizer, ROMol,
+    Reionizer, TautomerEnumerator, TautomerEnumeratorStatus,
+};
+
+#[test]
+fn test_normalizer() {
+    let mol = ROMol::from_smiles("C[N+](C)=C[C-](C)C").unwrap();
+    let normalized = Normalizer::new().normalize(&mol).unwrap();
+    assert_eq!(normalized.as_smiles(), "CN(C)C=C(C)C");
+
+    let mol = ROMol::from_smiles("CS(C)=O").unwrap();
+    let normalized = Normalizer::default().normalize(&mol).unwrap();
+    assert_eq!(normalized.as_smiles(), "C[S+](C)[O-]");
+}
+
+#[test]
+fn test_normalizer_custom_transforms() {
+    let normalizer = Normalizer::from_transforms(
+        &[(
+            "Sulfoxide to -S+(O-)",
+            "[S+0;D3:1]=[O+0;D1:2]>>[S+1:1]-[O-1:2]",
+        )],
+        200,
+    )
+    .unwrap();
+    let mol = ROMol::from_smiles("CS(C)=O").unwrap();
+    assert_eq!(
+        normalizer.normalize(&mol).unwrap().as_smiles(),
+        "C[S+](C)[O-]"
+    );
+
+    // only the given transforms are applied
+    let normalizer = Normalizer::from_transforms(&[("Cl to Br", "[Cl:1]>>[Br:1]")], 200).unwrap();
+    assert_eq!(normalizer.normalize(&mol).unwrap().as_smiles(), "CS(C)=O");
+
+    assert!(Normalizer::from_transforms(&[("broken", "not smirks")], 200).is_err());
+}
+
+#[test]
+fn test_reionizer() {
+    let mol = ROMol::from_smiles("C1=C(C=CC(=C1)[S]([O-])=O)[S](O)(=O)=O").unwrap();
+    let reionized = Reionizer::new().reionize(&mol).unwrap();
+    assert_eq!(reionized.as_smiles(), "O=S(O)c1ccc(S(=O)(=O)[O-])cc1");
+
+    let reionizer =
+        Reionizer::from_acid_base_pairs(&[("-SO3H", "OS(=O)(=O)[C,c]", "[O-]S(=O)(=O)[C,c]")])
+            .unwrap();
+    let reionized = reionizer.reionize(&mol).unwrap();
+    assert_eq!(reionized.formal_charge(), -1);
+}
+
+#[test]
+fn test_metal_disconnector() {
+    let mol = ROMol::from_smiles("CCC(=O)O[Na]").unwrap();
+    let disconnected = MetalDisconnector::default().disconnect(&mol).unwrap();
+    assert_eq!(disconnected.as_smiles(), "CCC(=O)[O-].[Na+]");
+
+    let options = MetalDisconnectorOptions {
+        split_grignards: true,
+        ..Default::default()
+    };
+    let mol = ROMol::from_smiles("CC[Mg]Br").unwrap();
+    let disconnected = MetalDisconnector::new(&options).disconnect(&mol).unwrap();
+    assert_eq!(disconnected.as_smiles(), "[Br-].[CH2-]C.[Mg+2]");
+}
+
+#[test]
+fn test_largest_fragment_chooser() {
+    let mol = ROMol::from_smiles("O=C(O)c1ccccc1.O=C(O)c1ccccc1.[Na+].[Cl-].CCCCCCCCCCCC").unwrap();
+    let largest = LargestFragmentChooser::default().choose(&mol);
+    assert_eq!(largest.as_smiles(), "CCCCCCCCCCCC");
+
+    let options = LargestFragmentChooserOptions {
+        prefer_organic: true,
+        ..Default::default()
+    };
+    let mol = ROMol::from_smiles("[Na+].[Cl-].[Na+].[Cl-].C").unwrap();
+    let largest = LargestFragmentChooser::new(&options).choose(&mol);
+    assert_eq!(largest.as_smiles(), "C");
+}
+
+#[test]
+fn test_standardize() {
+    let rw_mol = ROMol::from_smiles("[Na]OC(=O)c1ccc(C[S+2]([O-])([O-]))cc1")
+        .unwrap()
+        .as_rw_mol(false, -1);
+    let cleanup_params = CleanupParameters::default();
+
+    let standardized = standardize(&rw_mol, &cleanup_params).unwrap();
+    assert_eq!(
+        standardized.as_smiles(),
+        "O=C([O-])c1ccc(C[S](=O)=O)cc1.[Na+]"
+    );
+
+    let normalized = normalize(&rw_mol, &cleanup_params).unwrap();
+    let smiles = normalized.as_smiles();
+    assert!(smiles.contains("[Na]"));
+    assert!(smiles.contains("S](=O)=O"));
+
+    let rw_mol = ROMol::from_smiles("OC(=O)CC(=O)[O-]")
+        .unwrap()
+        .as_rw_mol(false, -1);
+    let reionized = reionize(&rw_mol, &cleanup_params).unwrap();
+    assert_eq!(reionized.to_ro_mol().formal_charge(), -1);
+}
+
+#[test]
+fn test_cleanup_parameters_accessors() {
+    let mut cleanup_params = CleanupParameters::default();
+    assert_eq!(cleanup_params.get_max_restarts(), 200);
+    assert_eq!(cleanup_params.get_max_tautomers(), 1000);
+    assert!(!cleanup_params.get_prefer_organic());
+    assert!(cleanup_params.get_do_canonical());
+    assert!(cleanup_params.get_largest_fragment_chooser_use_atom_count());
+    assert_eq!(cleanup_params.get_normalizations(), "");
+
+    cleanup_params.set_max_restarts(10);
+    cleanup_params.set_max_tautomers(50);
+    cleanup_params.set_max_transforms(20);
+    cleanup_params.set_prefer_organic(true);
+    cleanup_params.set_do_canonical(false);
+    cleanup_params.set_largest_fragment_chooser_use_atom_count(false);
+    cleanup_params.set_acidbase_file("/tmp/acid_base_pairs.txt");
+
+    assert_eq!(cleanup_params.get_max_restarts(), 10);
+    assert_eq!(cleanup_params.get_max_tautomers(), 50);
+    assert_eq!(cleanup_params.get_max_transforms(), 20);
+    assert!(cleanup_params.get_prefer_organic());
+    assert!(!cleanup_params.get_do_canonical());
+    assert!(!cleanup_params.get_largest_fragment_chooser_use_atom_count());
+    assert_eq!(
+        cleanup_params.get_acidbase_file(),
+        "/tmp/acid_base_pairs.txt"
+    );
+}
+
+#[test]
+fn test_cleanup_parameters_custom_data() {
+    let mut cleanup_params = CleanupParameters::default();
+    assert!(cleanup_params.normalization_data().is_empty());
+
+    cleanup_params
+        .set_normalizations_from_str("//\tName\tSMIRKS\n\nCl to Br\t[Cl:1]>>[Br:1]\n")
+        .unwrap();
+    assert_eq!(
+        cleanup_params.normalization_data(),
+        vec![("Cl to Br".to_string(), "[Cl:1]>>[Br:1]".to_string())]
+    );
+
+    let rw_mol = ROMol::from_smiles("CCCl").unwrap().as_rw_mol(false, -1);
+    let normalized = normalize(&rw_mol, &cleanup_params).unwrap();
+    assert_eq!(normalized.as_smiles(), "CCBr");
+
+    cleanup_params
+        .set_acid_base_pairs_from_str(
+            "//\tName\tAcid\tBase\n-SO3H\tOS(=O)(=O)[C,c]\t[O-]S(=O)(=O)[C,c]\n",
+        )
+        .unwrap();
+    assert_eq!(cleanup_params.acid_base_data().len(), 1);
+    assert_eq!(cleanup_params.acid_base_data()[0].0, "-SO3H");
+
+    let error = cleanup_params
+        .set_acid_base_pairs_from_str("-SO3H\tOS(=O)(=O)[C,c]\n")
+        .unwrap_err();
+    assert_eq!(
+        error,
+        CleanupParametersError::InvalidLine {
+            line: 1,
+            expected_columns: 3,
+            content: "-SO3H\tOS(=O)(=O)[C,c]".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_parents() {
+    let cleanup_params = CleanupParameters::default();
+    let rw_mol = |smiles| ROMol::from_smiles(smiles).unwrap().as_rw_mol(false, -1);
+
+    let parent = charge_parent(&rw_mol("CC(=O)[O-].[Na+]"), &cleanup_params, false).unwrap();
+    assert_eq!(parent.as_smiles(), "CC(=O)O");
+
+    let parent = tautomer_parent(&rw_mol("Oc1ccccn1"), &cleanup_params, false).unwrap();
+    assert_eq!(parent.as_smiles(), "O=c1cccc[nH]1");
+
+    let parent = stereo_parent(&rw_mol("C[C@H](N)O"), &cleanup_params, false).unwrap();
+    assert_eq!(parent.as_smiles(), "CC(N)O");
+
+    let parent = isotope_parent(&rw_mol("[13CH3]CO"), &cleanup_params, false).unwrap();
+    assert_eq!(parent.as_smiles(), "CCO");
+
+    let mol = rw_mol("[13CH3][C@H](N)C(=O)[O-].[Na+]");
+    let parent = super_parent(&mol, &cleanup_params, false).unwrap();
+    assert_eq!(parent.as_smiles(), "CC(N)C(=O)O");
+}
+
+#[test]
+fn test_tautomer_enumerator_options() {
+    let mut enumerator = TautomerEnumerator::new();
+    assert_eq!(enumerator.get_max_tautomers(), 1000);
+    assert_eq!(enumerator.get_max_transforms(), 1000);
+    assert!(enumerator.get_remove_sp3_stereo());
+    assert!(enumerator.get_remove_bond_stereo());
+    assert!(enumerator.get_reassign_stereo());
+
+    enumerator.set_max_tautomers(10);
+    enumerator.set_max_transforms(20);
+    enumerator.set_remove_sp3_stereo(false);
+    enumerator.set_remove_bond_stereo(false);
+    enumerator.set_reassign_stereo(false);
+    assert_eq!(enumerator.get_max_tautomers(), 10);
+    assert_eq!(enumerator.get_max_transforms(), 20);
+    assert!(!enumerator.get_remove_sp3_stereo());
+    assert!(!enumerator.get_remove_bond_stereo());
+    assert!(!enumerator.get_reassign_stereo());
+
+    let mut cleanup_params = CleanupParameters::default();
+    cleanup_params.set_max_tautomers(5);
+    let enumerator = TautomerEnumerator::from_cleanup_parameters(&cleanup_params).unwrap();
+    assert_eq!(enumerator.get_max_tautomers(), 5);
+
+    cleanup_params.set_tautomer_transforms("/does/not/exist.in");
+    assert!(TautomerEnumerator::from_cleanup_parameters(&cleanup_params).is_err());
+}
+
+#[test]
+fn test_tautomer_enumerator_status() {
+    let mol = ROMol::from_smiles("CC(=O)CC(=O)C").unwrap();
+
+    let mut enumerator = TautomerEnumerator::new();
+    let result = enumerator.enumerate(&mol);
+    assert_eq!(result.status(), TautomerEnumeratorStatus::Completed);
+    assert!(result.count() > 1);
+
+    enumerator.set_max_tautomers(1);
+    let result = enumerator.enumerate(&mol);
+    assert_eq!(
+        result.status(),
+        TautomerEnumeratorStatus::MaxTautomersReached
+    );
+}
+
+#[test]
+fn test_tautomer_enumerator_cancellation() {
+    let mol = ROMol::from_smiles("CC(=O)CC(=O)C").unwrap();
+
+    let mut enumerator = TautomerEnumerator::new();
+    enumerator.set_callback(|num_tautomers| num_tautomers < 2);
+    let result = enumerator.enumerate(&mol);
+    assert_eq!(result.status(), TautomerEnumeratorStatus::Canceled);
+
+    let mut enumerator = TautomerEnumerator::new();
+    enumerator.set_timeout(Duration::ZERO);
+    let result = enumerator.enumerate(&mol);
+    assert_eq!(result.status(), TautomerEnumeratorStatus::Canceled);
+
+    let mut enumerator = TautomerEnumerator::new();
+    enumerator.set_callback(|_| panic!("callback failed"));
+    let result = enumerator.enumerate(&mol);
+    assert_eq!(result.status(), TautomerEnumeratorStatus::Canceled);
+    let result = enumerator.enumerate(&mol);
+    assert_eq!(result.status(), TautomerEnumeratorStatus::Canceled);
+}
+
+#[test]
+fn test_score_tautomer() {
+    let amide = ROMol::from_smiles("CNC(=O)c1ccccc1").unwrap();
+    let imidic_acid = ROMol::from_smiles("CN=C(O)c1ccccc1").unwrap();
+    assert!(
+        TautomerEnumerator::score_tautomer(&amide)
+            > TautomerEnumerator::score_tautomer(&imidic_acid)
+    );
+}
+
+#[test]
+fn test_tautomer_enumerator_result_details() {
+    let mol = ROMol::from_smiles("c1ccccc1C(=O)NC").unwrap();
+    let result = TautomerEnumerator::new().enumerate(&mol);
+
+    let smiles = result.smiles();
+    assert_eq!(smiles, vec!["CN=C(O)c1ccccc1", "CNC(=O)c1ccccc1"]);
+
+    let tautomers = result.smiles_tautomer_map();
+    assert_eq!(tautomers.len(), 2);
+    for (smiles, tautomer) in &tautomers {
+        assert_eq!(&tautomer.as_smiles(), smiles);
+    }
+
+    // the hydrogen moves between the amide oxygen and nitrogen
+    let modified_atoms = result.modified_atoms();
+    assert!(modified_atoms.contains(&7));
+    assert!(modified_atoms.contains(&8));
+    assert!(modified_atoms.iter().all(|idx| *idx > 5));
+    let modified_bonds = result.modified_bonds();
+    assert!(modified_bonds.contains(&7));
+    assert!(modified_bonds.contains(&8));
+}
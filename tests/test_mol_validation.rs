@@ -0,0 +1,121 @@
+use rdkit::{
+    AllowedAtomsValidation, DisallowedAtomsValidation, FragmentValidation, IsotopeValidation,
+    NeutralValidation, NoAtomValidation, RDKitValidation, ROMol, SmilesParserParams,
+    ValidationLevel, ValidationRuleSet, Validator,
+};
+
+#[test]
+fn test_rdkit_validation() {
+    let mut params = SmilesParserParams::default();
+    params.set_sanitize(false);
+    let mol = ROMol::from_smiles_with_params("CO(C)C", &params).unwrap();
+
+    let errors = RDKitValidation::new().validate(&mol, true).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].level, ValidationLevel::Error);
+    assert_eq!(errors[0].validation, "ValenceValidation");
+    assert_eq!(errors[0].atom_indices, vec![1]);
+
+    let mol = ROMol::from_smiles("CCO").unwrap();
+    assert!(RDKitValidation::new()
+        .validate(&mol, true)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_no_atom_fragment_and_neutral_validation() {
+    let mol = ROMol::from_smiles("").unwrap();
+    let errors = NoAtomValidation::new().validate(&mol, true).unwrap();
+    assert_eq!(errors[0].validation, "NoAtomValidation");
+
+    let mol = ROMol::from_smiles("CCO.O").unwrap();
+    let errors = FragmentValidation::new().validate(&mol, true).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].message.contains("water"));
+
+    let mol = ROMol::from_smiles("C[N+](C)(C)C").unwrap();
+    let errors = NeutralValidation::new().validate(&mol, true).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].message.contains("+1"));
+    assert!(errors[0].atom_indices.is_empty());
+}
+
+#[test]
+fn test_isotope_validation() {
+    let mol = ROMol::from_smiles("[13CH3]C[13CH2]O").unwrap();
+    let errors = IsotopeValidation::default().validate(&mol, true).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].validation, "IsotopeValidation");
+    assert_eq!(errors[0].atom_indices, vec![0, 2]);
+}
+
+#[test]
+fn test_allowed_and_disallowed_atoms_validation() {
+    let mol = ROMol::from_smiles("CC(Cl)CBr").unwrap();
+
+    let errors = AllowedAtomsValidation::new(&[6, 7, 8])
+        .validate(&mol, true)
+        .unwrap();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].atom_indices, vec![2]);
+    assert_eq!(errors[1].atom_indices, vec![4]);
+
+    let errors = AllowedAtomsValidation::new(&[6, 7, 8])
+        .validate(&mol, false)
+        .unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].atom_indices, vec![2]);
+
+    let errors = DisallowedAtomsValidation::new(&[35])
+        .validate(&mol, true)
+        .unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].atom_indices, vec![4]);
+}
+
+#[test]
+fn test_validation_rule_set() {
+    let rules = ValidationRuleSet::new()
+        .with(RDKitValidation::new())
+        .with(NeutralValidation::new())
+        .with(DisallowedAtomsValidation::new(&[35]));
+    assert_eq!(rules.len(), 3);
+
+    let mol = ROMol::from_smiles("CCO").unwrap();
+    assert!(rules.validate(&mol, true).unwrap().is_empty());
+    assert!(rules.is_valid(&mol).unwrap());
+
+    let mut params = SmilesParserParams::default();
+    params.set_sanitize(false);
+    let mol = ROMol::from_smiles_with_params("CO(C)C.[Br-]", &params).unwrap();
+    let errors = rules.validate(&mol, true).unwrap();
+    let validations = errors
+        .iter()
+        .map(|error| error.validation.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        validations,
+        vec![
+            "ValenceValidation",
+            "NeutralValidation",
+            "DisallowedAtomsValidation"
+        ]
+    );
+    assert_eq!(errors[2].atom_indices, vec![4]);
+    assert!(!rules.is_valid(&mol).unwrap());
+}
+
+#[test]
+fn test_validation_rule_set_fails_on_info_messages() {
+    let rules = ValidationRuleSet::new().with(DisallowedAtomsValidation::new(&[35]));
+
+    let mol = ROMol::from_smiles("CCBr").unwrap();
+    let errors = rules.validate(&mol, true).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].level, ValidationLevel::Info);
+    assert!(!rules.is_valid(&mol).unwrap());
+
+    let mol = ROMol::from_smiles("CCCl").unwrap();
+    assert!(rules.is_valid(&mol).unwrap());
+}
@@ -0,0 +1,100 @@
+use rdkit::{AtomQuery, BondQuery, ROMol, SubstructMatchParameters};
+
+#[test]
+fn test_atom_query_smarts() {
+    let query = AtomQuery::halogen().and(AtomQuery::Degree(1));
+    assert_eq!(query.to_smarts(), "[#9,#17,#35,#53;D1]");
+
+    let query = AtomQuery::AtomicNum(6).and(!AtomQuery::InRing);
+    assert_eq!(query.to_smarts(), "[#6&!R]");
+
+    let query = AtomQuery::FormalCharge(-1).or(AtomQuery::FormalCharge(1));
+    assert_eq!(query.to_smarts(), "[-1,+1]");
+
+    // SMARTS can't negate an OR directly
+    let query = !AtomQuery::AtomicNum(6).or(AtomQuery::AtomicNum(7));
+    assert_eq!(query.to_smarts(), "[!#6&!#7]");
+
+    let query = AtomQuery::AtomicNum(6)
+        .or(AtomQuery::AtomicNum(7).and(AtomQuery::Aromatic.or(AtomQuery::InRing)));
+    assert_eq!(query.to_smarts(), "[#6,#7;#6,a,R]");
+}
+
+#[test]
+fn test_atom_query_matching() {
+    let params = SubstructMatchParameters::new();
+    let query = AtomQuery::halogen()
+        .and(AtomQuery::Degree(1))
+        .to_mol()
+        .unwrap();
+    assert!(ROMol::from_smiles("CCCl")
+        .unwrap()
+        .has_substruct_match(&query, &params));
+    assert!(!ROMol::from_smiles("CCO")
+        .unwrap()
+        .has_substruct_match(&query, &params));
+
+    let mut query = query;
+    let atom = query.atom_with_idx(0);
+    assert!(atom.has_query());
+    assert!(atom.describe_query().contains("AtomAtomicNum"));
+}
+
+#[test]
+fn test_isotope_atom_query() {
+    let query = AtomQuery::Isotope(13).and(AtomQuery::AtomicNum(6));
+    assert_eq!(query.to_smarts(), "[13&#6]");
+
+    let params = SubstructMatchParameters::new();
+    let query = ROMol::from_smarts(&query.to_smarts()).unwrap();
+    assert!(ROMol::from_smiles("[13CH4]")
+        .unwrap()
+        .has_substruct_match(&query, &params));
+    assert!(!ROMol::from_smiles("[12CH4]")
+        .unwrap()
+        .has_substruct_match(&query, &params));
+}
+
+#[test]
+fn test_recursive_atom_query() {
+    let carbonyl = ROMol::from_smarts("[#6]=[#8]").unwrap();
+    let query = AtomQuery::recursive(&carbonyl);
+    assert_eq!(query.to_smarts(), "[$([#6]=[#8])]");
+
+    let params = SubstructMatchParameters::new();
+    let query = query.to_mol().unwrap();
+    let mol = ROMol::from_smiles("CC(=O)C").unwrap();
+    assert_eq!(
+        mol.substruct_match_first(&query, &params),
+        Some(vec![(0, 1)])
+    );
+}
+
+#[test]
+fn test_bond_query() {
+    let query = !BondQuery::Single.or(BondQuery::Double);
+    assert_eq!(query.to_smarts(), "!-&!=");
+
+    let query = BondQuery::Single.and(BondQuery::InRing).to_mol().unwrap();
+    assert_eq!(query.as_smarts(), "*-&@*");
+
+    let params = SubstructMatchParameters::new();
+    assert!(ROMol::from_smiles("C1CC1")
+        .unwrap()
+        .has_substruct_match(&query, &params));
+    assert!(!ROMol::from_smiles("CC")
+        .unwrap()
+        .has_substruct_match(&query, &params));
+}
+
+#[test]
+fn test_as_smarts() {
+    let mol = ROMol::from_smarts("[#6;R]-[#7,#8]").unwrap();
+    assert_eq!(mol.as_smarts(), "[#6&R]-[#7,#8]");
+
+    let mut mol = ROMol::from_smiles("CO").unwrap();
+    let atom = mol.atom_with_idx(1);
+    assert!(!atom.has_query());
+    assert_eq!(atom.as_smarts(), "O");
+    assert_eq!(mol.as_smarts(), "[#6]-[#8]");
+}
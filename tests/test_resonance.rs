@@ -0,0 +1,51 @@
+use rdkit::{
+    substruct_match, ROMol, ResonanceFlags, ResonanceMolSupplier, SubstructMatchParameters,
+};
+
+#[test]
+fn test_resonance_mol_supplier() {
+    let mol = ROMol::from_smiles("CC(=O)[O-]").unwrap();
+    let supplier = ResonanceMolSupplier::new(&mol, &ResonanceFlags::default(), 1000);
+    assert_eq!(supplier.len(), 2);
+    assert_eq!(supplier.num_conj_groups(), 1);
+    assert_eq!(supplier.atom_conj_group_idx(0).unwrap(), None);
+    assert_eq!(supplier.atom_conj_group_idx(2).unwrap(), Some(0));
+    assert_eq!(supplier.atom_conj_group_idx(3).unwrap(), Some(0));
+    assert_eq!(supplier.bond_conj_group_idx(0).unwrap(), None);
+    assert_eq!(supplier.bond_conj_group_idx(2).unwrap(), Some(0));
+    assert!(supplier.atom_conj_group_idx(10).is_err());
+    assert!(!supplier.was_canceled());
+
+    let structures = supplier.map(|mol| mol.as_smiles()).collect::<Vec<_>>();
+    assert_eq!(structures.len(), 2);
+    assert!(structures.iter().all(|smiles| smiles == "CC(=O)[O-]"));
+}
+
+#[test]
+fn test_resonance_flags() {
+    let mol = ROMol::from_smiles("NC(=O)C").unwrap();
+    let supplier = ResonanceMolSupplier::new(&mol, &ResonanceFlags::default(), 1000);
+    assert_eq!(supplier.len(), 1);
+
+    let flags = ResonanceFlags {
+        allow_charge_separation: true,
+        ..Default::default()
+    };
+    let supplier = ResonanceMolSupplier::new(&mol, &flags, 1000);
+    assert_eq!(supplier.len(), 2);
+}
+
+#[test]
+fn test_resonance_substruct_match() {
+    let mol = ROMol::from_smiles("CC(=O)[O-]").unwrap();
+    let query = ROMol::from_smiles("C[O-]").unwrap();
+
+    let params = SubstructMatchParameters::new();
+    assert_eq!(substruct_match(&mol, &query, &params).len(), 1);
+
+    let supplier = ResonanceMolSupplier::new(&mol, &ResonanceFlags::default(), 1000);
+    assert_eq!(supplier.substruct_match(&query, &params).len(), 2);
+    assert!(supplier.has_substruct_match(&query, &params));
+    assert_eq!(supplier.count_substruct_matches(&query, &params), 2);
+    assert!(supplier.substruct_match_first(&query, &params).is_some());
+}
@@ -0,0 +1,68 @@
+use rdkit::{
+    rgroup_decompose, RGroupDecompositionParameters, RGroupLabels, RGroupMatching,
+    RGroupParametersError, ROMol, RWMol,
+};
+
+#[test]
+fn test_rgroup_decompose() {
+    let core = RWMol::from_smarts("c1ccccc1[*:1]").unwrap().to_ro_mol();
+    let mols = ["c1ccccc1O", "CCO", "c1ccccc1CC", "c1ccccc1Cl"]
+        .map(|smiles| ROMol::from_smiles(smiles).unwrap());
+
+    let params = RGroupDecompositionParameters::default();
+    let decomposition = rgroup_decompose(&[core], &mols, &params).unwrap();
+    assert_eq!(decomposition.unmatched, vec![1]);
+    assert_eq!(decomposition.rows.len(), 3);
+    assert_eq!(decomposition.labels(), vec!["Core", "R1"]);
+
+    let mol_idxs = decomposition
+        .rows
+        .iter()
+        .map(|row| row.mol_idx)
+        .collect::<Vec<_>>();
+    assert_eq!(mol_idxs, vec![0, 2, 3]);
+
+    for row in &decomposition.rows {
+        assert!(row.core().is_some());
+        assert!(row.rgroup(1).is_some());
+        assert!(row.rgroup(2).is_none());
+    }
+    assert!(decomposition.rows[0].smiles()["R1"].contains('O'));
+    assert!(decomposition.rows[2].smiles()["R1"].contains("Cl"));
+}
+
+#[test]
+fn test_rgroup_decomposition_parameters() {
+    let params = RGroupDecompositionParameters::new()
+        .labels(&[
+            RGroupLabels::AtomMapLabels,
+            RGroupLabels::RelabelDuplicateLabels,
+        ])
+        .unwrap()
+        .matching_strategy(&[RGroupMatching::Greedy, RGroupMatching::NoSymmetrization])
+        .unwrap()
+        .remove_all_hydrogen_rgroups(false)
+        .only_match_at_rgroups(true)
+        .timeout(5.0);
+    assert!(!params.get_remove_all_hydrogen_rgroups());
+    assert!(params.get_only_match_at_rgroups());
+    assert_eq!(params.get_timeout(), 5.0);
+
+    // the methyl is not at a labelled position
+    let core = RWMol::from_smarts("c1ccccc1[*:1]").unwrap().to_ro_mol();
+    let mols = ["c1ccccc1O", "Cc1ccccc1O"].map(|smiles| ROMol::from_smiles(smiles).unwrap());
+    let decomposition = rgroup_decompose(&[core], &mols, &params).unwrap();
+    assert_eq!(decomposition.unmatched, vec![1]);
+    assert_eq!(decomposition.rows.len(), 1);
+
+    assert_eq!(
+        RGroupDecompositionParameters::new().labels(&[]).err(),
+        Some(RGroupParametersError::NoLabels)
+    );
+    assert_eq!(
+        RGroupDecompositionParameters::new()
+            .matching_strategy(&[])
+            .err(),
+        Some(RGroupParametersError::NoMatchingStrategy)
+    );
+}
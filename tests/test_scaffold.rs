@@ -0,0 +1,45 @@
+use rdkit::{generic_scaffold, group_by_scaffold, murcko_scaffold, ROMol};
+
+#[test]
+fn test_murcko_and_generic_scaffold() {
+    let mol = ROMol::from_smiles("CCOc1ccc(Cc2ccncc2)cc1").unwrap();
+    let scaffold = murcko_scaffold(&mol).unwrap();
+    assert_eq!(scaffold.as_smiles(), "c1ccc(Cc2ccncc2)cc1");
+
+    let generic = generic_scaffold(&scaffold).unwrap();
+    assert_eq!(generic.as_smiles(), "C1CCC(CC2CCCCC2)CC1");
+
+    let mol = ROMol::from_smiles("CCCO").unwrap();
+    assert_eq!(murcko_scaffold(&mol).unwrap().as_smiles(), "");
+}
+
+#[test]
+fn test_group_by_scaffold() {
+    let mols = ["c1ccccc1O", "c1ccccc1CC", "c1ccncc1C", "CCO"]
+        .iter()
+        .map(|smiles| ROMol::from_smiles(smiles).unwrap())
+        .collect::<Vec<_>>();
+
+    let groups = group_by_scaffold(&mols, false).unwrap();
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups["c1ccccc1"], vec![0, 1]);
+    assert_eq!(groups["c1ccncc1"], vec![2]);
+    assert_eq!(groups[""], vec![3]);
+
+    let groups = group_by_scaffold(&mols, true).unwrap();
+    assert_eq!(groups["C1CCCCC1"], vec![0, 1, 2]);
+}
+
+#[test]
+fn test_generic_scaffold_drops_isotopes() {
+    let mols = ["c1ccccc1Cc1ccncc1", "c1ccccc1[13CH2]c1ccncc1"]
+        .iter()
+        .map(|smiles| ROMol::from_smiles(smiles).unwrap())
+        .collect::<Vec<_>>();
+
+    let groups = group_by_scaffold(&mols, false).unwrap();
+    assert_eq!(groups.len(), 2);
+
+    let groups = group_by_scaffold(&mols, true).unwrap();
+    assert_eq!(groups["C1CCC(CC2CCCCC2)CC1"], vec![0, 1]);
+}
@@ -0,0 +1,84 @@
+use rdkit::{EdgeType, ROMol, ScaffoldNetwork, ScaffoldNetworkParams};
+
+#[test]
+fn test_scaffold_network() {
+    let mol = ROMol::from_smiles("c1ccccc1CC1NC(=O)CCC1").unwrap();
+    let params = ScaffoldNetworkParams::new().collect_mol_counts(true);
+    let mut network = ScaffoldNetwork::new([&mol], &params).unwrap();
+
+    let nodes = network.nodes();
+    assert!(nodes.contains(&"c1ccccc1".to_string()));
+    assert_eq!(network.counts().len(), nodes.len());
+    assert_eq!(network.mol_counts().len(), nodes.len());
+
+    let edges = network.edges();
+    assert!(edges.iter().all(
+        |edge| (edge.begin_idx as usize) < nodes.len() && (edge.end_idx as usize) < nodes.len()
+    ));
+    assert!(edges
+        .iter()
+        .any(|edge| edge.edge_type == EdgeType::Fragment));
+
+    let benzene = nodes.iter().position(|node| node == "c1ccccc1").unwrap();
+    assert_eq!(network.mol_counts()[benzene], 1);
+
+    let mol = ROMol::from_smiles("c1ccccc1CC1CCCCC1").unwrap();
+    network.update_network([&mol], &params).unwrap();
+    assert!(network.nodes().len() > nodes.len());
+    assert_eq!(network.mol_counts()[benzene], 2);
+}
+
+#[test]
+fn test_scaffold_network_export() {
+    let mol = ROMol::from_smiles("c1ccccc1CC1CCCCC1").unwrap();
+    let network = ScaffoldNetwork::new([&mol], &ScaffoldNetworkParams::default()).unwrap();
+    let nodes = network.nodes();
+
+    let json: serde_json::Value = serde_json::from_str(&network.to_json()).unwrap();
+    let json_nodes = json["nodes"].as_array().unwrap();
+    assert_eq!(json_nodes.len(), nodes.len());
+    for (idx, (node, smiles)) in json_nodes.iter().zip(&nodes).enumerate() {
+        assert_eq!(node["id"], idx);
+        assert_eq!(node["smiles"], smiles.as_str());
+        assert_eq!(node["mol_count"], network.mol_counts()[idx]);
+    }
+    let json_edges = json["edges"].as_array().unwrap();
+    assert_eq!(json_edges.len(), network.edges().len());
+    assert!(json_edges.iter().any(|edge| edge["type"] == "Fragment"));
+
+    let graphml = network.to_graphml();
+    let document = roxmltree::Document::parse(&graphml).unwrap();
+    let graph = document
+        .descendants()
+        .find(|node| node.has_tag_name("graph"))
+        .unwrap();
+    assert_eq!(graph.attribute("edgedefault"), Some("directed"));
+    let smiles = graph
+        .children()
+        .filter(|node| node.has_tag_name("node"))
+        .map(|node| {
+            node.children()
+                .find(|data| data.attribute("key") == Some("smiles"))
+                .and_then(|data| data.text())
+                .unwrap()
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(smiles, nodes);
+    assert_eq!(
+        graph
+            .children()
+            .filter(|node| node.has_tag_name("edge"))
+            .count(),
+        network.edges().len()
+    );
+}
+
+#[test]
+fn test_scaffold_network_bond_breakers() {
+    let params =
+        ScaffoldNetworkParams::with_bond_breakers(&["[!#0;R:1]-!@[!#0:2]>>[*:1]-[#0].[#0]-[*:2]"]);
+    assert!(params.is_ok());
+
+    assert!(ScaffoldNetworkParams::with_bond_breakers(&["not a reaction"]).is_err());
+}
@@ -1,3 +1,5 @@
+use std::sync::{Arc, Mutex};
+
 use rdkit::{substruct_match, ROMol, SubstructMatchItem, SubstructMatchParameters};
 
 #[test]
@@ -69,3 +71,91 @@ fn test_substruct_match() {
         ]
     );
 }
+
+#[test]
+fn test_has_and_count_substruct_matches() {
+    let mol = ROMol::from_smiles("OCCCO").unwrap();
+    let params = SubstructMatchParameters::new();
+
+    let query = ROMol::from_smiles("CO").unwrap();
+    assert!(mol.has_substruct_match(&query, &params));
+    assert_eq!(mol.count_substruct_matches(&query, &params), 2);
+    assert_eq!(
+        mol.substruct_match_first(&query, &params),
+        Some(vec![(0, 1), (1, 0)])
+    );
+
+    let query = ROMol::from_smiles("N").unwrap();
+    assert!(!mol.has_substruct_match(&query, &params));
+    assert_eq!(mol.count_substruct_matches(&query, &params), 0);
+    assert_eq!(mol.substruct_match_first(&query, &params), None);
+
+    // more matches than the default max_matches of 1000
+    let mol = ROMol::from_smiles(&"C".repeat(1500)).unwrap();
+    let query = ROMol::from_smiles("C").unwrap();
+    assert_eq!(mol.count_substruct_matches(&query, &params), 1500);
+}
+
+#[test]
+fn test_substruct_match_parameters() {
+    let params = SubstructMatchParameters::new()
+        .atom_properties(&["label"])
+        .bond_properties(&["order"])
+        .specified_stereo_query_matches_unspecified(true)
+        .max_recursive_matches(10)
+        .num_threads(2);
+    assert_eq!(params.get_atom_properties(), vec!["label".to_string()]);
+    assert_eq!(params.get_bond_properties(), vec!["order".to_string()]);
+    assert!(params.get_specified_stereo_query_matches_unspecified());
+    assert_eq!(params.get_max_recursive_matches(), 10);
+    assert_eq!(params.get_num_threads(), 2);
+}
+
+#[test]
+fn test_substruct_match_atom_properties() {
+    let mut mol = ROMol::from_smiles("CCC").unwrap();
+    mol.atom_with_idx(2).set_prop("label", "x");
+    let mut query = ROMol::from_smiles("C").unwrap();
+    query.atom_with_idx(0).set_prop("label", "x");
+
+    let params = SubstructMatchParameters::new().atom_properties(&["label"]);
+    assert_eq!(
+        mol.substruct_match_first(&query, &params),
+        Some(vec![(0, 2)])
+    );
+    assert_eq!(mol.count_substruct_matches(&query, &params), 1);
+}
+
+#[test]
+fn test_substruct_match_extra_final_check() {
+    let mol = ROMol::from_smiles("OCCCO").unwrap();
+    let query = ROMol::from_smiles("CO").unwrap();
+
+    // a failed assert inside the check would only reject the match, so record
+    // what it saw and assert afterwards
+    let seen = Arc::new(Mutex::new(vec![]));
+    let recorded = seen.clone();
+    let params = SubstructMatchParameters::new().extra_final_check(move |mol, atom_indices| {
+        let oxygen = mol.with_atom(atom_indices[1], |atom| atom.get_atomic_num());
+        recorded.lock().unwrap().push((mol.num_atoms(true), oxygen));
+        atom_indices[1] != 0
+    });
+    assert_eq!(
+        substruct_match(&mol, &query, &params),
+        vec![vec![
+            SubstructMatchItem {
+                query_atom_idx: 0,
+                mol_atom_idx: 3,
+            },
+            SubstructMatchItem {
+                query_atom_idx: 1,
+                mol_atom_idx: 4,
+            },
+        ]]
+    );
+    assert_eq!(*seen.lock().unwrap(), vec![(5, 8), (5, 8)]);
+
+    // a panicking check rejects the match instead of aborting
+    let params = SubstructMatchParameters::new().extra_final_check(|_, _| panic!("check failed"));
+    assert!(substruct_match(&mol, &query, &params).is_empty());
+}
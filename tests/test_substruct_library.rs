@@ -0,0 +1,51 @@
+use rdkit::{ROMol, SubstructLibrary, SubstructMatchParameters};
+
+fn library() -> SubstructLibrary {
+    let mut library = SubstructLibrary::new();
+    for smiles in ["c1ccccc1O", "CCCC", "c1ccncc1", "Oc1ccccc1CO"] {
+        let mol = ROMol::from_smiles(smiles).unwrap();
+        library.add_mol(&mol).unwrap();
+    }
+    library
+}
+
+#[test]
+fn test_substruct_library_search() {
+    let library = library();
+    assert_eq!(library.len(), 4);
+    assert_eq!(library.get_mol(2).unwrap().as_smiles(), "c1ccncc1");
+
+    let params = SubstructMatchParameters::new();
+    let query = ROMol::from_smiles("c1ccccc1O").unwrap();
+    assert_eq!(
+        library.get_matches(&query, &params, None, Some(1)).unwrap(),
+        vec![0, 3]
+    );
+    assert_eq!(
+        library
+            .get_matches(&query, &params, Some(1), Some(1))
+            .unwrap()
+            .len(),
+        1
+    );
+    assert_eq!(library.count_matches(&query, &params, None).unwrap(), 2);
+    assert!(library.has_match(&query, &params, None).unwrap());
+
+    let query = ROMol::from_smiles("Cl").unwrap();
+    assert!(!library.has_match(&query, &params, None).unwrap());
+}
+
+#[test]
+fn test_substruct_library_serialization() {
+    let library = library();
+    let bytes = library.to_bytes().unwrap();
+    let reloaded = SubstructLibrary::from_bytes(&bytes).unwrap();
+    assert_eq!(reloaded.len(), 4);
+
+    let params = SubstructMatchParameters::new();
+    let query = ROMol::from_smiles("CC").unwrap();
+    assert_eq!(
+        reloaded.get_matches(&query, &params, None, None).unwrap(),
+        library.get_matches(&query, &params, None, None).unwrap()
+    );
+}